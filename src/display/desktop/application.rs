@@ -0,0 +1,313 @@
+//! Application sharing one display connection between windows.
+//!
+//! Windows created by an [Application] share its display connection. Events of all windows are read
+//! from a single [Application::poll_event] that returns which window they belong to, or dispatched to an
+//! [ApplicationHandler] by [Application::run].
+
+use std::collections::VecDeque;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::StudioError;
+
+use super::event::Event;
+use super::event::window::EventWindow;
+use super::gamepad::GamepadList;
+use super::icon::WindowIcon;
+use super::manager::{ApplicationManager, ApplicationManagerType, WindowManager, WindowProvider};
+use super::property::WindowEventWaitMode;
+use super::window::Window;
+
+/// Identifier of a [Window] created by an [Application].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WindowId(u32);
+
+/// Handler receiving the events of an [Application] run loop.
+///
+/// Only [ApplicationHandler::on_event] is mandatory, other methods do nothing by default.
+pub trait ApplicationHandler<'app> {
+    /// Called for each event of a window, including close requests.
+    fn on_event(&mut self, app : &mut Application<'app>, id : WindowId, event : &Event);
+
+    /// Called when a window receive a close request, after [ApplicationHandler::on_event].
+    ///
+    /// Returns true to close the window (default), false to keep it open.
+    fn on_close_requested(&mut self, _app : &mut Application<'app>, _id : WindowId) -> bool {
+        true
+    }
+
+    /// Called once per frame with the elapsed time since the previous frame.
+    ///
+    /// With [WindowEventWaitMode::NeverWait], called on each loop iteration, at most at the frame rate if set.
+    /// With [WindowEventWaitMode::AlwaysWait], only called after events were handled.
+    /// With [WindowEventWaitMode::WaitTimeout] and [WindowEventWaitMode::WaitUntil], also called when the wait times out.
+    fn on_frame(&mut self, _app : &mut Application<'app>, _elapsed : Duration) {}
+
+    /// Called once events are handled, before waiting for the next event or frame.
+    fn on_idle(&mut self, _app : &mut Application<'app>) {}
+}
+
+/// Application owning windows that share one display connection.
+///
+/// Steps :
+/// new()
+/// create_window()
+/// get_window_mut(id) to set properties and show
+/// poll_event() in loop or run(handler)
+pub struct Application<'app> {
+
+    /// Platform manager of display connection.
+    manager : ApplicationManagerType,
+
+    /// Windows of application with their id.
+    windows : Vec<(WindowId, Window<'app>)>,
+
+    /// Events not polled yet.
+    events : VecDeque<(WindowId, Event)>,
+
+    /// Next window id.
+    next_id : u32,
+
+    /// Wait mode of poll_event.
+    wait_mode : WindowEventWaitMode,
+
+    /// Window having focus, receiving gamepad events.
+    focused : Option<WindowId>,
+
+    /// Target frames per second of run loop. None if not limited.
+    frame_rate : Option<u32>,
+
+    /// Icons applied to new windows. Empty if no default icon.
+    default_icon : Vec<WindowIcon>,
+}
+
+impl<'app> Application<'app> {
+
+    /// Create a new [Application] with its display connection.
+    ///
+    /// Returns Ok([Application]) on success, Err([StudioError]) if no display server available.
+    pub fn new() -> Result<Application<'app>, StudioError> {
+        Ok(Application { manager: ApplicationManagerType::new()?, windows: Vec::new(), events: VecDeque::new(), next_id: 0,
+            wait_mode: WindowEventWaitMode::NeverWait, focused: None, frame_rate: None, default_icon: Vec::new() })
+    }
+
+    /// Get the window provider id
+    pub fn get_window_provider(&self) -> WindowProvider {
+        self.manager.get_window_provider()
+    }
+
+    /// Create a new hidden [Window] using the application display connection.
+    ///
+    /// Returns the [WindowId] of new window.
+    pub fn create_window(&mut self) -> WindowId {
+        let id = WindowId(self.next_id);
+        self.next_id += 1;
+
+        let mut window = Window::from_manager(self.manager.create_window_manager());
+        if !self.default_icon.is_empty() {
+            window.manager.set_icon(&self.default_icon);
+        }
+
+        self.windows.push((id, window));
+        id
+    }
+
+    /// Get the icons applied to new windows. Empty if no default icon.
+    pub fn get_default_icon(&self) -> &[WindowIcon] {
+        &self.default_icon
+    }
+
+    /// Set the icons applied to windows created afterward. A window [WindowPropertySet::Icon](super::property::WindowPropertySet::Icon)
+    /// replaces the default icon. An empty list removes the default icon.
+    pub fn set_default_icon(&mut self, icon : Vec<WindowIcon>) {
+        self.default_icon = icon;
+    }
+
+    /// Get a window reference from its id.
+    ///
+    /// Returns Some([Window]) or None if no window with that id.
+    pub fn get_window(&self, id : WindowId) -> Option<&Window<'app>> {
+        self.windows.iter().find(|(window_id, _)| *window_id == id).map(|(_, window)| window)
+    }
+
+    /// Get a mutable window reference from its id.
+    ///
+    /// Returns Some([Window]) or None if no window with that id.
+    pub fn get_window_mut(&mut self, id : WindowId) -> Option<&mut Window<'app>> {
+        self.windows.iter_mut().find(|(window_id, _)| *window_id == id).map(|(_, window)| window)
+    }
+
+    /// Get the ids of all windows of application.
+    pub fn get_window_ids(&self) -> Vec<WindowId> {
+        self.windows.iter().map(|(id, _)| *id).collect()
+    }
+
+    /// Remove a window from application. Events of that window not polled yet are discarded.
+    ///
+    /// Returns Some([Window]) or None if no window with that id.
+    pub fn remove_window(&mut self, id : WindowId) -> Option<Window<'app>> {
+        let index = self.windows.iter().position(|(window_id, _)| *window_id == id)?;
+        self.events.retain(|(window_id, _)| *window_id != id);
+
+        if self.focused == Some(id) {
+            self.focused = None;
+        }
+
+        Some(self.windows.remove(index).1)
+    }
+
+    /// Get the connected gamepads. Gamepad events are sent to the window having focus.
+    pub fn get_gamepads(&self) -> &GamepadList {
+        self.manager.get_gamepads()
+    }
+
+    /// Get the connected gamepads as mutable.
+    pub fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        self.manager.get_gamepads_mut()
+    }
+
+    /// Get the [WindowEventWaitMode] of [Application::poll_event].
+    pub fn get_event_wait_mode(&self) -> WindowEventWaitMode {
+        self.wait_mode
+    }
+
+    /// Set the [WindowEventWaitMode] of [Application::poll_event].
+    pub fn set_event_wait_mode(&mut self, mode : WindowEventWaitMode) {
+        self.wait_mode = mode;
+    }
+
+    /// Get the target frames per second of [Application::run]. None if not limited.
+    pub fn get_frame_rate(&self) -> Option<u32> {
+        self.frame_rate
+    }
+
+    /// Set the target frames per second of [Application::run]. None or 0 remove the limit.
+    pub fn set_frame_rate(&mut self, frame_rate : Option<u32>) {
+        self.frame_rate = frame_rate.filter(|rate| *rate > 0);
+    }
+
+    /// Pop an event of any window with the [WindowId] it belongs to.
+    ///
+    /// Unless [WindowEventWaitMode::NeverWait], wait until an event is available or the wait times out, unless
+    /// application has no window.
+    ///
+    /// Returns Some(([WindowId], [Event])) or None if no event.
+    pub fn poll_event(&mut self) -> Option<(WindowId, Event)> {
+        self.next_event(self.wait_mode != WindowEventWaitMode::NeverWait, self.wait_mode.get_deadline())
+    }
+
+    /// Run the application, dispatching events to handler until all windows are closed.
+    ///
+    /// Windows receiving a close request are closed if [ApplicationHandler::on_close_requested] returns true.
+    /// Closed windows are removed from application.
+    pub fn run(&mut self, handler : &mut dyn ApplicationHandler<'app>) {
+        let mut last_frame = Instant::now();
+        let mut wait = false;
+
+        while !self.windows.is_empty() {
+            let handled = self.handle_events(handler, wait);
+
+            if self.windows.is_empty() {
+                break;
+            }
+
+            if self.wait_mode != WindowEventWaitMode::AlwaysWait || handled {
+                self.handle_frame(handler, &mut last_frame);
+            }
+
+            handler.on_idle(self);
+            wait = self.wait_mode != WindowEventWaitMode::NeverWait;
+        }
+    }
+
+    /// Send all pending events to handler. If wait is true, wait for the first event according to wait mode.
+    ///
+    /// Returns true if at least one event was handled.
+    fn handle_events(&mut self, handler : &mut dyn ApplicationHandler<'app>, wait : bool) -> bool {
+        let deadline = self.wait_mode.get_deadline();
+        let mut handled = false;
+
+        while let Some((id, event)) = self.next_event(wait && !handled, deadline) {
+            handled = true;
+            handler.on_event(self, id, &event);
+
+            match event {
+                Event::Window(EventWindow::CloseRequest) if handler.on_close_requested(self, id) => {
+                    if let Some(window) = self.get_window_mut(id) {
+                        window.close();
+                    }
+                },
+                Event::Window(EventWindow::Closed) => {
+                    self.remove_window(id);
+                },
+                _ => {},
+            }
+        }
+
+        handled
+    }
+
+    /// Wait until the next frame if frame rate is limited, then send the frame to handler.
+    fn handle_frame(&mut self, handler : &mut dyn ApplicationHandler<'app>, last_frame : &mut Instant) {
+        if let Some(frame_rate) = self.frame_rate {
+            let next_frame = *last_frame + Duration::from_secs(1) / frame_rate;
+            let now = Instant::now();
+
+            if next_frame > now {
+                thread::sleep(next_frame - now);
+            }
+        }
+
+        let now = Instant::now();
+        handler.on_frame(self, now - *last_frame);
+        *last_frame = now;
+    }
+
+    /// Pop an event of any window. If wait is true, wait until an event is available or until deadline unless
+    /// application has no window.
+    fn next_event(&mut self, wait : bool, deadline : Option<Instant>) -> Option<(WindowId, Event)> {
+        loop {
+            if let Some((id, event)) = self.events.pop_front() {
+                match event {
+                    Event::Window(EventWindow::Focus) => self.focused = Some(id),
+                    Event::Window(EventWindow::Blur) if self.focused == Some(id) => self.focused = None,
+                    _ => {},
+                }
+                return Some((id, event));
+            }
+
+            // Events retained by windows such as created, closed, gestures, user events or timers.
+            for (id, window) in self.windows.iter_mut() {
+                loop {
+                    match *window.poll_event() {
+                        Event::None => break,
+                        event => self.events.push_back((*id, event)),
+                    }
+                }
+            }
+
+            if !self.events.is_empty() {
+                continue;
+            }
+
+            if let Some(event) = self.manager.get_gamepads_mut().poll_event() {
+                let event = Event::Gamepad(event);
+                match self.focused.or(self.windows.first().map(|(id, _)| *id)) {
+                    Some(id) if self.get_window(id).is_some_and(|window| window.get_properties().event_mask.accepts(&event)) =>
+                        return Some((id, event)),
+                    _ => continue,   // No window to send gamepad event to or gamepad events masked.
+                }
+            }
+
+            // Wait is interrupted at deadline or when the next timer of windows expires.
+            let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            let wait = wait && !self.windows.is_empty() && remaining != Some(Duration::ZERO);
+            let timeout = self.windows.iter().filter_map(|(_, window)| window.get_timers().get_timeout()).chain(remaining).min();
+            self.manager.dispatch_events(&mut self.windows, &mut self.events, wait, timeout);
+
+            if self.events.is_empty() && !wait {
+                return None;
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Hardware input devices information.
+
+use cfg_boost::match_cfg;
+
+use crate::error::StudioError;
+
+
+/// Contains list of all hardware input devices.
+pub struct InputDeviceList {
+    /// List of input devices
+    device_list : Vec<InputDevice>,
+}
+
+impl InputDeviceList {
+
+    /// Create a new hardware input device list.
+    pub fn new() -> Result<InputDeviceList, StudioError> {
+
+        match_cfg! {
+            linux => {
+                // TODO: Try with Wayland first then X11
+                crate::display::desktop::manager::linux::x11::input::get_x11_input_device_list()
+            },
+            _ => Err(StudioError::Display(crate::display::DisplayError::NotSupported)),
+        }
+
+    }
+
+    /// Create an input device list from vector of input device.
+    pub(crate) fn create(device_list : Vec<InputDevice>) -> InputDeviceList {
+        InputDeviceList { device_list }
+    }
+
+    /// Get an input device reference from its id.
+    ///
+    /// Returns Some([InputDevice]) or None if no device with that id.
+    pub fn get_device(&self, id : u16) -> Option<&InputDevice> {
+        self.device_list.iter().find(|device| device.id == id)
+    }
+
+    /// Get a reference to the list of input devices.
+    pub fn get_device_list(&self) -> &Vec<InputDevice> {
+        &self.device_list
+    }
+
+}
+
+/// Enumeration of possible input device types.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InputDeviceType {
+    /// Keyboard device.
+    Keyboard,
+
+    /// Mouse or any relative pointing device.
+    Mouse,
+
+    /// Touchpad which move a pointer.
+    Touchpad,
+
+    /// Touchscreen where touches happen directly on the screen.
+    Touchscreen,
+
+    /// Pen or graphics tablet.
+    Pen,
+
+    /// Any other input device such as power buttons.
+    Other,
+}
+
+/// Hardware input device details.
+///
+/// # Note(s)
+/// Master devices are virtual devices (the cursor and the keyboard focus). Events
+/// report the physical (slave) device that generated them if known.
+#[derive(Clone)]
+pub struct InputDevice {
+    /// Identifier of that device
+    id : u16,
+
+    /// Name of that device
+    name : String,
+
+    /// Type of that device
+    device_type : InputDeviceType,
+
+    /// Is a master (virtual) device?
+    master : bool,
+
+    /// Is device enabled?
+    enabled : bool,
+
+    /// Count of buttons
+    buttons : u32,
+
+    /// Count of axis (valuators)
+    axes : u32,
+
+    /// Maximum count of simultaneous touches
+    touches : u32,
+}
+
+impl InputDevice {
+    /// Create a new [InputDevice] with fields.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id : u16, name : String, device_type : InputDeviceType, master : bool, enabled : bool, buttons : u32, axes : u32, touches : u32) -> InputDevice {
+        InputDevice { id, name, device_type, master, enabled, buttons, axes, touches }
+    }
+
+    /// Returns device identifier used in [EventSource](super::event::EventSource).
+    pub fn get_id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns device name as [String].
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    /// Returns [InputDeviceType] of device.
+    pub fn get_device_type(&self) -> InputDeviceType {
+        self.device_type
+    }
+
+    /// Returns True if device is a master (virtual) device.
+    pub fn is_master(&self) -> bool {
+        self.master
+    }
+
+    /// Returns True if device is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns count of buttons of device.
+    pub fn get_button_count(&self) -> u32 {
+        self.buttons
+    }
+
+    /// Returns count of axes of device.
+    pub fn get_axis_count(&self) -> u32 {
+        self.axes
+    }
+
+    /// Returns maximum count of simultaneous touches of device. 0 if device doesn't support touch.
+    pub fn get_touch_count(&self) -> u32 {
+        self.touches
+    }
+}
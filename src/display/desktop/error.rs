@@ -28,4 +28,31 @@ pub enum DisplayError {
     /// Happens when trying to change a window parent that is locked.
     ParentIsLocked,
 
+    /// Happens when a gamepad mapping line is invalid or for another platform.
+    GamepadMappingInvalid,
+
+    /// Happens when a gamepad doesn't support a force feedback effect or can't be written.
+    GamepadEffectNotSupported,
+
+    /// Happens when a gamepad refused to upload or play a force feedback effect.
+    GamepadEffectFailed,
+
+    /// Happens when posting a user event to a window or application that was dropped.
+    EventProxyClosed,
+
+    /// Happens when registering a file descriptor already registered in the event loop.
+    FdAlreadyRegistered,
+
+    /// Happens when an event recording can't be read or written.
+    RecordingIoFailed,
+
+    /// Happens when an event recording is corrupted or isn't a recording.
+    RecordingInvalid,
+
+    /// Happens when an event recording was written with an unsupported format version.
+    RecordingVersionNotSupported,
+
+    /// Happens when a window icon is empty or its pixels don't match its size.
+    IconInvalid,
+
 }
\ No newline at end of file
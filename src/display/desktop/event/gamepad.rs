@@ -1,6 +1,8 @@
+use crate::display::desktop::gamepad::mapping::{GamepadButton, GamepadAxis};
 
 /// Enumeration of possible gamepad events
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventGamepad {
 
     /// Happens when a controller device has been connected. Provides controller id.
@@ -19,6 +21,25 @@ pub enum EventGamepad {
     /// 
     /// # Reference(s)
     /// Based on SDL_ControllerAxisEvent : <https://wiki.libsdl.org/SDL2/SDL_ControllerAxisEvent>
-    Axis(u8, u8, i16)
+    Axis(u8, u8, i16),
+
+    /// Happens when a trigger axis goes over the trigger threshold of [GamepadSettings](crate::display::desktop::gamepad::state::GamepadSettings).
+    /// Provides controller id and axis id.
+    TriggerDown(u8, u8),
+
+    /// Happens when a trigger axis goes back under the trigger threshold. Provides controller id and axis id.
+    TriggerUp(u8, u8),
+
+    /// Happens after [EventGamepad::ButtonDown] or [EventGamepad::Axis] when it pressed a named button of a gamepad 
+    /// with a [GamepadMapping](crate::display::desktop::gamepad::mapping::GamepadMapping). Provides controller id and [GamepadButton].
+    MappedButtonDown(u8, GamepadButton),
+
+    /// Happens after [EventGamepad::ButtonUp] or [EventGamepad::Axis] when it released a named button of a gamepad 
+    /// with a [GamepadMapping](crate::display::desktop::gamepad::mapping::GamepadMapping). Provides controller id and [GamepadButton].
+    MappedButtonUp(u8, GamepadButton),
+
+    /// Happens after a raw event that moved a named axis of a gamepad with a [GamepadMapping](crate::display::desktop::gamepad::mapping::GamepadMapping). 
+    /// Provides controller id, [GamepadAxis] and axis value (range: -32768 to 32767, triggers range: 0 to 32767).
+    MappedAxis(u8, GamepadAxis, i16),
 
 }
\ No newline at end of file
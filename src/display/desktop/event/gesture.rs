@@ -0,0 +1,46 @@
+use super::pointer::PointerButton;
+
+/// Enumeration of possible gesture events.
+///
+/// Pointer gestures are recognized by a [GestureRecognizer](crate::display::desktop::gesture::GestureRecognizer) while
+/// touchpad gestures (pinch and swipe) are sent by the window if supported by the display server.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventGesture {
+    /// Happens when a button is pressed and released without dragging. Provides the button, the position and
+    /// the count of successive clicks (1 for a single click, 2 for a double click, etc...).
+    Click { button : PointerButton, position : (i32, i32), count : u32 },
+
+    /// Happens when a button is held without moving for the long press duration. Provides the button and the position.
+    LongPress { button : PointerButton, position : (i32, i32) },
+
+    /// Happens when the pointer moved further than the drag threshold while a button is held. Provides the button
+    /// and the position where the button was pressed.
+    DragStart { button : PointerButton, position : (i32, i32) },
+
+    /// Happens when the pointer move during a drag. Provides the button and the new position.
+    DragMove { button : PointerButton, position : (i32, i32) },
+
+    /// Happens when the button of a drag is released. Provides the button and the release position.
+    DragEnd { button : PointerButton, position : (i32, i32) },
+
+    /// Happens when fingers start a pinch on a touchpad. Provides the count of fingers and the pointer position.
+    PinchBegin { fingers : u32, position : (f64, f64) },
+
+    /// Happens when a touchpad pinch changed. Provides the scale relative to the beginning of the pinch (1.0 is unchanged),
+    /// the rotation in degrees since the beginning of the pinch (clockwise is positive) and the pointer position.
+    PinchUpdate { scale : f64, rotation : f64, position : (f64, f64) },
+
+    /// Happens when fingers are lifted from a touchpad pinch. Provides the final scale, the final rotation and true
+    /// if the pinch was cancelled.
+    PinchEnd { scale : f64, rotation : f64, cancelled : bool },
+
+    /// Happens when fingers start a swipe on a touchpad. Provides the count of fingers and the pointer position.
+    SwipeBegin { fingers : u32, position : (f64, f64) },
+
+    /// Happens when fingers move during a touchpad swipe. Provides the count of fingers and the (x, y) delta since last update.
+    SwipeUpdate { fingers : u32, delta : (f64, f64) },
+
+    /// Happens when fingers are lifted from a touchpad swipe. Provides the count of fingers and true if the swipe was cancelled.
+    SwipeEnd { fingers : u32, cancelled : bool },
+}
@@ -2,6 +2,7 @@ use crate::display::desktop::manager::{WindowManagerType, WindowManager};
 
 /// Enumeration of possible Keyboard events
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventKeyboard {
     // Keyboard key down event of direct input mode. Provides keycode as u32.
     KeyDown(u32),
@@ -15,7 +16,10 @@ pub enum EventKeyboard {
 
 
 /// Represent a key on keyboard.
+/// 
+/// Serialized with its [KeyModifier] flags instead of the keyboard state of the display server.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(into = "KeyFields", from = "KeyFields"))]
 pub struct Key {
 
     /// Keyboard state when captured
@@ -33,6 +37,19 @@ impl Key {
     pub fn new(state : u32, keycode : u32, character : Option<char>) -> Key {
         Key { state, keycode, character }
     }
+
+    /// Create a new KeyPress Key entry from [KeyModifier] flags, keycode and character.
+    pub fn from_modifiers(modifiers : u8, keycode : u32, character : Option<char>) -> Key {
+        Key { state: WindowManagerType::get_key_state(modifiers), keycode, character }
+    }
+
+    /// Get the [KeyModifier] flags of modifiers down and locks on when key was pressed.
+    pub fn get_modifiers(&self) -> u8 {
+        [(self.is_shift_down(), KeyModifier::SHIFT), (self.is_ctrl_down(), KeyModifier::CTRL), (self.is_alt_down(), KeyModifier::ALT),
+            (self.is_meta_down(), KeyModifier::META), (self.is_command_down(), KeyModifier::COMMAND), (self.is_hyper_down(), KeyModifier::HYPER),
+            (self.is_capslock_on(), KeyModifier::CAPSLOCK), (self.is_numlock_on(), KeyModifier::NUMLOCK)]
+            .iter().filter(|(down, _)| *down).fold(0, |modifiers, (_, modifier)| modifiers | modifier)
+    }
     
     /// Either left or right shift were pressed with key
     pub fn is_shift_down(&self) -> bool{
@@ -85,6 +102,34 @@ impl Key {
 
 }
 
+/// Serialized fields of a [Key].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeyFields {
+    /// [KeyModifier] flags.
+    modifiers : u8,
+
+    /// Keyboard keycode
+    keycode : u32,
+
+    /// UTF8 Character typed on the key press if available.
+    character : Option<char>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Key> for KeyFields {
+    fn from(key : Key) -> KeyFields {
+        KeyFields { modifiers: key.get_modifiers(), keycode: key.keycode, character: key.character }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<KeyFields> for Key {
+    fn from(fields : KeyFields) -> Key {
+        Key::from_modifiers(fields.modifiers, fields.keycode, fields.character)
+    }
+}
+
 /// List that contains key Identity from keycodes.
 pub struct KeyCodeIdentityList {
     list : Box<[KeyIdentity;u8::MAX as usize]>,
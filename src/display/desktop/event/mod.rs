@@ -1,6 +1,8 @@
 //! Window events input such as mouse, keyboard, etc..
 
-use self::{window::EventWindow, keyboard::EventKeyboard, pointer::EventPointer, gamepad::EventGamepad};
+use super::source::FdReadiness;
+use super::timer::TimerId;
+use self::{window::EventWindow, keyboard::EventKeyboard, pointer::EventPointer, gamepad::EventGamepad, pen::EventPen, gesture::EventGesture};
 
 pub mod window;
 
@@ -10,8 +12,13 @@ pub mod pointer;
 
 pub mod gamepad;
 
+pub mod pen;
+
+pub mod gesture;
+
 /// Union of possible events into an enumeration.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
 
     /// No event.
@@ -20,15 +27,140 @@ pub enum Event {
     /// Window events
     Window(EventWindow),
 
-    /// Keyboard events
-    Keyboard(EventKeyboard),
+    /// Keyboard events. Provides the [EventSource] of the event.
+    Keyboard(EventKeyboard, EventSource),
 
-    /// Pointer events
-    Pointer(EventPointer),
+    /// Pointer events. Provides the [EventSource] of the event.
+    Pointer(EventPointer, EventSource),
 
     /// Gamepad events
     Gamepad(EventGamepad),
+
+    /// Pen and graphics tablet events
+    Pen(EventPen),
+
+    /// Gesture events
+    Gesture(EventGesture),
+
+    /// User event posted by an [EventProxy](super::proxy::EventProxy). Provides the value posted, a key into user
+    /// storage or the key of a payload taken with [Window::take_user_payload](super::Window::take_user_payload).
+    User(u64),
+
+    /// Happens when a timer expires. Provides the [TimerId] of timer.
+    Timer(TimerId),
+
+    /// Happens when a registered file descriptor is ready. Provides the token given at registration and the [FdReadiness].
+    FdReady(u64, FdReadiness),
+}
+
+
+
+
+
+/// Mask of event categories, used to filter or coalesce the events of a [Window](super::window::Window).
+///
+/// Events outside these categories, such as window creation, close requests, user events or timers, can't be masked.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct EventCategoryMask(u32);
+
+impl EventCategoryMask {
+    /// No category.
+    pub const NONE : EventCategoryMask = EventCategoryMask(0);
+
+    /// [Event::Keyboard] events.
+    pub const KEYBOARD : EventCategoryMask = EventCategoryMask(1 << 0);
+
+    /// [Event::Pointer] button and scroll events.
+    pub const POINTER_BUTTON : EventCategoryMask = EventCategoryMask(1 << 1);
+
+    /// [Event::Pointer] moved and acceleration events.
+    pub const POINTER_MOTION : EventCategoryMask = EventCategoryMask(1 << 2);
+
+    /// [EventWindow::CursorEnter] and [EventWindow::CursorLeave] events.
+    pub const CURSOR_CROSSING : EventCategoryMask = EventCategoryMask(1 << 3);
+
+    /// [EventWindow::Focus] and [EventWindow::Blur] events.
+    pub const FOCUS : EventCategoryMask = EventCategoryMask(1 << 4);
+
+    /// [EventWindow::Exposed] events.
+    pub const EXPOSE : EventCategoryMask = EventCategoryMask(1 << 5);
+
+    /// [EventWindow::Moved], [EventWindow::Resized] and [EventWindow::MovedResized] events.
+    pub const CONFIGURE : EventCategoryMask = EventCategoryMask(1 << 6);
+
+    /// [Event::Gamepad] events.
+    pub const GAMEPAD : EventCategoryMask = EventCategoryMask(1 << 7);
+
+    /// [Event::Pen] events.
+    pub const PEN : EventCategoryMask = EventCategoryMask(1 << 8);
+
+    /// [Event::Gesture] events.
+    pub const GESTURE : EventCategoryMask = EventCategoryMask(1 << 9);
+
+    /// All categories.
+    pub const ALL : EventCategoryMask = EventCategoryMask((1 << 10) - 1);
+
+    /// Returns true if all categories of other are in self.
+    pub fn contains(&self, other : EventCategoryMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns self without the categories of other.
+    pub fn without(&self, other : EventCategoryMask) -> EventCategoryMask {
+        EventCategoryMask(self.0 & !other.0)
+    }
+
+    /// Get the category of an event. 
+    /// 
+    /// Returns Some([EventCategoryMask]) or None if event can't be masked.
+    pub fn get_category(event : &Event) -> Option<EventCategoryMask> {
+        match event {
+            Event::Keyboard(..) => Some(Self::KEYBOARD),
+            Event::Pointer(EventPointer::Moved(_) | EventPointer::Acceleration(_), _) => Some(Self::POINTER_MOTION),
+            Event::Pointer(..) => Some(Self::POINTER_BUTTON),
+            Event::Window(EventWindow::CursorEnter | EventWindow::CursorLeave) => Some(Self::CURSOR_CROSSING),
+            Event::Window(EventWindow::Focus | EventWindow::Blur) => Some(Self::FOCUS),
+            Event::Window(EventWindow::Exposed(..)) => Some(Self::EXPOSE),
+            Event::Window(EventWindow::Moved(_) | EventWindow::Resized(_) | EventWindow::MovedResized(..)) => Some(Self::CONFIGURE),
+            Event::Gamepad(_) => Some(Self::GAMEPAD),
+            Event::Pen(_) => Some(Self::PEN),
+            Event::Gesture(_) => Some(Self::GESTURE),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the event is not masked.
+    pub fn accepts(&self, event : &Event) -> bool {
+        match Self::get_category(event) {
+            Some(category) => self.contains(category),
+            None => true,
+        }
+    }
+}
+
+impl std::ops::BitOr for EventCategoryMask {
+    type Output = EventCategoryMask;
+
+    fn bitor(self, rhs : EventCategoryMask) -> EventCategoryMask {
+        EventCategoryMask(self.0 | rhs.0)
+    }
 }
 
+/// Input device and time of an input event.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventSource {
+    /// Id of the [InputDevice](super::device::InputDevice) that generated the event. 0 if device is unknown.
+    pub device : u16,
 
+    /// Monotonic timestamp of the event in milliseconds. Only meaningful when compared with other events timestamp.
+    pub timestamp : u64,
+}
 
+impl EventSource {
+    /// Create a new [EventSource] from device id and timestamp.
+    pub fn new(device : u16, timestamp : u64) -> EventSource {
+        EventSource { device, timestamp }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+/// Enumeration of possible pen tools.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PenTool {
+    /// Tip of a stylus.
+    Pen,
+
+    /// Eraser end of a stylus.
+    Eraser,
+}
+
+/// State of a pen when an [EventPen] happened.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PenState {
+    /// Id of the device that generated the event.
+    pub device : u16,
+
+    /// [PenTool] used.
+    pub tool : PenTool,
+
+    /// Sub-pixel position (x, y) of the pen relative to the window.
+    pub position : (f64, f64),
+
+    /// Pressure applied on the tip, from 0.0 (no pressure) to 1.0 (full pressure).
+    pub pressure : f64,
+
+    /// Tilt (x, y) of the pen, from -1.0 to 1.0. (0.0, 0.0) means the pen is perpendicular to the tablet.
+    pub tilt : (f64, f64),
+
+    /// Rotation of the pen around its own axis in degrees, from 0.0 to 360.0.
+    pub rotation : f64,
+}
+
+impl PenState {
+    /// Create a new [PenState] for a device and tool with default values.
+    pub fn new(device : u16, tool : PenTool) -> PenState {
+        PenState { device, tool, position: (0.0, 0.0), pressure: 0.0, tilt: (0.0, 0.0), rotation: 0.0 }
+    }
+
+    /// Returns true if the eraser end of the stylus is used.
+    pub fn is_eraser(&self) -> bool {
+        self.tool == PenTool::Eraser
+    }
+}
+
+/// Enumeration of possible pen and graphics tablet events.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventPen {
+    /// Happens when a pen enter the tablet detection range. Provides the [PenState].
+    /// 
+    /// # Known issue(s)
+    /// * `(Linux only)` Only reliable with the Wacom driver. Other drivers send it on the first event of the pen.
+    ProximityIn(PenState),
+
+    /// Happens when a pen leave the tablet detection range. Provides the [PenState].
+    /// 
+    /// # Known issue(s)
+    /// * `(Linux only)` Only reliable with the Wacom driver. Other drivers send it when the pen leaves the window.
+    ProximityOut(PenState),
+
+    /// Happens when the pen tip touch the tablet. Provides the [PenState].
+    Down(PenState),
+
+    /// Happens when the pen tip is lifted from the tablet. Provides the [PenState].
+    Up(PenState),
+
+    /// Happens when the pen position, pressure, tilt or rotation changed. Provides the [PenState].
+    Moved(PenState),
+
+    /// Happens when a pen barrel button is pressed. Provides button number and the [PenState].
+    ButtonDown(u8, PenState),
+
+    /// Happens when a pen barrel button is released. Provides button number and the [PenState].
+    ButtonUp(u8, PenState),
+}
@@ -1,6 +1,7 @@
 
 /// Enumeration of possible pointer buttons
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerButton {
     /// Left button of a pointer device.
     LeftButton,
@@ -36,6 +37,7 @@ pub enum PointerButton {
 
 /// Enumeration of possible pointer events
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventPointer {
 
     /// Pointer move event. Provides new (x, y) position. Only when in pointer mode.
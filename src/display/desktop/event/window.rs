@@ -1,6 +1,7 @@
 
 /// Enumeration of possible events for a window
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventWindow {
 
     /// Happens when window is created.
@@ -13,7 +14,8 @@ pub enum EventWindow {
     Hidden,
 
     /// Happens when Window is exposed/damaged, meaning part of drawing is lost and need to be redraw.
-    /// Provides position (x, y) and size (width, height) of region exposed. 
+    /// Provides position (x, y) and size (width, height) of the bounding box of region exposed. Happens once per
+    /// series of exposures, rectangles of region are given by [Window::get_damage_region](crate::display::desktop::Window::get_damage_region).
     Exposed((i32, i32), (u32, u32)),
 
     /// Happens when Window is moved. Provides (x,y) of new position.
@@ -67,6 +69,14 @@ pub enum EventWindow {
     /// Happens when a Modal subwindow closed
     ModalClosed,
 
+    /// Happens once after [Window::request_redraw](crate::display::desktop::Window::request_redraw) was called,
+    /// when no other event is pending.
+    RedrawRequested,
+
+    /// Happens when the above, below, sticky, skip taskbar or skip pager state of Window is changed by the window
+    /// manager or the user. New states are given by [Window::get_properties](crate::display::desktop::Window::get_properties).
+    StateChanged,
+
 
 
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+//! Gamepad rumble and force feedback effects.
+//!
+//! Effects are uploaded to the gamepad with [Gamepad::play_effect](super::Gamepad::play_effect) which returns a
+//! [GamepadEffectId]. Effects are removed from the gamepad once finished, when stopped or when the gamepad
+//! is disconnected.
+
+use std::time::Duration;
+
+/// Longest duration of an effect. Longer durations are clamped.
+pub const EFFECT_MAX_DURATION : Duration = Duration::from_millis(0x7fff);
+
+/// Identifier of an effect played on a gamepad.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GamepadEffectId(pub(crate) u32);
+
+/// Enumeration of periodic effects waveforms.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadWaveform {
+    /// Square wave.
+    Square,
+
+    /// Triangle wave.
+    Triangle,
+
+    /// Sine wave.
+    Sine,
+
+    /// Sawtooth going up.
+    SawUp,
+
+    /// Sawtooth going down.
+    SawDown,
+}
+
+/// Enumeration of gamepad effects.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GamepadEffect {
+    /// Vibration of the strong (low frequency) and weak (high frequency) motors.
+    Rumble {
+        /// Magnitude of the strong motor (range: 0 to 65535).
+        strong : u16,
+
+        /// Magnitude of the weak motor (range: 0 to 65535).
+        weak : u16,
+
+        /// Duration of effect.
+        duration : Duration,
+    },
+
+    /// Periodic force following a waveform.
+    Periodic {
+        /// Waveform of force.
+        waveform : GamepadWaveform,
+
+        /// Period of waveform.
+        period : Duration,
+
+        /// Peak magnitude of force (range: -32768 to 32767).
+        magnitude : i16,
+
+        /// Mean value of force (range: -32768 to 32767).
+        offset : i16,
+
+        /// Duration of effect.
+        duration : Duration,
+    },
+}
+
+impl GamepadEffect {
+    /// Create a new rumble effect from strong and weak magnitudes between 0.0 and 1.0. Magnitudes are clamped.
+    pub fn rumble(strong : f32, weak : f32, duration : Duration) -> GamepadEffect {
+        GamepadEffect::Rumble { strong: Self::to_magnitude(strong), weak: Self::to_magnitude(weak), duration }
+    }
+
+    /// Create a new periodic effect from a magnitude between 0.0 and 1.0. Magnitude is clamped.
+    pub fn periodic(waveform : GamepadWaveform, period : Duration, magnitude : f32, duration : Duration) -> GamepadEffect {
+        let magnitude = (magnitude.clamp(0.0, 1.0) * i16::MAX as f32).round() as i16;
+        GamepadEffect::Periodic { waveform, period, magnitude, offset: 0, duration }
+    }
+
+    /// Returns the duration of effect, clamped to [EFFECT_MAX_DURATION].
+    pub fn get_duration(&self) -> Duration {
+        match self {
+            GamepadEffect::Rumble { duration, .. } | GamepadEffect::Periodic { duration, .. } => (*duration).min(EFFECT_MAX_DURATION),
+        }
+    }
+
+    /// Returns true if effect is shorter than a millisecond. Such effects are rejected since a zero length plays forever.
+    pub fn is_empty(&self) -> bool {
+        self.get_duration().as_millis() == 0
+    }
+
+    /// Convert a magnitude from 0.0..1.0 to 0..65535.
+    fn to_magnitude(magnitude : f32) -> u16 {
+        (magnitude.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+    }
+}
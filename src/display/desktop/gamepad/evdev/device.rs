@@ -0,0 +1,586 @@
+//! Evdev devices opening and hotplug monitoring with inotify.
+
+use std::collections::VecDeque;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_int, c_ulong, c_void};
+use std::time::Instant;
+
+use crate::{error::StudioError, display::DisplayError};
+use crate::display::desktop::event::gamepad::EventGamepad;
+use crate::display::desktop::gamepad::effect::{GamepadEffect, GamepadEffectId, GamepadWaveform};
+use crate::display::desktop::gamepad::state::GamepadSettings;
+use crate::display::desktop::manager::linux::cbind::{open, close, read, write, ioctl, inotify_init1, inotify_add_watch, InotifyEvent,
+    O_RDWR, O_RDONLY, O_NONBLOCK, O_CLOEXEC, IN_NONBLOCK, IN_CLOEXEC, IN_ATTRIB, IN_CREATE, IN_DELETE};
+
+use super::{EvdevDecoder, EvdevLayout, EvdevAxis, EvdevInputEvent, INPUT_EVENT_SIZE, EV_KEY, EV_ABS, EV_FF, BTN_JOYSTICK, BTN_DIGI, KEY_MAX, 
+    ABS_MAX, ABS_HAT0X, ABS_X, ABS_Y, ABS_Z, ABS_RX, ABS_RY, ABS_RZ, ABS_GAS, ABS_BRAKE, FF_RUMBLE, FF_PERIODIC, FF_SQUARE, FF_TRIANGLE, FF_SINE, FF_SAW_UP, FF_SAW_DOWN, FF_MAX};
+
+/// Directory containing input devices.
+pub(crate) const INPUT_DIRECTORY : &str = "/dev/input";
+
+/// Prefix of evdev devices names.
+const EVENT_DEVICE_PREFIX : &str = "event";
+
+/// Count of events read at once.
+const READ_EVENT_COUNT : usize = 64;
+
+/// Error returned by read when device has been removed.
+const ENODEV : i32 = 19;
+
+/// ioctl write direction.
+const IOC_WRITE : c_ulong = 1;
+
+/// ioctl read direction.
+const IOC_READ : c_ulong = 2;
+
+/// Returns an evdev ioctl request number.
+const fn eviocg(dir : c_ulong, nr : c_ulong, size : usize) -> c_ulong {
+    (dir << 30) | ((size as c_ulong) << 16) | ((b'E' as c_ulong) << 8) | nr
+}
+
+/// Get device identifiers.
+const EVIOCGID : c_ulong = eviocg(IOC_READ, 0x02, 8);
+
+/// Get device name.
+const fn eviocgname(len : usize) -> c_ulong { eviocg(IOC_READ, 0x06, len) }
+
+/// Get the state of all keys.
+const fn eviocgkey(len : usize) -> c_ulong { eviocg(IOC_READ, 0x18, len) }
+
+/// Get event bits of an event type.
+const fn eviocgbit(ev : u16, len : usize) -> c_ulong { eviocg(IOC_READ, 0x20 + ev as c_ulong, len) }
+
+/// Get absolute axis details.
+const fn eviocgabs(abs : u16) -> c_ulong { eviocg(IOC_READ, 0x40 + abs as c_ulong, std::mem::size_of::<InputAbsInfo>()) }
+
+/// Upload a force feedback effect.
+const EVIOCSFF : c_ulong = eviocg(IOC_WRITE, 0x80, std::mem::size_of::<FfEffect>());
+
+/// Remove a force feedback effect.
+const EVIOCRMFF : c_ulong = eviocg(IOC_WRITE, 0x81, std::mem::size_of::<c_int>());
+
+/// Get count of force feedback effects playable at once.
+const EVIOCGEFFECTS : c_ulong = eviocg(IOC_READ, 0x84, std::mem::size_of::<c_int>());
+
+/// Device identifiers.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct InputId {
+    pub bustype : u16,
+    pub vendor : u16,
+    pub product : u16,
+    pub version : u16,
+}
+
+/// Absolute axis details.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct InputAbsInfo {
+    value : i32,
+    minimum : i32,
+    maximum : i32,
+    fuzz : i32,
+    flat : i32,
+    resolution : i32,
+}
+
+/// Force feedback effect trigger.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+struct FfTrigger {
+    button : u16,
+    interval : u16,
+}
+
+/// Force feedback effect scheduling.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+struct FfReplay {
+    length : u16,
+    delay : u16,
+}
+
+/// Force feedback effect envelope.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+struct FfEnvelope {
+    attack_length : u16,
+    attack_level : u16,
+    fade_length : u16,
+    fade_level : u16,
+}
+
+/// Force feedback periodic effect.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct FfPeriodicEffect {
+    waveform : u16,
+    period : u16,
+    magnitude : i16,
+    offset : i16,
+    phase : u16,
+    envelope : FfEnvelope,
+    custom_len : u32,
+    custom_data : *mut i16,
+}
+
+/// Force feedback rumble effect.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+struct FfRumbleEffect {
+    strong_magnitude : u16,
+    weak_magnitude : u16,
+}
+
+/// Force feedback effect details. Periodic is the largest member of the kernel union.
+#[repr(C)]
+#[derive(Clone, Copy)]
+union FfEffectDetails {
+    periodic : FfPeriodicEffect,
+    rumble : FfRumbleEffect,
+}
+
+/// Force feedback effect `ff_effect`.
+///
+/// Reference(s)
+/// <https://www.kernel.org/doc/html/latest/input/ff.html>
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct FfEffect {
+    kind : u16,
+    id : i16,
+    direction : u16,
+    trigger : FfTrigger,
+    replay : FfReplay,
+    details : FfEffectDetails,
+}
+
+impl FfEffect {
+    /// Create a new [FfEffect] from a [GamepadEffect]. Id -1 let the kernel allocate an effect id.
+    fn new(effect : &GamepadEffect) -> FfEffect {
+        let replay = FfReplay { length: effect.get_duration().as_millis() as u16, delay: 0 };
+
+        let (kind, details) = match *effect {
+            GamepadEffect::Rumble { strong, weak, .. } => 
+                (FF_RUMBLE, FfEffectDetails { rumble: FfRumbleEffect { strong_magnitude: strong, weak_magnitude: weak } }),
+            GamepadEffect::Periodic { waveform, period, magnitude, offset, .. } => 
+                (FF_PERIODIC, FfEffectDetails { periodic: FfPeriodicEffect { waveform: get_ff_waveform(waveform), 
+                    period: period.as_millis().min(u16::MAX as u128) as u16, magnitude, offset, phase: 0, 
+                    envelope: FfEnvelope::default(), custom_len: 0, custom_data: std::ptr::null_mut() } }),
+        };
+
+        FfEffect { kind, id: -1, direction: 0, trigger: FfTrigger::default(), replay, details }
+    }
+}
+
+/// Returns the force feedback code of a [GamepadWaveform].
+fn get_ff_waveform(waveform : GamepadWaveform) -> u16 {
+    match waveform {
+        GamepadWaveform::Square => FF_SQUARE,
+        GamepadWaveform::Triangle => FF_TRIANGLE,
+        GamepadWaveform::Sine => FF_SINE,
+        GamepadWaveform::SawUp => FF_SAW_UP,
+        GamepadWaveform::SawDown => FF_SAW_DOWN,
+    }
+}
+
+/// Force feedback effect uploaded to a device.
+struct EvdevEffect {
+    /// Handle returned to the user.
+    handle : u32,
+
+    /// Id allocated by the kernel.
+    id : i16,
+
+    /// Time when effect ends.
+    end : Instant,
+}
+
+/// Returns true if bit is set into a bits array.
+#[inline(always)]
+fn test_bit(bits : &[u8], bit : u16) -> bool {
+    bits[(bit / 8) as usize] & (1 << (bit % 8)) != 0
+}
+
+/// Gamepad opened from an evdev device.
+pub(crate) struct EvdevGamepad {
+    /// File descriptor of device.
+    pub fd : c_int,
+
+    /// Path of device.
+    pub path : String,
+
+    /// Name of device.
+    pub name : String,
+
+    /// Identifiers of device.
+    pub ids : InputId,
+
+    /// Decoder of device events.
+    pub decoder : EvdevDecoder,
+
+    /// Force feedback effects supported. Empty if device is read only.
+    ff_bits : [u8; (FF_MAX as usize / 8) + 1],
+
+    /// Count of effects playable at once.
+    ff_capacity : usize,
+
+    /// Effects uploaded to device.
+    effects : Vec<EvdevEffect>,
+
+    /// Next effect handle.
+    next_handle : u32,
+}
+
+impl EvdevGamepad {
+    /// Open the device at path.
+    ///
+    /// Returns Some([EvdevGamepad]) if device can be read and is a joystick or gamepad, None otherwise.
+    pub fn open(path : &str) -> Option<EvdevGamepad> {
+        unsafe {
+            let cpath = CString::new(path).ok()?;
+
+            // Read and write is needed for force feedback. Fallback to read only.
+            let mut fd = open(cpath.as_ptr(), O_RDWR | O_NONBLOCK | O_CLOEXEC);
+            let writable = fd >= 0;
+            if fd < 0 {
+                fd = open(cpath.as_ptr(), O_RDONLY | O_NONBLOCK | O_CLOEXEC);
+                if fd < 0 {
+                    return None;
+                }
+            }
+
+            match Self::query_layout(fd) {
+                Some(layout) => {
+                    let mut name = [0u8; 256];
+                    ioctl(fd, eviocgname(name.len()), name.as_mut_ptr());
+                    let name = CStr::from_bytes_until_nul(&name).map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+
+                    let mut ids = InputId::default();
+                    ioctl(fd, EVIOCGID, &mut ids as *mut InputId);
+
+                    let mut ff_bits = [0u8; (FF_MAX as usize / 8) + 1];
+                    let mut ff_capacity : c_int = 0;
+                    if writable && ioctl(fd, eviocgbit(EV_FF, ff_bits.len()), ff_bits.as_mut_ptr()) >= 0 {
+                        ioctl(fd, EVIOCGEFFECTS, &mut ff_capacity as *mut c_int);
+                    }
+
+                    Some(EvdevGamepad { fd, path: path.to_string(), name, ids, decoder: EvdevDecoder::new(layout), ff_bits, 
+                        ff_capacity: ff_capacity.max(0) as usize, effects: Vec::new(), next_handle: 0 })
+                },
+                None => {
+                    close(fd);
+                    None
+                },
+            }
+        }
+    }
+
+    /// Query buttons and axes of device.
+    ///
+    /// Returns Some([EvdevLayout]) if device has joystick or gamepad buttons, None otherwise.
+    fn query_layout(fd : c_int) -> Option<EvdevLayout> {
+        unsafe {
+            let mut key_bits = [0u8; (KEY_MAX as usize / 8) + 1];
+            let mut abs_bits = [0u8; (ABS_MAX as usize / 8) + 1];
+
+            if ioctl(fd, eviocgbit(EV_KEY, key_bits.len()), key_bits.as_mut_ptr()) < 0 {
+                return None;
+            }
+            ioctl(fd, eviocgbit(EV_ABS, abs_bits.len()), abs_bits.as_mut_ptr());
+
+            // Joysticks and gamepads have at least one button between BTN_JOYSTICK and BTN_DIGI.
+            if !(BTN_JOYSTICK..BTN_DIGI).any(|code| test_bit(&key_bits, code)) {
+                return None;
+            }
+
+            let buttons : Vec<u16> = (0..KEY_MAX).filter(|code| test_bit(&key_bits, *code)).collect();
+            let axes : Vec<EvdevAxis> = (0..ABS_MAX).filter(|code| test_bit(&abs_bits, *code)).map(|code| {
+                let mut info = InputAbsInfo::default();
+                ioctl(fd, eviocgabs(code), &mut info as *mut InputAbsInfo);
+                EvdevAxis::new(code, info.minimum, info.maximum)
+            }).collect();
+
+            Some(EvdevLayout::new(buttons, axes))
+        }
+    }
+
+    /// Get raw axes ids (x, y) of each hat of device.
+    pub fn get_hats(&self) -> Vec<(u8, u8)> {
+        let layout = self.decoder.get_layout();
+
+        (0..4).filter_map(|hat| {
+            let x = layout.get_axis_id(ABS_HAT0X + hat * 2);
+            let y = layout.get_axis_id(ABS_HAT0X + hat * 2 + 1);
+            match (x, y) {
+                (None, None) => None,
+                (x, y) => Some((x.unwrap_or(u8::MAX), y.unwrap_or(u8::MAX))),
+            }
+        }).collect()
+    }
+
+    /// Get default settings with sticks on (ABS_X, ABS_Y), (ABS_RX, ABS_RY) and triggers on ABS_Z, ABS_RZ, ABS_GAS, ABS_BRAKE.
+    pub fn get_default_settings(&self) -> GamepadSettings {
+        let layout = self.decoder.get_layout();
+
+        let sticks = [(ABS_X, ABS_Y), (ABS_RX, ABS_RY)].iter()
+            .filter_map(|(x, y)| Some((layout.get_axis_id(*x)?, layout.get_axis_id(*y)?))).collect();
+        let triggers = [ABS_Z, ABS_RZ, ABS_GAS, ABS_BRAKE].iter().filter_map(|code| layout.get_axis_id(*code)).collect();
+
+        GamepadSettings::new(sticks, triggers)
+    }
+
+    /// Returns true if device can play effect.
+    pub fn is_effect_supported(&self, effect : &GamepadEffect) -> bool {
+        self.ff_capacity > 0 && match effect {
+            GamepadEffect::Rumble { .. } => test_bit(&self.ff_bits, FF_RUMBLE),
+            GamepadEffect::Periodic { waveform, .. } => test_bit(&self.ff_bits, FF_PERIODIC) && test_bit(&self.ff_bits, get_ff_waveform(*waveform)),
+        }
+    }
+
+    /// Upload and play an effect. If all slots are used, the oldest effect is stopped.
+    ///
+    /// Returns Ok([GamepadEffectId]) on success, Err([StudioError]) if effect is not supported, empty or failed.
+    pub fn play_effect(&mut self, effect : &GamepadEffect) -> Result<GamepadEffectId, StudioError> {
+        if effect.is_empty() || !self.is_effect_supported(effect) {     // Zero length is played forever by the kernel.
+            return Err(StudioError::Display(DisplayError::GamepadEffectNotSupported));
+        }
+
+        self.remove_finished_effects();
+        if self.effects.len() >= self.ff_capacity {
+            let oldest = self.effects.remove(0);
+            self.remove_effect(&oldest);
+        }
+
+        unsafe {
+            let mut ff_effect = FfEffect::new(effect);
+            if ioctl(self.fd, EVIOCSFF, &mut ff_effect as *mut FfEffect) < 0 {
+                return Err(StudioError::Display(DisplayError::GamepadEffectFailed));
+            }
+
+            let evdev_effect = EvdevEffect { handle: self.next_handle, id: ff_effect.id, end: Instant::now() + effect.get_duration() };
+            if !self.write_event(EvdevInputEvent::new(EV_FF, ff_effect.id as u16, 1)) {
+                self.remove_effect(&evdev_effect);
+                return Err(StudioError::Display(DisplayError::GamepadEffectFailed));
+            }
+
+            self.next_handle = self.next_handle.wrapping_add(1);
+            self.effects.push(evdev_effect);
+            Ok(GamepadEffectId(self.next_handle.wrapping_sub(1)))
+        }
+    }
+
+    /// Stop and remove an effect.
+    ///
+    /// Returns true if effect was playing.
+    pub fn stop_effect(&mut self, effect : GamepadEffectId) -> bool {
+        match self.effects.iter().position(|evdev_effect| evdev_effect.handle == effect.0) {
+            Some(index) => {
+                let evdev_effect = self.effects.remove(index);
+                self.remove_effect(&evdev_effect);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Stop and remove all effects.
+    pub fn stop_all_effects(&mut self) {
+        for evdev_effect in std::mem::take(&mut self.effects) {
+            self.remove_effect(&evdev_effect);
+        }
+    }
+
+    /// Remove effects that ended to free device slots.
+    pub fn remove_finished_effects(&mut self) {
+        let now = Instant::now();
+        let (finished, playing) : (Vec<EvdevEffect>, Vec<EvdevEffect>) = std::mem::take(&mut self.effects)
+            .into_iter().partition(|evdev_effect| evdev_effect.end <= now);
+        self.effects = playing;
+
+        for evdev_effect in finished {
+            self.remove_effect(&evdev_effect);
+        }
+    }
+
+    /// Stop effect and free its device slot.
+    fn remove_effect(&self, evdev_effect : &EvdevEffect) {
+        unsafe {
+            self.write_event(EvdevInputEvent::new(EV_FF, evdev_effect.id as u16, 0));
+            ioctl(self.fd, EVIOCRMFF, evdev_effect.id as c_int);
+        }
+    }
+
+    /// Write an event to device.
+    ///
+    /// Returns true if event was written.
+    fn write_event(&self, event : EvdevInputEvent) -> bool {
+        let bytes = event.to_bytes();
+        unsafe { write(self.fd, bytes.as_ptr() as *const c_void, bytes.len()) == bytes.len() as isize }
+    }
+
+    /// Read the state of device after dropped events and push the changes into queue.
+    ///
+    /// Reference(s)
+    /// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-syn>
+    fn resync(&mut self, id : u8, queue : &mut VecDeque<EventGamepad>) {
+        unsafe {
+            let mut key_bits = [0u8; (KEY_MAX as usize / 8) + 1];
+            ioctl(self.fd, eviocgkey(key_bits.len()), key_bits.as_mut_ptr());
+
+            let layout = self.decoder.get_layout();
+            let pressed : Vec<u16> = layout.get_buttons().iter().copied().filter(|code| test_bit(&key_bits, *code)).collect();
+            let axes : Vec<(u16, i32)> = layout.get_axes().iter().map(|axis| {
+                let mut info = InputAbsInfo::default();
+                ioctl(self.fd, eviocgabs(axis.code), &mut info as *mut InputAbsInfo);
+                (axis.code, info.value)
+            }).collect();
+
+            queue.extend(self.decoder.resync(id, &pressed, &axes));
+        }
+    }
+
+    /// Read all pending events of device and push decoded events into queue.
+    ///
+    /// Returns false if device has been disconnected.
+    pub fn read_events(&mut self, id : u8, queue : &mut VecDeque<EventGamepad>) -> bool {
+        unsafe {
+            let mut buffer = [0u8; INPUT_EVENT_SIZE * READ_EVENT_COUNT];
+
+            loop {
+                let count = read(self.fd, buffer.as_mut_ptr() as *mut c_void, buffer.len());
+
+                if count > 0 {
+                    for event in EvdevInputEvent::from_bytes(&buffer[0..count as usize]) {
+                        if let Some(event) = self.decoder.decode(id, &event) {
+                            queue.push_back(event);
+                        }
+                        if self.decoder.needs_resync() {
+                            self.resync(id, queue);
+                        }
+                    }
+                } else if count == 0 {
+                    return true;
+                } else {
+                    // EAGAIN means no more events.
+                    return std::io::Error::last_os_error().raw_os_error() != Some(ENODEV);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for EvdevGamepad {
+    fn drop(&mut self) {
+        self.stop_all_effects();
+
+        unsafe {
+            close(self.fd);
+        }
+    }
+}
+
+/// Change of the input directory.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum EvdevChange {
+    /// Device was added or its permissions changed.
+    Added(String),
+
+    /// Device was removed.
+    Removed(String),
+}
+
+/// Monitor evdev devices plugged and unplugged with inotify.
+pub(crate) struct EvdevMonitor {
+    /// Inotify file descriptor. Negative if inotify is not available.
+    pub fd : c_int,
+}
+
+impl EvdevMonitor {
+    /// Create a new [EvdevMonitor] watching the input directory.
+    pub fn new() -> EvdevMonitor {
+        unsafe {
+            let fd = inotify_init1(IN_NONBLOCK | IN_CLOEXEC);
+
+            if fd >= 0 {
+                let directory = CString::new(INPUT_DIRECTORY).unwrap();
+                if inotify_add_watch(fd, directory.as_ptr(), IN_CREATE | IN_DELETE | IN_ATTRIB) < 0 {
+                    close(fd);
+                    return EvdevMonitor { fd: -1 };
+                }
+            }
+
+            EvdevMonitor { fd }
+        }
+    }
+
+    /// Create a [EvdevMonitor] that never report devices.
+    pub fn disabled() -> EvdevMonitor {
+        EvdevMonitor { fd: -1 }
+    }
+
+    /// Returns the paths of all evdev devices, sorted by name.
+    pub fn scan(&self) -> Vec<String> {
+        let mut paths : Vec<String> = match std::fs::read_dir(INPUT_DIRECTORY) {
+            Ok(entries) => entries.filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| name.starts_with(EVENT_DEVICE_PREFIX))
+                .map(|name| format!("{}/{}", INPUT_DIRECTORY, name)).collect(),
+            Err(_) => Vec::new(),
+        };
+
+        // Sort event2 before event10.
+        paths.sort_by_key(|path| (path.len(), path.clone()));
+        paths
+    }
+
+    /// Read pending changes of the input directory.
+    pub fn read_changes(&self) -> Vec<EvdevChange> {
+        let mut changes : Vec<EvdevChange> = Vec::new();
+
+        if self.fd < 0 {
+            return changes;
+        }
+
+        unsafe {
+            let mut buffer = [0u8; 4096];
+            let header = std::mem::size_of::<InotifyEvent>();
+
+            loop {
+                let count = read(self.fd, buffer.as_mut_ptr() as *mut c_void, buffer.len());
+                if count <= 0 {
+                    break;
+                }
+
+                let mut offset : usize = 0;
+                while offset + header <= count as usize {
+                    let event = std::ptr::read_unaligned(buffer.as_ptr().add(offset) as *const InotifyEvent);
+                    let name = &buffer[offset + header..offset + header + event.len as usize];
+                    let name = CStr::from_bytes_until_nul(name).map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+                    offset += header + event.len as usize;
+
+                    if name.starts_with(EVENT_DEVICE_PREFIX) {
+                        let path = format!("{}/{}", INPUT_DIRECTORY, name);
+                        if event.mask & IN_DELETE != 0 {
+                            changes.push(EvdevChange::Removed(path));
+                        } else {
+                            changes.push(EvdevChange::Added(path));
+                        }
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+impl Drop for EvdevMonitor {
+    fn drop(&mut self) {
+        if self.fd >= 0 {
+            unsafe {
+                close(self.fd);
+            }
+        }
+    }
+}
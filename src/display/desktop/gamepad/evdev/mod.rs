@@ -0,0 +1,315 @@
+//! Linux evdev gamepads decoding.
+//!
+//! Raw `input_event` read from `/dev/input/event*` are decoded by an [EvdevDecoder] into [EventGamepad].
+//! Buttons and axes are numbered like SDL joysticks so that ids match SDL mappings.
+//!
+//! Reference(s)
+//! <https://www.kernel.org/doc/html/latest/input/event-codes.html>
+
+use std::mem::size_of;
+use std::os::raw::c_long;
+
+use crate::display::desktop::event::gamepad::EventGamepad;
+
+/// Contains evdev devices opening and hotplug monitoring.
+pub(crate) mod device;
+
+// Event types.
+pub const EV_SYN : u16 = 0x00;
+pub const EV_KEY : u16 = 0x01;
+pub const EV_ABS : u16 = 0x03;
+pub const EV_FF : u16 = 0x15;
+
+// Synchronization events.
+pub const SYN_REPORT : u16 = 0;
+pub const SYN_DROPPED : u16 = 3;
+
+// Buttons codes.
+pub const BTN_MISC : u16 = 0x100;
+pub const BTN_JOYSTICK : u16 = 0x120;
+pub const BTN_GAMEPAD : u16 = 0x130;
+pub const BTN_DIGI : u16 = 0x140;
+pub const KEY_MAX : u16 = 0x2ff;
+
+// Force feedback effects codes.
+pub const FF_RUMBLE : u16 = 0x50;
+pub const FF_PERIODIC : u16 = 0x51;
+pub const FF_SQUARE : u16 = 0x58;
+pub const FF_TRIANGLE : u16 = 0x59;
+pub const FF_SINE : u16 = 0x5a;
+pub const FF_SAW_UP : u16 = 0x5b;
+pub const FF_SAW_DOWN : u16 = 0x5c;
+pub const FF_MAX : u16 = 0x7f;
+
+// Axes codes.
+pub const ABS_X : u16 = 0x00;
+pub const ABS_Y : u16 = 0x01;
+pub const ABS_Z : u16 = 0x02;
+pub const ABS_RX : u16 = 0x03;
+pub const ABS_RY : u16 = 0x04;
+pub const ABS_RZ : u16 = 0x05;
+pub const ABS_GAS : u16 = 0x09;
+pub const ABS_BRAKE : u16 = 0x0a;
+pub const ABS_HAT0X : u16 = 0x10;
+pub const ABS_HAT3Y : u16 = 0x17;
+pub const ABS_MAX : u16 = 0x3f;
+
+/// Size in bytes of a raw `input_event`.
+pub const INPUT_EVENT_SIZE : usize = size_of::<RawInputEvent>();
+
+/// Raw `input_event` struct as read from the device.
+#[repr(C)]
+struct RawInputEvent {
+    tv_sec : c_long,
+    tv_usec : c_long,
+    kind : u16,
+    code : u16,
+    value : i32,
+}
+
+/// Decoded evdev `input_event` without time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EvdevInputEvent {
+    /// Type of event (EV_KEY, EV_ABS, ...)
+    pub kind : u16,
+
+    /// Code of the button or axis.
+    pub code : u16,
+
+    /// Value of event.
+    pub value : i32,
+}
+
+impl EvdevInputEvent {
+    /// Create a new [EvdevInputEvent].
+    pub fn new(kind : u16, code : u16, value : i32) -> EvdevInputEvent {
+        EvdevInputEvent { kind, code, value }
+    }
+
+    /// Decode a byte stream of raw `input_event`. Trailing incomplete event is ignored.
+    pub fn from_bytes(bytes : &[u8]) -> Vec<EvdevInputEvent> {
+        // Time is 2 c_long.
+        let offset = 2 * size_of::<c_long>();
+
+        bytes.chunks_exact(INPUT_EVENT_SIZE).map(|raw| EvdevInputEvent {
+            kind: u16::from_ne_bytes([raw[offset], raw[offset + 1]]),
+            code: u16::from_ne_bytes([raw[offset + 2], raw[offset + 3]]),
+            value: i32::from_ne_bytes([raw[offset + 4], raw[offset + 5], raw[offset + 6], raw[offset + 7]]),
+        }).collect()
+    }
+
+    /// Encode into a raw `input_event` with time set to 0.
+    pub fn to_bytes(&self) -> [u8; INPUT_EVENT_SIZE] {
+        let offset = 2 * size_of::<c_long>();
+        let mut bytes = [0; INPUT_EVENT_SIZE];
+
+        bytes[offset..offset + 2].copy_from_slice(&self.kind.to_ne_bytes());
+        bytes[offset + 2..offset + 4].copy_from_slice(&self.code.to_ne_bytes());
+        bytes[offset + 4..offset + 8].copy_from_slice(&self.value.to_ne_bytes());
+
+        bytes
+    }
+}
+
+/// Absolute axis of an evdev device.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EvdevAxis {
+    /// Code of axis (ABS_X, ABS_Y, ...)
+    pub code : u16,
+
+    /// Minimum value reported by device.
+    pub min : i32,
+
+    /// Maximum value reported by device.
+    pub max : i32,
+}
+
+impl EvdevAxis {
+    /// Create a new [EvdevAxis].
+    pub fn new(code : u16, min : i32, max : i32) -> EvdevAxis {
+        EvdevAxis { code, min, max }
+    }
+
+    /// Returns true if axis is a hat (directional pad).
+    pub fn is_hat(&self) -> bool {
+        (ABS_HAT0X..=ABS_HAT3Y).contains(&self.code)
+    }
+
+    /// Normalize a device value to -32768..32767. The center of the axis range is always 0.
+    pub fn normalize(&self, value : i32) -> i16 {
+        if self.max <= self.min {
+            return 0;
+        }
+
+        let center = (self.min as f64 + self.max as f64) / 2.0;
+        let half = (self.max as f64 - self.min as f64) / 2.0;
+        let value = ((value as f64 - center) / half).clamp(-1.0, 1.0);
+
+        if value < 0.0 {
+            (value * 32768.0).round() as i16
+        } else {
+            (value * 32767.0).round() as i16
+        }
+    }
+}
+
+/// Buttons and axes of an evdev device, ordered like SDL joysticks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvdevLayout {
+    /// Buttons codes. Index is the button id.
+    buttons : Vec<u16>,
+
+    /// Axes. Index is the axis id. Hats come after all other axes.
+    axes : Vec<EvdevAxis>,
+}
+
+impl EvdevLayout {
+    /// Create a new [EvdevLayout] from buttons codes and axes.
+    ///
+    /// Buttons are sorted from BTN_JOYSTICK to KEY_MAX then from 0 to BTN_JOYSTICK. Axes are sorted
+    /// by code with hats at the end.
+    pub fn new(mut buttons : Vec<u16>, mut axes : Vec<EvdevAxis>) -> EvdevLayout {
+        buttons.sort_by_key(|code| (*code < BTN_JOYSTICK, *code));
+        buttons.dedup();
+        axes.sort_by_key(|axis| (axis.is_hat(), axis.code));
+        axes.dedup_by_key(|axis| axis.code);
+
+        EvdevLayout { buttons, axes }
+    }
+
+    /// Get buttons codes. Index is the button id.
+    pub fn get_buttons(&self) -> &Vec<u16> {
+        &self.buttons
+    }
+
+    /// Get axes. Index is the axis id.
+    pub fn get_axes(&self) -> &Vec<EvdevAxis> {
+        &self.axes
+    }
+
+    /// Get the button id of a button code.
+    pub fn get_button_id(&self, code : u16) -> Option<u8> {
+        self.buttons.iter().position(|button| *button == code).map(|id| id as u8)
+    }
+
+    /// Get the axis id of an axis code.
+    pub fn get_axis_id(&self, code : u16) -> Option<u8> {
+        self.axes.iter().position(|axis| axis.code == code).map(|id| id as u8)
+    }
+}
+
+/// Decode [EvdevInputEvent] of a device into [EventGamepad].
+#[derive(Debug, Clone)]
+pub struct EvdevDecoder {
+    /// Layout of device.
+    layout : EvdevLayout,
+
+    /// Last normalized value of each axis.
+    values : Vec<i16>,
+
+    /// Last state of each button.
+    pressed : Vec<bool>,
+
+    /// Events are dropped until next SYN_REPORT since kernel buffer overflowed.
+    dropped : bool,
+
+    /// Device state must be read again since events were dropped.
+    resync : bool,
+}
+
+impl EvdevDecoder {
+    /// Create a new [EvdevDecoder] for a device layout.
+    pub fn new(layout : EvdevLayout) -> EvdevDecoder {
+        let values = vec![0; layout.axes.len()];
+        let pressed = vec![false; layout.buttons.len()];
+        EvdevDecoder { layout, values, pressed, dropped: false, resync: false }
+    }
+
+    /// Get the layout of the device.
+    pub fn get_layout(&self) -> &EvdevLayout {
+        &self.layout
+    }
+
+    /// Returns true if events were dropped and the device state must be given to [EvdevDecoder::resync].
+    pub fn needs_resync(&self) -> bool {
+        self.resync
+    }
+
+    /// Compare the device state read after dropped events with the last known state.
+    ///
+    /// pressed contains the codes of the buttons currently pressed and axes the current (code, value) of axes.
+    ///
+    /// Returns the [EventGamepad] of buttons and axes that changed while events were dropped.
+    pub fn resync(&mut self, id : u8, pressed : &[u16], axes : &[(u16, i32)]) -> Vec<EventGamepad> {
+        self.resync = false;
+
+        let mut events = Vec::new();
+        for button in 0..self.layout.buttons.len() {
+            let down = pressed.contains(&self.layout.buttons[button]);
+            if self.pressed[button] != down {
+                self.pressed[button] = down;
+                events.push(if down { EventGamepad::ButtonDown(id, button as u8) } else { EventGamepad::ButtonUp(id, button as u8) });
+            }
+        }
+
+        for (code, value) in axes {
+            if let Some(event) = self.decode_axis(id, *code, *value) {
+                events.push(event);
+            }
+        }
+
+        events
+    }
+
+    /// Decode an event of the gamepad id.
+    ///
+    /// After a SYN_DROPPED, events are dropped until the next SYN_REPORT then [EvdevDecoder::needs_resync] returns true.
+    ///
+    /// Returns Some([EventGamepad]) if event changed a button or an axis, None otherwise.
+    pub fn decode(&mut self, id : u8, event : &EvdevInputEvent) -> Option<EventGamepad> {
+        match event.kind {
+            EV_SYN => {
+                match event.code {
+                    SYN_DROPPED => self.dropped = true,
+                    SYN_REPORT if self.dropped => {
+                        self.dropped = false;
+                        self.resync = true;
+                    },
+                    _ => {},
+                }
+                None
+            },
+            _ if self.dropped => None,
+            EV_KEY => {
+                let button = self.layout.get_button_id(event.code)?;
+                let down = match event.value {
+                    0 => false,
+                    1 => true,
+                    _ => return None,      // Auto-repeat
+                };
+
+                if self.pressed[button as usize] != down {
+                    self.pressed[button as usize] = down;
+                    Some(if down { EventGamepad::ButtonDown(id, button) } else { EventGamepad::ButtonUp(id, button) })
+                } else {
+                    None
+                }
+            },
+            EV_ABS => self.decode_axis(id, event.code, event.value),
+            _ => None,
+        }
+    }
+
+    /// Decode an axis value, returning an [EventGamepad] if its normalized value changed.
+    fn decode_axis(&mut self, id : u8, code : u16, value : i32) -> Option<EventGamepad> {
+        let axis = self.layout.get_axis_id(code)?;
+        let value = self.layout.axes[axis as usize].normalize(value);
+
+        if self.values[axis as usize] != value {
+            self.values[axis as usize] = value;
+            Some(EventGamepad::Axis(id, axis, value))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! SDL GameControllerDB mappings of gamepads.
+//!
+//! A mapping line is `GUID,name,binding:input,...,platform:Linux,` where each binding associate a named
+//! [GamepadButton] or [GamepadAxis] to a raw button (`b0`), axis (`a0`, `+a0`, `-a0`, `a0~`) or hat (`h0.1`).
+//!
+//! Reference(s)
+//! <https://github.com/mdqinc/SDL_GameControllerDB>
+
+use std::fmt::Display;
+
+use crate::{error::StudioError, display::DisplayError};
+use crate::display::desktop::event::gamepad::EventGamepad;
+
+/// Platform name of mappings used.
+const MAPPING_PLATFORM : &str = "Linux";
+
+/// Axis value over which a button mapped from an axis is pressed.
+const AXIS_PRESS_THRESHOLD : i32 = 16384;
+
+/// Enumeration of named gamepad buttons.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadButton {
+    /// Bottom face button (A on Xbox, Cross on PlayStation).
+    A,
+
+    /// Right face button (B on Xbox, Circle on PlayStation).
+    B,
+
+    /// Left face button (X on Xbox, Square on PlayStation).
+    X,
+
+    /// Top face button (Y on Xbox, Triangle on PlayStation).
+    Y,
+
+    /// Back or select button.
+    Back,
+
+    /// Guide or home button.
+    Guide,
+
+    /// Start button.
+    Start,
+
+    /// Left stick click.
+    LeftStick,
+
+    /// Right stick click.
+    RightStick,
+
+    /// Left shoulder (bumper).
+    LeftShoulder,
+
+    /// Right shoulder (bumper).
+    RightShoulder,
+
+    /// Directional pad up.
+    DPadUp,
+
+    /// Directional pad down.
+    DPadDown,
+
+    /// Directional pad left.
+    DPadLeft,
+
+    /// Directional pad right.
+    DPadRight,
+
+    /// Miscellaneous button (share, capture, microphone...).
+    Misc1,
+
+    /// Upper left paddle.
+    Paddle1,
+
+    /// Upper right paddle.
+    Paddle2,
+
+    /// Lower left paddle.
+    Paddle3,
+
+    /// Lower right paddle.
+    Paddle4,
+
+    /// Touchpad click.
+    Touchpad,
+}
+
+impl GamepadButton {
+    /// Get [GamepadButton] from its SDL mapping name.
+    pub fn from_sdl_name(name : &str) -> Option<GamepadButton> {
+        match name {
+            "a" => Some(GamepadButton::A),
+            "b" => Some(GamepadButton::B),
+            "x" => Some(GamepadButton::X),
+            "y" => Some(GamepadButton::Y),
+            "back" => Some(GamepadButton::Back),
+            "guide" => Some(GamepadButton::Guide),
+            "start" => Some(GamepadButton::Start),
+            "leftstick" => Some(GamepadButton::LeftStick),
+            "rightstick" => Some(GamepadButton::RightStick),
+            "leftshoulder" => Some(GamepadButton::LeftShoulder),
+            "rightshoulder" => Some(GamepadButton::RightShoulder),
+            "dpup" => Some(GamepadButton::DPadUp),
+            "dpdown" => Some(GamepadButton::DPadDown),
+            "dpleft" => Some(GamepadButton::DPadLeft),
+            "dpright" => Some(GamepadButton::DPadRight),
+            "misc1" => Some(GamepadButton::Misc1),
+            "paddle1" => Some(GamepadButton::Paddle1),
+            "paddle2" => Some(GamepadButton::Paddle2),
+            "paddle3" => Some(GamepadButton::Paddle3),
+            "paddle4" => Some(GamepadButton::Paddle4),
+            "touchpad" => Some(GamepadButton::Touchpad),
+            _ => None,
+        }
+    }
+}
+
+/// Enumeration of named gamepad axes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadAxis {
+    /// Left stick horizontal axis, from -32768 (left) to 32767 (right).
+    LeftX,
+
+    /// Left stick vertical axis, from -32768 (up) to 32767 (down).
+    LeftY,
+
+    /// Right stick horizontal axis, from -32768 (left) to 32767 (right).
+    RightX,
+
+    /// Right stick vertical axis, from -32768 (up) to 32767 (down).
+    RightY,
+
+    /// Left trigger, from 0 (released) to 32767 (fully pressed).
+    LeftTrigger,
+
+    /// Right trigger, from 0 (released) to 32767 (fully pressed).
+    RightTrigger,
+}
+
+impl GamepadAxis {
+    /// Get [GamepadAxis] from its SDL mapping name.
+    pub fn from_sdl_name(name : &str) -> Option<GamepadAxis> {
+        match name {
+            "leftx" => Some(GamepadAxis::LeftX),
+            "lefty" => Some(GamepadAxis::LeftY),
+            "rightx" => Some(GamepadAxis::RightX),
+            "righty" => Some(GamepadAxis::RightY),
+            "lefttrigger" => Some(GamepadAxis::LeftTrigger),
+            "righttrigger" => Some(GamepadAxis::RightTrigger),
+            _ => None,
+        }
+    }
+
+    /// Returns true if axis is a trigger.
+    pub fn is_trigger(&self) -> bool {
+        matches!(self, GamepadAxis::LeftTrigger | GamepadAxis::RightTrigger)
+    }
+}
+
+/// Range of an axis used by a binding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadAxisRange {
+    /// Full axis range.
+    Full,
+
+    /// Positive half of axis (`+`).
+    Positive,
+
+    /// Negative half of axis (`-`).
+    Negative,
+}
+
+/// Raw input of a binding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadMappingInput {
+    /// Raw button id.
+    Button(u8),
+
+    /// Raw axis id with range used and inversion (`~`).
+    Axis(u8, GamepadAxisRange, bool),
+
+    /// Hat index and direction mask (1 up, 2 right, 4 down, 8 left).
+    Hat(u8, u8),
+}
+
+/// Named output of a binding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadMappingOutput {
+    /// Named button.
+    Button(GamepadButton),
+
+    /// Named axis with range written.
+    Axis(GamepadAxis, GamepadAxisRange),
+}
+
+/// Gamepad identifier used by SDL mappings, built from device bus, vendor, product and version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GamepadGuid {
+    bytes : [u8; 16],
+}
+
+impl GamepadGuid {
+    /// Create a new [GamepadGuid] from device identifiers.
+    pub fn new(bus : u16, vendor : u16, product : u16, version : u16) -> GamepadGuid {
+        let mut bytes = [0u8; 16];
+        bytes[0..2].copy_from_slice(&bus.to_le_bytes());
+        bytes[4..6].copy_from_slice(&vendor.to_le_bytes());
+        bytes[8..10].copy_from_slice(&product.to_le_bytes());
+        bytes[12..14].copy_from_slice(&version.to_le_bytes());
+        GamepadGuid { bytes }
+    }
+
+    /// Parse a [GamepadGuid] from its 32 hexadecimal characters.
+    pub fn parse(guid : &str) -> Option<GamepadGuid> {
+        if guid.len() != 32 || !guid.is_ascii() {
+            return None;
+        }
+
+        let mut bytes = [0u8; 16];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&guid[index * 2..index * 2 + 2], 16).ok()?;
+        }
+        Some(GamepadGuid { bytes })
+    }
+
+    /// Returns a copy of guid with CRC set to 0.
+    fn without_crc(&self) -> GamepadGuid {
+        let mut guid = *self;
+        guid.bytes[2..4].fill(0);
+        guid
+    }
+
+    /// Returns a copy of guid with CRC and version set to 0.
+    fn without_version(&self) -> GamepadGuid {
+        let mut guid = self.without_crc();
+        guid.bytes[12..14].fill(0);
+        guid
+    }
+}
+
+impl Display for GamepadGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.bytes {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parsed SDL mapping of a gamepad.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadMapping {
+    /// Guid of gamepad.
+    guid : GamepadGuid,
+
+    /// Name of gamepad.
+    name : String,
+
+    /// Bindings of named outputs to raw inputs.
+    bindings : Vec<(GamepadMappingOutput, GamepadMappingInput)>,
+}
+
+impl GamepadMapping {
+    /// Parse a SDL mapping line.
+    ///
+    /// Returns Ok([GamepadMapping]) on success, Err([StudioError]) if line is invalid or for another platform.
+    pub fn parse(line : &str) -> Result<GamepadMapping, StudioError> {
+        let invalid = StudioError::Display(DisplayError::GamepadMappingInvalid);
+        let mut fields = line.trim().split(',');
+
+        let guid = fields.next().and_then(GamepadGuid::parse).ok_or(invalid)?;
+        let name = fields.next().ok_or(invalid)?.to_string();
+        let mut bindings : Vec<(GamepadMappingOutput, GamepadMappingInput)> = Vec::new();
+
+        for field in fields.filter(|field| !field.is_empty()) {
+            let (output, input) = field.split_once(':').ok_or(invalid)?;
+
+            if output == "platform" {
+                if input != MAPPING_PLATFORM {
+                    return Err(invalid);
+                }
+                continue;
+            }
+
+            // Unknown outputs such as crc or hint are ignored.
+            if let (Some(output), Some(input)) = (Self::parse_output(output), Self::parse_input(input)) {
+                bindings.push((output, input));
+            }
+        }
+
+        Ok(GamepadMapping { guid, name, bindings })
+    }
+
+    /// Get the guid of mapping.
+    pub fn get_guid(&self) -> GamepadGuid {
+        self.guid
+    }
+
+    /// Get the gamepad name of mapping.
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get bindings of named outputs to raw inputs.
+    pub fn get_bindings(&self) -> &Vec<(GamepadMappingOutput, GamepadMappingInput)> {
+        &self.bindings
+    }
+
+    /// Parse the named output of a binding.
+    fn parse_output(output : &str) -> Option<GamepadMappingOutput> {
+        let (range, name) = Self::parse_range(output);
+
+        match GamepadButton::from_sdl_name(name) {
+            Some(button) => Some(GamepadMappingOutput::Button(button)),
+            None => GamepadAxis::from_sdl_name(name).map(|axis| GamepadMappingOutput::Axis(axis, range)),
+        }
+    }
+
+    /// Parse the raw input of a binding.
+    fn parse_input(input : &str) -> Option<GamepadMappingInput> {
+        let (range, input) = Self::parse_range(input);
+        let (inverted, input) = match input.strip_suffix('~') {
+            Some(input) => (true, input),
+            None => (false, input),
+        };
+
+        match input.split_at_checked(1)? {
+            ("b", id) => Some(GamepadMappingInput::Button(id.parse().ok()?)),
+            ("a", id) => Some(GamepadMappingInput::Axis(id.parse().ok()?, range, inverted)),
+            ("h", hat) => {
+                let (hat, mask) = hat.split_once('.')?;
+                Some(GamepadMappingInput::Hat(hat.parse().ok()?, mask.parse().ok()?))
+            },
+            _ => None,
+        }
+    }
+
+    /// Split the `+` or `-` range prefix of a binding part.
+    fn parse_range(part : &str) -> (GamepadAxisRange, &str) {
+        if let Some(part) = part.strip_prefix('+') {
+            (GamepadAxisRange::Positive, part)
+        } else if let Some(part) = part.strip_prefix('-') {
+            (GamepadAxisRange::Negative, part)
+        } else {
+            (GamepadAxisRange::Full, part)
+        }
+    }
+}
+
+/// Database of [GamepadMapping] for the current platform.
+#[derive(Debug, Clone, Default)]
+pub struct GamepadMappingDB {
+    mappings : Vec<GamepadMapping>,
+}
+
+impl GamepadMappingDB {
+    /// Create a new empty [GamepadMappingDB].
+    pub fn new() -> GamepadMappingDB {
+        GamepadMappingDB { mappings: Vec::new() }
+    }
+
+    /// Load mappings from the content of a `gamecontrollerdb.txt` file. Comments, invalid lines and
+    /// mappings of other platforms are ignored. Mappings replace existing ones with the same guid.
+    ///
+    /// Returns count of mappings loaded.
+    pub fn load(&mut self, content : &str) -> usize {
+        content.lines().filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .filter(|line| self.add_mapping(line).is_ok()).count()
+    }
+
+    /// Add a mapping line, replacing existing mapping with the same guid.
+    ///
+    /// Returns Ok(()) on success, Err([StudioError]) if line is invalid or for another platform.
+    pub fn add_mapping(&mut self, line : &str) -> Result<(), StudioError> {
+        let mapping = GamepadMapping::parse(line)?;
+        self.mappings.retain(|existing| existing.guid != mapping.guid);
+        self.mappings.push(mapping);
+        Ok(())
+    }
+
+    /// Get the mapping of a guid. If no exact match, mappings ignoring CRC then version are searched.
+    ///
+    /// Returns Some([GamepadMapping]) or None if no mapping found.
+    pub fn get_mapping(&self, guid : &GamepadGuid) -> Option<&GamepadMapping> {
+        self.mappings.iter().find(|mapping| mapping.guid == *guid)
+            .or_else(|| self.mappings.iter().find(|mapping| mapping.guid.without_crc() == guid.without_crc()))
+            .or_else(|| self.mappings.iter().find(|mapping| mapping.guid.without_version() == guid.without_version()))
+    }
+
+    /// Get the list of mappings.
+    pub fn get_mapping_list(&self) -> &Vec<GamepadMapping> {
+        &self.mappings
+    }
+}
+
+/// Translate raw [EventGamepad] of a gamepad into named events with a [GamepadMapping].
+#[derive(Debug, Clone)]
+pub struct GamepadMapper {
+    /// Mapping used.
+    mapping : GamepadMapping,
+
+    /// Raw axes ids (x, y) of each hat.
+    hats : Vec<(u8, u8)>,
+
+    /// Last pressed state of each binding with a button output.
+    pressed : Vec<bool>,
+}
+
+impl GamepadMapper {
+    /// Create a new [GamepadMapper] from a mapping and the raw axes ids (x, y) of each hat of the gamepad.
+    pub fn new(mapping : GamepadMapping, hats : Vec<(u8, u8)>) -> GamepadMapper {
+        let pressed = vec![false; mapping.bindings.len()];
+        GamepadMapper { mapping, hats, pressed }
+    }
+
+    /// Get the mapping used.
+    pub fn get_mapping(&self) -> &GamepadMapping {
+        &self.mapping
+    }
+
+    /// Translate a raw event into named events pushed into events.
+    pub fn map_event(&mut self, event : &EventGamepad, events : &mut Vec<EventGamepad>) {
+        for (index, (output, input)) in self.mapping.bindings.iter().enumerate() {
+            // Get the input value from -32768 to 32767 if event concern this binding.
+            let value : Option<i32> = match (input, event) {
+                (GamepadMappingInput::Button(button), EventGamepad::ButtonDown(_, raw)) if button == raw => Some(i16::MAX as i32),
+                (GamepadMappingInput::Button(button), EventGamepad::ButtonUp(_, raw)) if button == raw => Some(0),
+                (GamepadMappingInput::Axis(axis, range, inverted), EventGamepad::Axis(_, raw, value)) if axis == raw => {
+                    let value = if *inverted { -(*value as i32) - 1 } else { *value as i32 };
+                    match range {
+                        GamepadAxisRange::Full => Some(value),
+                        GamepadAxisRange::Positive => Some(value.max(0)),
+                        GamepadAxisRange::Negative => Some((-value - 1).max(0)),
+                    }
+                },
+                (GamepadMappingInput::Hat(hat, mask), EventGamepad::Axis(_, raw, value)) => {
+                    match self.hats.get(*hat as usize) {
+                        // Right and left use x axis, down and up use y axis.
+                        Some((x, _)) if x == raw && mask & 0b1010 != 0 =>
+                            Some(if (mask & 2 != 0 && *value > 0) || (mask & 8 != 0 && *value < 0) { i16::MAX as i32 } else { 0 }),
+                        Some((_, y)) if y == raw && mask & 0b0101 != 0 =>
+                            Some(if (mask & 4 != 0 && *value > 0) || (mask & 1 != 0 && *value < 0) { i16::MAX as i32 } else { 0 }),
+                        _ => None,
+                    }
+                },
+                _ => None,
+            };
+
+            let id = match event {
+                EventGamepad::ButtonDown(id, _) | EventGamepad::ButtonUp(id, _) | EventGamepad::Axis(id, _, _) => *id,
+                _ => return,
+            };
+
+            if let Some(value) = value {
+                match output {
+                    GamepadMappingOutput::Button(button) => {
+                        let pressed = value >= AXIS_PRESS_THRESHOLD;
+                        if pressed != self.pressed[index] {
+                            self.pressed[index] = pressed;
+                            events.push(if pressed { EventGamepad::MappedButtonDown(id, *button) } else { EventGamepad::MappedButtonUp(id, *button) });
+                        }
+                    },
+                    GamepadMappingOutput::Axis(axis, range) => {
+                        let value = match (range, input) {
+                            (GamepadAxisRange::Negative, _) => -value,
+                            // Full range axis used as trigger goes from 0 to 32767.
+                            (GamepadAxisRange::Full, GamepadMappingInput::Axis(_, GamepadAxisRange::Full, _)) if axis.is_trigger() => (value + 32768) / 2,
+                            _ => value,
+                        };
+                        events.push(EventGamepad::MappedAxis(id, *axis, value.clamp(i16::MIN as i32, i16::MAX as i32) as i16));
+                    },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,377 @@
+//! Gamepads and joysticks.
+//!
+//! Connected gamepads are polled by the [Window](super::Window) and sent as [EventGamepad] into the window
+//! event queue. Controller ids are reused once a gamepad is disconnected.
+//!
+//! Gamepads with a SDL [mapping](mapping::GamepadMapping) also send named buttons and axes events. Mappings
+//! of the `SDL_GAMECONTROLLERCONFIG` environment variable are loaded on creation.
+//!
+//! Axes are filtered with the dead zones, triggers and calibrations of [GamepadSettings]. The last state of each
+//! gamepad can be read with [GamepadList::get_state] instead of consuming events.
+//!
+//! Rumble and force feedback [effects](effect::GamepadEffect) are played with [Gamepad::play_effect]. Effects
+//! of a gamepad are stopped when it is disconnected.
+
+use std::collections::VecDeque;
+use std::os::raw::c_int;
+
+use cfg_boost::target_cfg;
+
+use crate::error::StudioError;
+use super::event::gamepad::EventGamepad;
+use mapping::{GamepadMappingDB, GamepadMapper, GamepadGuid, GamepadMapping};
+use effect::{GamepadEffect, GamepadEffectId};
+use state::{GamepadFilter, GamepadSettings, GamepadState};
+
+/// SDL gamepad mappings
+pub mod mapping;
+
+/// Rumble and force feedback effects
+pub mod effect;
+
+/// Dead zones, calibration and state
+pub mod state;
+
+/// Environment variable containing user gamepad mappings.
+const MAPPING_ENVIRONMENT_VARIABLE : &str = "SDL_GAMECONTROLLERCONFIG";
+
+target_cfg! {
+    linux => {
+        // Linux evdev gamepads
+        pub mod evdev;
+
+        pub(crate) type GamepadDevice = evdev::device::EvdevGamepad;
+        pub(crate) type GamepadMonitor = evdev::device::EvdevMonitor;
+        use evdev::device::EvdevChange;
+    },
+}
+
+/// Contains list of connected gamepads.
+pub struct GamepadList {
+    /// Connected gamepads
+    gamepads : Vec<Gamepad>,
+
+    /// Events not polled yet
+    events : VecDeque<EventGamepad>,
+
+    /// Monitor of gamepads plugged and unplugged
+    monitor : GamepadMonitor,
+
+    /// Mappings of gamepads
+    mappings : GamepadMappingDB,
+}
+
+impl GamepadList {
+
+    /// Create a new gamepad list with all gamepads currently connected.
+    ///
+    /// A [EventGamepad::Connected] is sent for each of them.
+    pub fn new() -> GamepadList {
+        let mut list = GamepadList { gamepads: Vec::new(), events: VecDeque::new(), monitor: GamepadMonitor::new(), 
+            mappings: GamepadMappingDB::new() };
+
+        if let Ok(mappings) = std::env::var(MAPPING_ENVIRONMENT_VARIABLE) {
+            list.mappings.load(&mappings);
+        }
+
+        for path in list.monitor.scan() {
+            list.connect(&path);
+        }
+
+        list
+    }
+
+    /// Create a new gamepad list that never connects gamepads.
+    pub(crate) fn empty() -> GamepadList {
+        GamepadList { gamepads: Vec::new(), events: VecDeque::new(), monitor: GamepadMonitor::disabled(), 
+            mappings: GamepadMappingDB::new() }
+    }
+
+    /// Get a gamepad reference from its controller id.
+    ///
+    /// Returns Some([Gamepad]) or None if no gamepad with that id.
+    pub fn get_gamepad(&self, id : u8) -> Option<&Gamepad> {
+        self.gamepads.iter().find(|gamepad| gamepad.id == id)
+    }
+
+    /// Get a mutable gamepad reference from its controller id.
+    ///
+    /// Returns Some([Gamepad]) or None if no gamepad with that id.
+    pub fn get_gamepad_mut(&mut self, id : u8) -> Option<&mut Gamepad> {
+        self.gamepads.iter_mut().find(|gamepad| gamepad.id == id)
+    }
+
+    /// Get a reference to the list of connected gamepads.
+    pub fn get_gamepad_list(&self) -> &Vec<Gamepad> {
+        &self.gamepads
+    }
+
+    /// Get the state of a gamepad from its controller id. State contains all events read from the gamepad,
+    /// including events not polled yet.
+    ///
+    /// Returns Some([GamepadState]) or None if no gamepad with that id.
+    pub fn get_state(&self, id : u8) -> Option<&GamepadState> {
+        self.get_gamepad(id).map(|gamepad| gamepad.get_state())
+    }
+
+    /// Read pending events of gamepads so that [GamepadList::get_state] is up to date. Events are still queued.
+    pub fn update_states(&mut self) {
+        self.update();
+    }
+
+    /// Get the mappings database.
+    pub fn get_mappings(&self) -> &GamepadMappingDB {
+        &self.mappings
+    }
+
+    /// Load mappings from the content of a SDL `gamecontrollerdb.txt` file. Mappings override existing ones 
+    /// with the same guid and are applied to connected gamepads.
+    /// 
+    /// Returns count of mappings loaded.
+    pub fn load_mappings(&mut self, content : &str) -> usize {
+        let count = self.mappings.load(content);
+
+        for gamepad in &mut self.gamepads {
+            gamepad.mapper = Gamepad::get_mapper(&self.mappings, &gamepad.device);
+        }
+
+        count
+    }
+
+    /// Pop a gamepad event.
+    ///
+    /// Returns Some([EventGamepad]) or None if no event.
+    pub fn poll_event(&mut self) -> Option<EventGamepad> {
+        if self.events.is_empty() {
+            self.update();
+        }
+
+        self.events.pop_front()
+    }
+
+    /// Get file descriptors that become readable when a gamepad event is available.
+    pub(crate) fn get_fds(&self) -> Vec<c_int> {
+        let mut fds : Vec<c_int> = self.gamepads.iter().map(|gamepad| gamepad.device.fd).collect();
+        if self.monitor.fd >= 0 {
+            fds.push(self.monitor.fd);
+        }
+        fds
+    }
+
+    /// Read gamepads plugged, unplugged and devices events.
+    fn update(&mut self) {
+        for change in self.monitor.read_changes() {
+            match change {
+                EvdevChange::Added(path) => self.connect(&path),
+                EvdevChange::Removed(path) => {
+                    if let Some(id) = self.gamepads.iter().find(|gamepad| gamepad.device.path == path).map(|gamepad| gamepad.id) {
+                        self.disconnect(id);
+                    }
+                },
+            }
+        }
+
+        let mut disconnected : Vec<u8> = Vec::new();
+        let mut raw_events : VecDeque<EventGamepad> = VecDeque::new();
+        let mut filtered_events : Vec<EventGamepad> = Vec::new();
+        let mut mapped_events : Vec<EventGamepad> = Vec::new();
+
+        for gamepad in &mut self.gamepads {
+            gamepad.device.remove_finished_effects();
+
+            if !gamepad.device.read_events(gamepad.id, &mut raw_events) {
+                disconnected.push(gamepad.id);
+            }
+
+            for event in raw_events.drain(..) {
+                gamepad.filter.filter_event(&event, &mut filtered_events);
+            }
+
+            // Named events are sent after the filtered event.
+            for event in filtered_events.drain(..) {
+                mapped_events.push(event);
+                if let Some(mapper) = &mut gamepad.mapper {
+                    mapper.map_event(&event, &mut mapped_events);
+                }
+
+                for event in mapped_events.drain(..) {
+                    gamepad.state.apply(&event);
+                    self.events.push_back(event);
+                }
+            }
+        }
+
+        for id in disconnected {
+            self.disconnect(id);
+        }
+    }
+
+    /// Connect the gamepad at path if not already connected.
+    fn connect(&mut self, path : &str) {
+        if self.gamepads.iter().any(|gamepad| gamepad.device.path == path) {
+            return;
+        }
+
+        if let Some(device) = GamepadDevice::open(path) {
+            // Smallest id not used.
+            let id = (0..=u8::MAX).find(|id| self.get_gamepad(*id).is_none());
+
+            if let Some(id) = id {
+                let mapper = Gamepad::get_mapper(&self.mappings, &device);
+                let filter = GamepadFilter::new(device.get_default_settings());
+                let state = GamepadState::new(device.decoder.get_layout().get_buttons().len(), device.decoder.get_layout().get_axes().len());
+                self.gamepads.push(Gamepad { id, device, mapper, filter, state });
+                self.events.push_back(EventGamepad::Connected(id));
+            }
+        }
+    }
+
+    /// Disconnect the gamepad with id and stop its effects.
+    fn disconnect(&mut self, id : u8) {
+        if let Some(gamepad) = self.get_gamepad_mut(id) {
+            gamepad.stop_all_effects();
+        }
+
+        self.gamepads.retain(|gamepad| gamepad.id != id);
+        queue_disconnected(&mut self.events, id);
+    }
+}
+
+/// Queue the [EventGamepad::Disconnected] of gamepad with id into events not polled yet.
+///
+/// If its [EventGamepad::Connected] wasn't polled yet, all events of gamepad are removed and nothing is queued since
+/// the gamepad was never seen. Otherwise its pending events, such as buttons and axes releases, are kept before
+/// the disconnection.
+pub fn queue_disconnected(events : &mut VecDeque<EventGamepad>, id : u8) {
+    // Events before the connection belong to a previous gamepad with the same id.
+    match events.iter().rposition(|event| *event == EventGamepad::Connected(id)) {
+        Some(connected) => {
+            let mut index = 0;
+            events.retain(|event| { index += 1; index <= connected || Gamepad::get_event_id(event) != id });
+        },
+        None => events.push_back(EventGamepad::Disconnected(id)),
+    }
+}
+
+impl Default for GamepadList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Connected gamepad details.
+pub struct Gamepad {
+    /// Controller id used in [EventGamepad]
+    id : u8,
+
+    /// Platform device
+    pub(crate) device : GamepadDevice,
+
+    /// Mapper of named buttons and axes
+    mapper : Option<GamepadMapper>,
+
+    /// Filter of axes
+    filter : GamepadFilter,
+
+    /// Last state of buttons and axes
+    state : GamepadState,
+}
+
+impl Gamepad {
+    /// Returns controller id used in [EventGamepad].
+    pub fn get_id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns gamepad name as [String].
+    pub fn get_name(&self) -> &String {
+        &self.device.name
+    }
+
+    /// Returns (bus, vendor, product, version) identifiers of gamepad.
+    pub fn get_identifiers(&self) -> (u16, u16, u16, u16) {
+        let ids = &self.device.ids;
+        (ids.bustype, ids.vendor, ids.product, ids.version)
+    }
+
+    /// Returns the [GamepadGuid] of gamepad used to find its mapping.
+    pub fn get_guid(&self) -> GamepadGuid {
+        let ids = &self.device.ids;
+        GamepadGuid::new(ids.bustype, ids.vendor, ids.product, ids.version)
+    }
+
+    /// Returns the [GamepadMapping] of gamepad if any.
+    pub fn get_mapping(&self) -> Option<&GamepadMapping> {
+        self.mapper.as_ref().map(|mapper| mapper.get_mapping())
+    }
+
+    /// Returns count of buttons of gamepad.
+    pub fn get_button_count(&self) -> usize {
+        self.device.decoder.get_layout().get_buttons().len()
+    }
+
+    /// Returns count of axes of gamepad.
+    pub fn get_axis_count(&self) -> usize {
+        self.device.decoder.get_layout().get_axes().len()
+    }
+
+    /// Returns the last [GamepadState] of gamepad.
+    pub fn get_state(&self) -> &GamepadState {
+        &self.state
+    }
+
+    /// Returns the dead zones, triggers and calibrations applied to gamepad axes.
+    pub fn get_settings(&self) -> &GamepadSettings {
+        self.filter.get_settings()
+    }
+
+    /// Set the dead zones, triggers and calibrations applied to gamepad axes.
+    pub fn set_settings(&mut self, settings : GamepadSettings) {
+        self.filter.set_settings(settings)
+    }
+
+    /// Returns true if gamepad can play effect.
+    pub fn is_effect_supported(&self, effect : &GamepadEffect) -> bool {
+        self.device.is_effect_supported(effect)
+    }
+
+    /// Play a rumble or force feedback effect. Effect is removed from gamepad once its duration elapsed. If gamepad
+    /// can't play more effects at once, the oldest effect is stopped.
+    ///
+    /// Returns Ok([GamepadEffectId]) on success, Err([StudioError]) if effect is not supported, empty or failed.
+    pub fn play_effect(&mut self, effect : &GamepadEffect) -> Result<GamepadEffectId, StudioError> {
+        self.device.play_effect(effect)
+    }
+
+    /// Stop an effect before its end.
+    ///
+    /// Returns true if effect was playing.
+    pub fn stop_effect(&mut self, effect : GamepadEffectId) -> bool {
+        self.device.stop_effect(effect)
+    }
+
+    /// Stop all effects playing.
+    pub fn stop_all_effects(&mut self) {
+        self.device.stop_all_effects()
+    }
+
+    /// Returns the controller id of an [EventGamepad].
+    pub(crate) fn get_event_id(event : &EventGamepad) -> u8 {
+        match event {
+            EventGamepad::Connected(id) | EventGamepad::Disconnected(id) => *id,
+            EventGamepad::ButtonDown(id, _) | EventGamepad::ButtonUp(id, _) => *id,
+            EventGamepad::Axis(id, _, _) => *id,
+            EventGamepad::TriggerDown(id, _) | EventGamepad::TriggerUp(id, _) => *id,
+            EventGamepad::MappedButtonDown(id, _) | EventGamepad::MappedButtonUp(id, _) => *id,
+            EventGamepad::MappedAxis(id, _, _) => *id,
+        }
+    }
+
+    /// Get the [GamepadMapper] of a device if it has a mapping.
+    fn get_mapper(mappings : &GamepadMappingDB, device : &GamepadDevice) -> Option<GamepadMapper> {
+        let ids = &device.ids;
+        let guid = GamepadGuid::new(ids.bustype, ids.vendor, ids.product, ids.version);
+
+        mappings.get_mapping(&guid).map(|mapping| GamepadMapper::new(mapping.clone(), device.get_hats()))
+    }
+}
@@ -0,0 +1,311 @@
+//! Dead zones, calibration and state of gamepads.
+//!
+//! Raw axes values are filtered by a [GamepadFilter] before being sent as [EventGamepad]. Axis events are only
+//! sent when the filtered value changed so that sticks jitter inside dead zones is ignored. The [GamepadState]
+//! keeps the last buttons and axes values of a gamepad to be read once per frame.
+
+use crate::display::desktop::event::gamepad::EventGamepad;
+
+use super::mapping::{GamepadButton, GamepadAxis};
+
+/// Default dead zone of sticks. Same as XInput left stick dead zone.
+pub const DEFAULT_DEAD_ZONE : u16 = 7849;
+
+/// Default travel of triggers (range: 0.0 to 1.0) over which they are pressed.
+pub const DEFAULT_TRIGGER_THRESHOLD : f32 = 0.5;
+
+/// Count of [GamepadButton] variants.
+const GAMEPAD_BUTTON_COUNT : usize = 21;
+
+/// Count of [GamepadAxis] variants.
+const GAMEPAD_AXIS_COUNT : usize = 6;
+
+/// Enumeration of dead zones applied to sticks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadDeadZone {
+    /// No dead zone.
+    None,
+
+    /// Each axis of stick is set to 0 under this value (range: 0 to 32767).
+    Axial(u16),
+
+    /// Both axes of stick are set to 0 when stick distance to center is under this value (range: 0 to 32767).
+    Radial(u16),
+}
+
+/// Calibration of a raw axis. Values are normalized axis values (range: -32768 to 32767).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GamepadCalibration {
+    /// Value reported at minimum position.
+    pub min : i16,
+
+    /// Value reported at rest.
+    pub center : i16,
+
+    /// Value reported at maximum position.
+    pub max : i16,
+}
+
+impl GamepadCalibration {
+    /// Create a new [GamepadCalibration].
+    pub fn new(min : i16, center : i16, max : i16) -> GamepadCalibration {
+        GamepadCalibration { min, center, max }
+    }
+
+    /// Scale a value so that min, center and max become -32768, 0 and 32767.
+    pub fn apply(&self, value : i16) -> i16 {
+        let (value, min, center, max) = (value as i32, self.min as i32, self.center as i32, self.max as i32);
+
+        let value = if value < center {
+            if center > min { (value - center) * 32768 / (center - min) } else { 0 }
+        } else if max > center {
+            (value - center) * 32767 / (max - center)
+        } else {
+            0
+        };
+
+        value.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+    }
+}
+
+/// Dead zones, triggers and calibrations of a gamepad.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadSettings {
+    /// Dead zone applied to sticks.
+    pub dead_zone : GamepadDeadZone,
+
+    /// Raw axes ids (x, y) of sticks.
+    pub sticks : Vec<(u8, u8)>,
+
+    /// Raw axes ids of triggers.
+    pub triggers : Vec<u8>,
+
+    /// Travel of triggers (range: 0.0 to 1.0) over which [EventGamepad::TriggerDown] is sent.
+    pub trigger_threshold : f32,
+
+    /// Calibrations of raw axes ids.
+    pub calibrations : Vec<(u8, GamepadCalibration)>,
+}
+
+impl GamepadSettings {
+    /// Create a new [GamepadSettings] for sticks and triggers raw axes ids with default dead zone and threshold.
+    pub fn new(sticks : Vec<(u8, u8)>, triggers : Vec<u8>) -> GamepadSettings {
+        GamepadSettings { dead_zone: GamepadDeadZone::Radial(DEFAULT_DEAD_ZONE), sticks, triggers,
+            trigger_threshold: DEFAULT_TRIGGER_THRESHOLD, calibrations: Vec::new() }
+    }
+
+    /// Set the calibration of a raw axis id, replacing existing one.
+    pub fn set_calibration(&mut self, axis : u8, calibration : GamepadCalibration) {
+        self.calibrations.retain(|(id, _)| *id != axis);
+        self.calibrations.push((axis, calibration));
+    }
+
+    /// Get the calibration of a raw axis id.
+    ///
+    /// Returns Some([GamepadCalibration]) or None if axis isn't calibrated.
+    pub fn get_calibration(&self, axis : u8) -> Option<&GamepadCalibration> {
+        self.calibrations.iter().find(|(id, _)| *id == axis).map(|(_, calibration)| calibration)
+    }
+}
+
+impl Default for GamepadSettings {
+    fn default() -> Self {
+        Self::new(Vec::new(), Vec::new())
+    }
+}
+
+/// Filter raw [EventGamepad] of a gamepad with [GamepadSettings].
+#[derive(Debug, Clone)]
+pub struct GamepadFilter {
+    /// Settings applied.
+    settings : GamepadSettings,
+
+    /// Calibrated value of each raw axis.
+    values : Vec<i16>,
+
+    /// Last sent value of each raw axis.
+    sent : Vec<i16>,
+
+    /// Pressed state of each trigger axis.
+    pressed : Vec<bool>,
+}
+
+impl GamepadFilter {
+    /// Create a new [GamepadFilter] from settings.
+    pub fn new(settings : GamepadSettings) -> GamepadFilter {
+        GamepadFilter { settings, values: Vec::new(), sent: Vec::new(), pressed: Vec::new() }
+    }
+
+    /// Get settings applied.
+    pub fn get_settings(&self) -> &GamepadSettings {
+        &self.settings
+    }
+
+    /// Set settings applied. Takes effect on the next axis event.
+    pub fn set_settings(&mut self, settings : GamepadSettings) {
+        self.settings = settings;
+    }
+
+    /// Filter a raw event and push resulting events into events. Events other than [EventGamepad::Axis] are unchanged.
+    pub fn filter_event(&mut self, event : &EventGamepad, events : &mut Vec<EventGamepad>) {
+        match *event {
+            EventGamepad::Axis(id, axis, value) => {
+                let index = axis as usize;
+                if self.values.len() <= index {
+                    self.values.resize(index + 1, 0);
+                    self.pressed.resize(index + 1, false);
+                }
+
+                self.values[index] = match self.settings.get_calibration(axis) {
+                    Some(calibration) => calibration.apply(value),
+                    None => value,
+                };
+
+                if let Some((x, y)) = self.settings.sticks.iter().find(|(x, y)| *x == axis || *y == axis).copied() {
+                    let (vx, vy) = (self.get_value(x), self.get_value(y));
+                    let (vx, vy) = match self.settings.dead_zone {
+                        GamepadDeadZone::None => (vx, vy),
+                        GamepadDeadZone::Axial(dead_zone) => (Self::apply_axial(vx, dead_zone), Self::apply_axial(vy, dead_zone)),
+                        GamepadDeadZone::Radial(dead_zone) => Self::apply_radial(vx, vy, dead_zone),
+                    };
+                    self.send(id, x, vx, events);
+                    self.send(id, y, vy, events);
+                } else {
+                    self.send(id, axis, self.values[index], events);
+
+                    if self.settings.triggers.contains(&axis) {
+                        let travel = (self.values[index] as f32 + 32768.0) / 65535.0;
+                        let pressed = travel >= self.settings.trigger_threshold;
+
+                        if pressed != self.pressed[index] {
+                            self.pressed[index] = pressed;
+                            events.push(if pressed { EventGamepad::TriggerDown(id, axis) } else { EventGamepad::TriggerUp(id, axis) });
+                        }
+                    }
+                }
+            },
+            _ => events.push(*event),
+        }
+    }
+
+    /// Get the calibrated value of a raw axis. Unknown axes are 0.
+    fn get_value(&self, axis : u8) -> i16 {
+        self.values.get(axis as usize).copied().unwrap_or(0)
+    }
+
+    /// Push an axis event if value changed since last sent.
+    fn send(&mut self, id : u8, axis : u8, value : i16, events : &mut Vec<EventGamepad>) {
+        if self.sent.len() <= axis as usize {
+            self.sent.resize(axis as usize + 1, 0);
+        }
+
+        if self.sent[axis as usize] != value {
+            self.sent[axis as usize] = value;
+            events.push(EventGamepad::Axis(id, axis, value));
+        }
+    }
+
+    /// Apply an axial dead zone, rescaling values outside of it to the full range.
+    fn apply_axial(value : i16, dead_zone : u16) -> i16 {
+        let dead_zone = (dead_zone as i32).min(i16::MAX as i32 - 1);
+        let value = value as i32;
+
+        if value.abs() < dead_zone {
+            0
+        } else if value < 0 {
+            ((value + dead_zone) * 32768 / (32768 - dead_zone)).max(i16::MIN as i32) as i16
+        } else {
+            ((value - dead_zone) * 32767 / (32767 - dead_zone)).min(i16::MAX as i32) as i16
+        }
+    }
+
+    /// Apply a radial dead zone, rescaling stick distance outside of it to the full range.
+    fn apply_radial(x : i16, y : i16, dead_zone : u16) -> (i16, i16) {
+        let dead_zone = (dead_zone as f64).min(i16::MAX as f64 - 1.0);
+        let (x, y) = (x as f64, y as f64);
+        let distance = (x * x + y * y).sqrt();
+
+        if distance < dead_zone || distance == 0.0 {
+            (0, 0)
+        } else {
+            let scale = ((distance - dead_zone) / (i16::MAX as f64 - dead_zone)).min(1.0) * i16::MAX as f64 / distance;
+            ((x * scale).round().clamp(i16::MIN as f64, i16::MAX as f64) as i16,
+                (y * scale).round().clamp(i16::MIN as f64, i16::MAX as f64) as i16)
+        }
+    }
+}
+
+/// Snapshot of buttons and axes of a gamepad.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamepadState {
+    /// Pressed state of raw buttons.
+    buttons : Vec<bool>,
+
+    /// Filtered values of raw axes.
+    axes : Vec<i16>,
+
+    /// Pressed state of triggers raw axes.
+    triggers : Vec<bool>,
+
+    /// Pressed state of named buttons.
+    mapped_buttons : [bool; GAMEPAD_BUTTON_COUNT],
+
+    /// Values of named axes.
+    mapped_axes : [i16; GAMEPAD_AXIS_COUNT],
+}
+
+impl GamepadState {
+    /// Create a new [GamepadState] with buttons released and axes centered.
+    pub fn new(button_count : usize, axis_count : usize) -> GamepadState {
+        GamepadState { buttons: vec![false; button_count], axes: vec![0; axis_count], triggers: vec![false; axis_count],
+            mapped_buttons: [false; GAMEPAD_BUTTON_COUNT], mapped_axes: [0; GAMEPAD_AXIS_COUNT] }
+    }
+
+    /// Update state with an event of the gamepad.
+    pub fn apply(&mut self, event : &EventGamepad) {
+        match *event {
+            EventGamepad::ButtonDown(_, button) => Self::set(&mut self.buttons, button, true),
+            EventGamepad::ButtonUp(_, button) => Self::set(&mut self.buttons, button, false),
+            EventGamepad::Axis(_, axis, value) => Self::set(&mut self.axes, axis, value),
+            EventGamepad::TriggerDown(_, axis) => Self::set(&mut self.triggers, axis, true),
+            EventGamepad::TriggerUp(_, axis) => Self::set(&mut self.triggers, axis, false),
+            EventGamepad::MappedButtonDown(_, button) => self.mapped_buttons[button as usize] = true,
+            EventGamepad::MappedButtonUp(_, button) => self.mapped_buttons[button as usize] = false,
+            EventGamepad::MappedAxis(_, axis, value) => self.mapped_axes[axis as usize] = value,
+            EventGamepad::Connected(_) | EventGamepad::Disconnected(_) => {},
+        }
+    }
+
+    /// Returns true if raw button id is pressed.
+    pub fn is_button_pressed(&self, button : u8) -> bool {
+        self.buttons.get(button as usize).copied().unwrap_or(false)
+    }
+
+    /// Returns the filtered value of raw axis id (range: -32768 to 32767).
+    pub fn get_axis(&self, axis : u8) -> i16 {
+        self.axes.get(axis as usize).copied().unwrap_or(0)
+    }
+
+    /// Returns true if trigger raw axis id is over the trigger threshold.
+    pub fn is_trigger_pressed(&self, axis : u8) -> bool {
+        self.triggers.get(axis as usize).copied().unwrap_or(false)
+    }
+
+    /// Returns true if named button is pressed. Always false without mapping.
+    pub fn is_mapped_button_pressed(&self, button : GamepadButton) -> bool {
+        self.mapped_buttons[button as usize]
+    }
+
+    /// Returns the value of named axis. Always 0 without mapping.
+    pub fn get_mapped_axis(&self, axis : GamepadAxis) -> i16 {
+        self.mapped_axes[axis as usize]
+    }
+
+    /// Set a value of a state vector, growing it if needed.
+    fn set<T : Copy + Default>(values : &mut Vec<T>, index : u8, value : T) {
+        if values.len() <= index as usize {
+            values.resize(index as usize + 1, T::default());
+        }
+        values[index as usize] = value;
+    }
+}
@@ -0,0 +1,351 @@
+//! Pointer gestures recognition such as double-click, long press and drag.
+//!
+//! The [GestureRecognizer] only consume [Event] and their [EventSource](super::event::EventSource) timestamps,
+//! making it independent of the window provider.
+//!
+//! The [TouchpadGestureDecoder] convert touchpad pinch and swipe values reported by display server into [EventGesture].
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use cfg_boost::match_cfg;
+
+use super::event::{Event, gesture::EventGesture, pointer::{EventPointer, PointerButton}};
+
+/// Default maximum delay in milliseconds between 2 clicks of a multiple click.
+pub const DEFAULT_DOUBLE_CLICK_TIME : u64 = 400;
+
+/// Default distance in pixels the pointer must move before a drag start.
+pub const DEFAULT_DRAG_THRESHOLD : u32 = 8;
+
+/// Default delay in milliseconds a button must be held for a long press.
+pub const DEFAULT_LONG_PRESS_TIME : u64 = 500;
+
+/// Thresholds used by [GestureRecognizer].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GestureSettings {
+    /// Maximum delay in milliseconds between 2 clicks of a multiple click.
+    pub double_click_time : u64,
+
+    /// Distance in pixels the pointer must move before a drag start.
+    pub drag_threshold : u32,
+
+    /// Delay in milliseconds a button must be held for a long press.
+    pub long_press_time : u64,
+}
+
+impl GestureSettings {
+    /// Create new [GestureSettings] with default values.
+    pub fn new() -> GestureSettings {
+        GestureSettings { double_click_time: DEFAULT_DOUBLE_CLICK_TIME, drag_threshold: DEFAULT_DRAG_THRESHOLD,
+            long_press_time: DEFAULT_LONG_PRESS_TIME }
+    }
+
+    /// Create new [GestureSettings] from desktop settings. Values not provided by the desktop are set to default.
+    ///
+    /// On X11, `Net/DoubleClickTime` and `Net/DndDragThreshold` are read from XSETTINGS.
+    pub fn from_desktop() -> GestureSettings {
+        #[allow(unused_mut)]
+        let mut settings = GestureSettings::new();
+
+        match_cfg! {
+            linux => {
+                use crate::display::desktop::manager::linux::x11::xsettings::{get_x11_xsettings_data, parse_xsettings_int};
+
+                // Property is fetched once for all settings.
+                if let Some(data) = get_x11_xsettings_data() {
+                    if let Some(time) = parse_xsettings_int(&data, "Net/DoubleClickTime") {
+                        settings.double_click_time = time.max(0) as u64;
+                    }
+
+                    if let Some(threshold) = parse_xsettings_int(&data, "Net/DndDragThreshold") {
+                        settings.drag_threshold = threshold.max(0) as u32;
+                    }
+                }
+            },
+            _ => {},
+        }
+
+        settings
+    }
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Button currently held.
+#[derive(Debug, Copy, Clone)]
+struct GesturePress {
+    /// Button held
+    button : PointerButton,
+
+    /// Position where button was pressed
+    position : (i32, i32),
+
+    /// Timestamp when button was pressed
+    timestamp : u64,
+
+    /// Drag started
+    dragging : bool,
+
+    /// Long press was emitted
+    long_pressed : bool,
+}
+
+/// Last click, used to count multiple clicks.
+#[derive(Debug, Copy, Clone)]
+struct GestureClick {
+    /// Button clicked
+    button : PointerButton,
+
+    /// Position of click
+    position : (i32, i32),
+
+    /// Timestamp when button was pressed
+    timestamp : u64,
+
+    /// Count of successive clicks
+    count : u32,
+}
+
+/// Recognize click, long press and drag gestures from pointer events.
+///
+/// Steps :
+/// handle_event() for each polled event
+/// update() when no event is polled so long press can be detected
+/// poll_gesture() until None
+///
+/// [GestureRecognizer::get_timeout] gives how long a wait for events can last before a long press is due.
+pub struct GestureRecognizer {
+    /// Thresholds used
+    settings : GestureSettings,
+
+    /// Button currently held
+    press : Option<GesturePress>,
+
+    /// Last click
+    click : Option<GestureClick>,
+
+    /// Gestures recognized but not polled yet
+    gestures : VecDeque<EventGesture>,
+
+    /// Last event timestamp received with the instant it was received.
+    last_timestamp : Option<(u64, Instant)>,
+}
+
+impl GestureRecognizer {
+    /// Create a new [GestureRecognizer] with [GestureSettings].
+    pub fn new(settings : GestureSettings) -> GestureRecognizer {
+        GestureRecognizer { settings, press: None, click: None, gestures: VecDeque::new(), last_timestamp: None }
+    }
+
+    /// Get the [GestureSettings] used.
+    pub fn get_settings(&self) -> &GestureSettings {
+        &self.settings
+    }
+
+    /// Set the [GestureSettings] used.
+    pub fn set_settings(&mut self, settings : GestureSettings) {
+        self.settings = settings;
+    }
+
+    /// Pop the oldest gesture recognized.
+    ///
+    /// Returns Some([EventGesture]) or None if no gesture.
+    pub fn poll_gesture(&mut self) -> Option<EventGesture> {
+        self.gestures.pop_front()
+    }
+
+    /// Handle an event. Only pointer events are used, others are ignored.
+    pub fn handle_event(&mut self, event : &Event) {
+        if let Event::Pointer(pointer, source) = event {
+            self.update(source.timestamp);
+            self.last_timestamp = Some((source.timestamp, Instant::now()));
+
+            match pointer {
+                EventPointer::ButtonDown(button, position) => self.handle_button_down(*button, *position, source.timestamp),
+                EventPointer::ButtonUp(button, position) => self.handle_button_up(*button, *position),
+                EventPointer::Moved(position) => self.handle_moved(*position),
+                EventPointer::Acceleration(_) => {},
+            }
+        }
+    }
+
+    /// Update long press with the current timestamp, in the same time base as events timestamp.
+    pub fn update(&mut self, timestamp : u64) {
+        if let Some(press) = &mut self.press {
+            if !press.dragging && !press.long_pressed && timestamp.saturating_sub(press.timestamp) >= self.settings.long_press_time {
+                press.long_pressed = true;
+                self.click = None;      // A long press break multiple clicks.
+                self.gestures.push_back(EventGesture::LongPress { button: press.button, position: press.position });
+            }
+        }
+    }
+
+    /// Get the timestamp at which the held button becomes a long press, in the same time base as events timestamp.
+    ///
+    /// Returns Some(timestamp) or None if no long press is pending.
+    pub fn get_long_press_deadline(&self) -> Option<u64> {
+        self.press.filter(|press| !press.dragging && !press.long_pressed).map(|press| press.timestamp + self.settings.long_press_time)
+    }
+
+    /// Returns the duration until the pending long press is due, zero if already due. None if no long press is pending.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        let deadline = self.get_long_press_deadline()?;
+        let (timestamp, instant) = self.last_timestamp?;
+        Some(Duration::from_millis(deadline.saturating_sub(timestamp + instant.elapsed().as_millis() as u64)))
+    }
+
+    /// Update long press with the time elapsed since the last event was handled.
+    pub fn update_elapsed(&mut self) {
+        if let Some((timestamp, instant)) = self.last_timestamp {
+            self.update(timestamp + instant.elapsed().as_millis() as u64);
+        }
+    }
+
+    /// Handle button down.
+    #[inline(always)]
+    fn handle_button_down(&mut self, button : PointerButton, position : (i32, i32), timestamp : u64) {
+        if Self::is_scroll(button) || self.press.is_some() {
+            return;     // Scroll and other buttons while one is held are ignored.
+        }
+
+        self.press = Some(GesturePress { button, position, timestamp, dragging: false, long_pressed: false });
+    }
+
+    /// Handle button up.
+    #[inline(always)]
+    fn handle_button_up(&mut self, button : PointerButton, position : (i32, i32)) {
+        let press = match self.press {
+            Some(press) if press.button == button => press,
+            _ => return,
+        };
+        self.press = None;
+
+        if press.dragging {
+            self.gestures.push_back(EventGesture::DragEnd { button, position });
+        } else if !press.long_pressed {
+            let count = match self.click {
+                Some(click) if click.button == button && press.timestamp.saturating_sub(click.timestamp) <= self.settings.double_click_time
+                    && self.is_within_threshold(click.position, press.position) => click.count + 1,
+                _ => 1,
+            };
+
+            self.click = Some(GestureClick { button, position: press.position, timestamp: press.timestamp, count });
+            self.gestures.push_back(EventGesture::Click { button, position, count });
+        }
+    }
+
+    /// Handle pointer moved.
+    #[inline(always)]
+    fn handle_moved(&mut self, position : (i32, i32)) {
+        let within = match &self.press {
+            Some(press) => self.is_within_threshold(press.position, position),
+            None => return,
+        };
+
+        if let Some(press) = &mut self.press {
+            if !press.dragging && !within {
+                press.dragging = true;
+                self.click = None;      // A drag break multiple clicks.
+                self.gestures.push_back(EventGesture::DragStart { button: press.button, position: press.position });
+            }
+
+            if press.dragging {
+                self.gestures.push_back(EventGesture::DragMove { button: press.button, position });
+            }
+        }
+    }
+
+    /// Returns true if both positions are within drag threshold.
+    #[inline(always)]
+    fn is_within_threshold(&self, a : (i32, i32), b : (i32, i32)) -> bool {
+        a.0.abs_diff(b.0) <= self.settings.drag_threshold && a.1.abs_diff(b.1) <= self.settings.drag_threshold
+    }
+
+    /// Returns true if button is a scroll button.
+    #[inline(always)]
+    fn is_scroll(button : PointerButton) -> bool {
+        matches!(button, PointerButton::ScrollUp | PointerButton::ScrollDown | PointerButton::ScrollLeft | PointerButton::ScrollRight)
+    }
+}
+
+/// Phase of a touchpad gesture reported by display server.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TouchpadGesturePhase {
+    /// Fingers started the gesture.
+    Begin,
+
+    /// Fingers moved.
+    Update,
+
+    /// Fingers were lifted or gesture was cancelled.
+    End,
+}
+
+/// Touchpad gesture values reported by display server, independent of the window provider.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TouchpadGestureInput {
+    /// Phase of gesture.
+    pub phase : TouchpadGesturePhase,
+
+    /// Count of fingers.
+    pub fingers : u32,
+
+    /// Pointer position.
+    pub position : (f64, f64),
+
+    /// (x, y) delta since last update.
+    pub delta : (f64, f64),
+
+    /// Scale relative to the beginning of a pinch.
+    pub scale : f64,
+
+    /// Rotation in degrees since last update of a pinch.
+    pub delta_angle : f64,
+
+    /// Gesture was cancelled.
+    pub cancelled : bool,
+}
+
+/// Convert touchpad pinch and swipe gestures into [EventGesture]. Rotation of a pinch is accumulated since its beginning.
+#[derive(Debug, Default)]
+pub struct TouchpadGestureDecoder {
+    /// Rotation accumulated since the beginning of the current pinch.
+    pinch_rotation : f64,
+}
+
+impl TouchpadGestureDecoder {
+    /// Create a new [TouchpadGestureDecoder].
+    pub fn new() -> TouchpadGestureDecoder {
+        TouchpadGestureDecoder { pinch_rotation: 0.0 }
+    }
+
+    /// Decode a pinch gesture.
+    pub fn decode_pinch(&mut self, input : &TouchpadGestureInput) -> EventGesture {
+        match input.phase {
+            TouchpadGesturePhase::Begin => {
+                self.pinch_rotation = 0.0;
+                EventGesture::PinchBegin { fingers: input.fingers, position: input.position }
+            },
+            TouchpadGesturePhase::Update => {
+                self.pinch_rotation += input.delta_angle;
+                EventGesture::PinchUpdate { scale: input.scale, rotation: self.pinch_rotation, position: input.position }
+            },
+            TouchpadGesturePhase::End => EventGesture::PinchEnd { scale: input.scale, rotation: self.pinch_rotation, cancelled: input.cancelled },
+        }
+    }
+
+    /// Decode a swipe gesture.
+    pub fn decode_swipe(&self, input : &TouchpadGestureInput) -> EventGesture {
+        match input.phase {
+            TouchpadGesturePhase::Begin => EventGesture::SwipeBegin { fingers: input.fingers, position: input.position },
+            TouchpadGesturePhase::Update => EventGesture::SwipeUpdate { fingers: input.fingers, delta: input.delta },
+            TouchpadGesturePhase::End => EventGesture::SwipeEnd { fingers: input.fingers, cancelled: input.cancelled },
+        }
+    }
+}
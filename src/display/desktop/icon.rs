@@ -0,0 +1,49 @@
+//! Window icons.
+//!
+//! A [WindowIcon] is a RGBA image shown in taskbar and window switcher. Windows accept multiple icons of
+//! different sizes so that the window manager picks the best fitting one.
+
+use crate::error::StudioError;
+
+use super::error::DisplayError;
+
+/// RGBA image used as window icon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowIcon {
+    /// Width of icon in pixels.
+    width : u32,
+
+    /// Height of icon in pixels.
+    height : u32,
+
+    /// Pixels of icon as RGBA bytes, row by row.
+    pixels : Vec<u8>,
+}
+
+impl WindowIcon {
+    /// Create a new [WindowIcon] from RGBA pixels, 4 bytes per pixel, row by row.
+    ///
+    /// Returns Ok([WindowIcon]) or Err([StudioError]) if icon is empty or pixels don't match size.
+    pub fn new(width : u32, height : u32, pixels : Vec<u8>) -> Result<WindowIcon, StudioError> {
+        if width == 0 || height == 0 || pixels.len() as u64 != width as u64 * height as u64 * 4 {
+            return Err(StudioError::Display(DisplayError::IconInvalid));
+        }
+
+        Ok(WindowIcon { width, height, pixels })
+    }
+
+    /// Get the (width, height) of icon.
+    pub fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Get the RGBA pixels of icon.
+    pub fn get_pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Get the pixels of icon as ARGB values.
+    pub fn get_argb(&self) -> impl Iterator<Item = u32> + '_ {
+        self.pixels.chunks_exact(4).map(|rgba| u32::from_be_bytes([rgba[3], rgba[0], rgba[1], rgba[2]]))
+    }
+}
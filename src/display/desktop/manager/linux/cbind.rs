@@ -0,0 +1,123 @@
+//! Bindings of the C standard library functions used by linux managers.
+
+use std::os::raw::{c_char, c_int, c_short, c_uint, c_ulong, c_void};
+
+// Flags of open.
+pub const O_RDONLY : c_int = 0;
+pub const O_RDWR : c_int = 2;
+pub const O_NONBLOCK : c_int = 0o4000;
+pub const O_CLOEXEC : c_int = 0o2000000;
+
+// Events of poll.
+pub const POLLIN : c_short = 0x001;
+pub const POLLOUT : c_short = 0x004;
+pub const POLLERR : c_short = 0x008;
+pub const POLLHUP : c_short = 0x010;
+pub const POLLNVAL : c_short = 0x020;
+
+// Flags of inotify_init1.
+pub const IN_NONBLOCK : c_int = O_NONBLOCK;
+pub const IN_CLOEXEC : c_int = O_CLOEXEC;
+
+// Flags of eventfd.
+pub const EFD_NONBLOCK : c_int = O_NONBLOCK;
+pub const EFD_CLOEXEC : c_int = O_CLOEXEC;
+
+// Events of inotify_add_watch.
+pub const IN_ATTRIB : u32 = 0x00000004;
+pub const IN_CREATE : u32 = 0x00000100;
+pub const IN_DELETE : u32 = 0x00000200;
+
+/// File descriptor polled by poll.
+///
+/// Reference(s)
+/// <https://man7.org/linux/man-pages/man2/poll.2.html>
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollFd {
+    pub fd : c_int,
+    pub events : c_short,
+    pub revents : c_short,
+}
+
+impl PollFd {
+    /// Create a new [PollFd] waiting for input on fd.
+    pub fn new(fd : c_int) -> PollFd {
+        PollFd { fd, events: POLLIN, revents: 0 }
+    }
+
+    /// Create a new [PollFd] waiting for events on fd.
+    pub fn with_events(fd : c_int, events : c_short) -> PollFd {
+        PollFd { fd, events, revents: 0 }
+    }
+}
+
+/// Header of an inotify event. Followed by `len` bytes of name.
+///
+/// Reference(s)
+/// <https://man7.org/linux/man-pages/man7/inotify.7.html>
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InotifyEvent {
+    pub wd : c_int,
+    pub mask : u32,
+    pub cookie : u32,
+    pub len : u32,
+}
+
+#[allow(dead_code)]
+extern "C" {
+    /// The open() system call opens the file specified by pathname.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/open.2.html>
+    pub(crate) fn open(pathname : *const c_char, flags : c_int, ...) -> c_int;
+
+    /// close() closes a file descriptor, so that it no longer refers to any file and may be reused.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/close.2.html>
+    pub(crate) fn close(fd : c_int) -> c_int;
+
+    /// read() attempts to read up to count bytes from file descriptor fd into the buffer starting at buf.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/read.2.html>
+    pub(crate) fn read(fd : c_int, buf : *mut c_void, count : usize) -> isize;
+
+    /// write() writes up to count bytes from the buffer starting at buf to the file referred to by the file descriptor fd.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/write.2.html>
+    pub(crate) fn write(fd : c_int, buf : *const c_void, count : usize) -> isize;
+
+    /// The ioctl() system call manipulates the underlying device parameters of special files.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/ioctl.2.html>
+    pub(crate) fn ioctl(fd : c_int, request : c_ulong, ...) -> c_int;
+
+    /// poll() waits for one of a set of file descriptors to become ready to perform I/O.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/poll.2.html>
+    pub(crate) fn poll(fds : *mut PollFd, nfds : c_ulong, timeout : c_int) -> c_int;
+
+    /// inotify_init1() initializes a new inotify instance and returns a file descriptor associated with a new inotify event queue.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/inotify_init1.2.html>
+    pub(crate) fn inotify_init1(flags : c_int) -> c_int;
+
+    /// inotify_add_watch() adds a new watch, or modifies an existing watch, for the file whose location is specified in pathname.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html>
+    pub(crate) fn inotify_add_watch(fd : c_int, pathname : *const c_char, mask : u32) -> c_int;
+
+    /// eventfd() creates an "eventfd object" that can be used as an event wait/notify mechanism by user-space applications.
+    ///
+    /// Reference(s)
+    /// <https://man7.org/linux/man-pages/man2/eventfd.2.html>
+    pub(crate) fn eventfd(initval : c_uint, flags : c_int) -> c_int;
+}
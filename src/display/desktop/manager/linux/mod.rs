@@ -1,7 +1,12 @@
 //! Linux implementations of [WindowManager].
 
-use crate::{display::{ desktop::{ manager::WindowManager, window::{  Window}, event::{Event}, property::{WindowProperty, SubWindowOption, WindowPositionOption, KeyboardMode, WindowEventWaitMode}}, DisplayError}, error::StudioError};
-use self::{wayland::{WaylandWindowManager, WAYLAND_SUPPORTED}, x11::X11WindowManager};
+use std::any::{Any, TypeId};
+use std::collections::VecDeque;
+use std::ffi::c_int;
+use std::time::Duration;
+
+use crate::{display::{ desktop::{ application::WindowId, gamepad::GamepadList, icon::WindowIcon, proxy::EventProxy, region::DamageRegion, source::FdSourceList, timer::TimerList, manager::{WindowManager, ApplicationManager}, window::{  Window}, event::{Event, EventCategoryMask}, property::{WindowProperty, SubWindowOption, WindowPositionOption, KeyboardMode, WindowEventWaitMode, WindowType}}, DisplayError}, error::StudioError};
+use self::{wayland::{WaylandWindowManager, WaylandApplicationManager, WAYLAND_SUPPORTED}, x11::X11WindowManager, x11::application::X11ApplicationManager};
 use super::WindowProvider;
 
 /// Wayland DisplayManager
@@ -10,6 +15,25 @@ pub mod wayland;
 /// X11 DisplayManager
 pub mod x11;
 
+/// C standard library bindings
+pub(crate) mod cbind;
+
+/// Waker of blocked event wait
+pub(crate) mod waker;
+
+/// Thread waking async tasks
+#[cfg(feature = "async")]
+pub(crate) mod waiter;
+
+
+/// Convert a timeout to the milliseconds of poll(), rounded up so that poll() doesn't return before timeout.
+/// None is converted to -1 that wait forever.
+pub(crate) fn get_poll_timeout(timeout : Option<Duration>) -> c_int {
+    match timeout {
+        Some(timeout) => timeout.as_nanos().div_ceil(1_000_000).min(c_int::MAX as u128) as c_int,
+        None => -1,
+    }
+}
 
 /// Enumeration of implemented [WindowManager]
 enum ImplementedLinuxWindowManager<'window>{
@@ -86,6 +110,56 @@ impl<'window> WindowManager<'window> for LinuxWindowManager<'window> {
         wmfn!(self, push_event(event))
     }
 
+    #[inline(always)]
+    fn get_gamepads(&self) -> &GamepadList {
+        wmfn!(self, get_gamepads())
+    }
+
+    #[inline(always)]
+    fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        wmfn!(mut self, get_gamepads_mut())
+    }
+
+    #[inline(always)]
+    fn create_proxy(&self) -> EventProxy {
+        wmfn!(self, create_proxy())
+    }
+
+    #[inline(always)]
+    fn take_user_payload(&self, key : u64, type_id : TypeId) -> Option<Box<dyn Any + Send>> {
+        wmfn!(self, take_user_payload(key, type_id))
+    }
+
+    #[inline(always)]
+    fn get_timers(&self) -> &TimerList {
+        wmfn!(self, get_timers())
+    }
+
+    #[inline(always)]
+    fn get_timers_mut(&mut self) -> &mut TimerList {
+        wmfn!(mut self, get_timers_mut())
+    }
+
+    #[inline(always)]
+    fn get_fd_sources(&self) -> &FdSourceList {
+        wmfn!(self, get_fd_sources())
+    }
+
+    #[inline(always)]
+    fn get_fd_sources_mut(&mut self) -> &mut FdSourceList {
+        wmfn!(mut self, get_fd_sources_mut())
+    }
+
+    #[inline(always)]
+    fn get_damage_region(&self) -> &DamageRegion {
+        wmfn!(self, get_damage_region())
+    }
+
+    #[inline(always)]
+    fn request_redraw(&mut self) {
+        wmfn!(mut self, request_redraw())
+    }
+
     #[inline(always)]
     fn show(&mut self){
         wmfn!(mut self, show())
@@ -106,6 +180,16 @@ impl<'window> WindowManager<'window> for LinuxWindowManager<'window> {
         wmfn!(mut self, set_event_wait_mode(mode))
     }
 
+    #[inline(always)]
+    fn set_event_mask(&mut self, mask : EventCategoryMask) -> bool {
+        wmfn!(mut self, set_event_mask(mask))
+    }
+
+    #[inline(always)]
+    fn set_event_coalescing(&mut self, mask : EventCategoryMask) -> bool {
+        wmfn!(mut self, set_event_coalescing(mask))
+    }
+
     
 
     #[inline(always)]
@@ -123,6 +207,66 @@ impl<'window> WindowManager<'window> for LinuxWindowManager<'window> {
          wmfn!(mut self, set_size(size))
     }
 
+    #[inline(always)]
+    fn set_min_size(&mut self, min_size : Option<(u32, u32)>) -> bool {
+        wmfn!(mut self, set_min_size(min_size))
+    }
+
+    #[inline(always)]
+    fn set_max_size(&mut self, max_size : Option<(u32, u32)>) -> bool {
+        wmfn!(mut self, set_max_size(max_size))
+    }
+
+    #[inline(always)]
+    fn set_aspect_ratio(&mut self, aspect_ratio : Option<(u32, u32)>) -> bool {
+        wmfn!(mut self, set_aspect_ratio(aspect_ratio))
+    }
+
+    #[inline(always)]
+    fn set_resize_increment(&mut self, resize_increment : Option<(u32, u32)>) -> bool {
+        wmfn!(mut self, set_resize_increment(resize_increment))
+    }
+
+    #[inline(always)]
+    fn set_resizable(&mut self, resizable : bool) -> bool {
+        wmfn!(mut self, set_resizable(resizable))
+    }
+
+    #[inline(always)]
+    fn set_icon(&mut self, icon : &[WindowIcon]) -> bool {
+        wmfn!(mut self, set_icon(icon))
+    }
+
+    #[inline(always)]
+    fn set_window_type(&mut self, window_type : WindowType) -> bool {
+        wmfn!(mut self, set_window_type(window_type))
+    }
+
+    #[inline(always)]
+    fn set_above(&mut self, above : bool) -> bool {
+        wmfn!(mut self, set_above(above))
+    }
+
+    #[inline(always)]
+    fn set_below(&mut self, below : bool) -> bool {
+        wmfn!(mut self, set_below(below))
+    }
+
+    #[inline(always)]
+    fn set_sticky(&mut self, sticky : bool) -> bool {
+        wmfn!(mut self, set_sticky(sticky))
+    }
+
+    #[inline(always)]
+    fn set_skip_taskbar(&mut self, skip_taskbar : bool) -> bool {
+        wmfn!(mut self, set_skip_taskbar(skip_taskbar))
+    }
+
+    #[inline(always)]
+    fn set_skip_pager(&mut self, skip_pager : bool) -> bool {
+        wmfn!(mut self, set_skip_pager(skip_pager))
+    }
+
     #[inline(always)]
     fn show_decoration(&mut self) -> bool {
          wmfn!(mut self, show_decoration())
@@ -183,6 +327,16 @@ impl<'window> WindowManager<'window> for LinuxWindowManager<'window> {
         wmfn!(self, get_display_handle())
     }
 
+    #[inline(always)]
+    fn get_event_fds(&self) -> Vec<c_int> {
+        wmfn!(self, get_event_fds())
+    }
+
+    #[inline(always)]
+    fn has_queued_events(&self) -> bool {
+        wmfn!(self, has_queued_events())
+    }
+
     fn get_properties(&self) -> &WindowProperty {
         wmfn!(self, get_properties())
     }
@@ -243,9 +397,86 @@ impl<'window> WindowManager<'window> for LinuxWindowManager<'window> {
         static_wmfn!(is_numlock_on(state))
     }
 
+    fn get_key_state(modifiers : u8) -> u32 {
+        static_wmfn!(get_key_state(modifiers))
+    }
+
     
 
 
+}
+
+impl<'window> LinuxWindowManager<'window> {
+    /// Get the X11 window manager if window use X11.
+    pub(crate) fn get_x11_mut(&mut self) -> Option<&mut X11WindowManager<'window>> {
+        match &mut self.wm {
+            ImplementedLinuxWindowManager::X11(wm) => Some(wm),
+            _ => None,
+        }
+    }
+}
+
+/// Enumeration of implemented [ApplicationManager]
+enum ImplementedLinuxApplicationManager {
+    Wayland(WaylandApplicationManager),
+    X11(X11ApplicationManager),
+}
+
+pub struct LinuxApplicationManager {
+    am : ImplementedLinuxApplicationManager,
+}
+
+impl ApplicationManager for LinuxApplicationManager {
+    fn new() -> Result<Self, StudioError> where Self : Sized {
+        if wayland::WaylandWindowManager::is_supported() {
+            Ok(LinuxApplicationManager { 
+                am : ImplementedLinuxApplicationManager::Wayland(WaylandApplicationManager::new()?)
+            })
+        } else if x11::X11WindowManager::is_supported() {
+            Ok(LinuxApplicationManager { 
+                am : ImplementedLinuxApplicationManager::X11(X11ApplicationManager::new()?)
+            })
+        } else {    // No supported display server available
+            Err(StudioError::Display(DisplayError::NoDisplayServer))
+        }
+    }
+
+    fn get_window_provider(&self) -> WindowProvider {
+        match &self.am {
+            ImplementedLinuxApplicationManager::Wayland(_) => WindowProvider::Wayland,
+            ImplementedLinuxApplicationManager::X11(_) => WindowProvider::X11,
+        }
+    }
+
+    fn create_window_manager<'window>(&self) -> LinuxWindowManager<'window> {
+        match &self.am {
+            ImplementedLinuxApplicationManager::Wayland(am) => LinuxWindowManager { 
+                wm : ImplementedLinuxWindowManager::Wayland(am.create_window_manager()) },
+            ImplementedLinuxApplicationManager::X11(am) => LinuxWindowManager { 
+                wm : ImplementedLinuxWindowManager::X11(am.create_window_manager()) },
+        }
+    }
+
+    fn dispatch_events<'window>(&mut self, windows : &mut [(WindowId, Window<'window>)], events : &mut VecDeque<(WindowId, Event)>, wait : bool, timeout : Option<Duration>) {
+        match &mut self.am {
+            ImplementedLinuxApplicationManager::Wayland(am) => am.dispatch_events(windows, events, wait, timeout),
+            ImplementedLinuxApplicationManager::X11(am) => am.dispatch_events(windows, events, wait, timeout),
+        }
+    }
+
+    fn get_gamepads(&self) -> &GamepadList {
+        match &self.am {
+            ImplementedLinuxApplicationManager::Wayland(am) => am.get_gamepads(),
+            ImplementedLinuxApplicationManager::X11(am) => am.get_gamepads(),
+        }
+    }
+
+    fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        match &mut self.am {
+            ImplementedLinuxApplicationManager::Wayland(am) => am.get_gamepads_mut(),
+            ImplementedLinuxApplicationManager::X11(am) => am.get_gamepads_mut(),
+        }
+    }
 }
 
 /*
@@ -0,0 +1,108 @@
+//! Thread waiting on file descriptors to wake async tasks.
+
+use std::ffi::{c_int, c_ulong};
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::Waker;
+use std::thread::{self, JoinHandle};
+
+use super::cbind::{PollFd, poll};
+use super::waker::EventFdWaker;
+
+/// Request of a [FdWaiter] wait.
+struct FdWaitRequest {
+    /// File descriptors waited for.
+    fds : Vec<PollFd>,
+
+    /// Timeout of wait in milliseconds. Negative wait forever.
+    timeout : c_int,
+}
+
+/// State shared between a [FdWaiter] and its thread.
+struct FdWaiterShared {
+    /// Next wait request. None if no wait requested.
+    request : Mutex<Option<FdWaitRequest>>,
+
+    /// Notify the thread that a request was made or that it must stop.
+    condvar : Condvar,
+
+    /// Waker of the task to wake once a wait ends.
+    waker : Mutex<Option<Waker>>,
+
+    /// Thread must stop.
+    stop : AtomicBool,
+
+    /// Interrupt the thread wait on drop.
+    cancel : EventFdWaker,
+}
+
+/// Thread waiting with poll() on file descriptors for an async task, waking the task when one is readable or the
+/// wait times out. Lets any executor wait for display events without knowing their file descriptors.
+pub(crate) struct FdWaiter {
+    shared : Arc<FdWaiterShared>,
+    thread : Option<JoinHandle<()>>,
+}
+
+impl FdWaiter {
+    /// Create a new [FdWaiter] and its thread.
+    pub(crate) fn new() -> FdWaiter {
+        let shared = Arc::new(FdWaiterShared { request: Mutex::new(None), condvar: Condvar::new(), waker: Mutex::new(None),
+            stop: AtomicBool::new(false), cancel: EventFdWaker::new() });
+        let thread_shared = shared.clone();
+
+        FdWaiter { shared, thread: Some(thread::spawn(move || Self::run(thread_shared))) }
+    }
+
+    /// Wake waker once one of fds is ready or timeout in milliseconds expires (negative wait forever).
+    ///
+    /// If a wait is in progress, it wakes waker when it ends and the request is waited next.
+    pub(crate) fn wait(&self, fds : Vec<PollFd>, timeout : c_int, waker : &Waker) {
+        *self.shared.waker.lock().unwrap() = Some(waker.clone());
+        *self.shared.request.lock().unwrap() = Some(FdWaitRequest { fds, timeout });
+        self.shared.condvar.notify_one();
+    }
+
+    /// Loop of thread, waiting for requests then waiting on their file descriptors.
+    fn run(shared : Arc<FdWaiterShared>) {
+        loop {
+            let request = {
+                let mut request = shared.request.lock().unwrap();
+                while request.is_none() && !shared.stop.load(Ordering::Acquire) {
+                    request = shared.condvar.wait(request).unwrap();
+                }
+
+                match request.take() {
+                    Some(request) if !shared.stop.load(Ordering::Acquire) => request,
+                    _ => break,
+                }
+            };
+
+            let mut fds = request.fds;
+            fds.extend(shared.cancel.get_fd().map(PollFd::new));
+
+            unsafe {
+                poll(fds.as_mut_ptr(), fds.len() as c_ulong, request.timeout);
+            }
+
+            if let Some(waker) = shared.waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl Drop for FdWaiter {
+    fn drop(&mut self) {
+        {
+            // Stop is set while locked so that the thread can't miss the notification.
+            let _request = self.shared.request.lock().unwrap();
+            self.shared.stop.store(true, Ordering::Release);
+        }
+        self.shared.cancel.wake();
+        self.shared.condvar.notify_one();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Waker of a blocked event wait, usable from any thread.
+
+use std::ffi::{c_int, c_void};
+
+use super::cbind::{eventfd, read, write, close, EFD_NONBLOCK, EFD_CLOEXEC};
+
+/// Wake a thread waiting with poll() on its file descriptor, implemented with an eventfd.
+pub(crate) struct EventFdWaker {
+    /// Eventfd file descriptor. Negative if eventfd couldn't be created.
+    fd : c_int,
+}
+
+impl EventFdWaker {
+    /// Create a new [EventFdWaker]. Waking does nothing if the eventfd couldn't be created.
+    pub(crate) fn new() -> EventFdWaker {
+        unsafe {
+            EventFdWaker { fd: eventfd(0, EFD_NONBLOCK | EFD_CLOEXEC) }
+        }
+    }
+
+    /// Get the file descriptor to poll. None if eventfd couldn't be created.
+    pub(crate) fn get_fd(&self) -> Option<c_int> {
+        if self.fd >= 0 { Some(self.fd) } else { None }
+    }
+
+    /// Make the file descriptor readable, waking the poll() waiting on it.
+    pub(crate) fn wake(&self) {
+        if self.fd >= 0 {
+            let value : u64 = 1;
+            unsafe {
+                write(self.fd, &value as *const u64 as *const c_void, size_of::<u64>());
+            }
+        }
+    }
+
+    /// Reset the file descriptor to not readable.
+    pub(crate) fn clear(&self) {
+        if self.fd >= 0 {
+            let mut value : u64 = 0;
+            unsafe {
+                read(self.fd, &mut value as *mut u64 as *mut c_void, size_of::<u64>());
+            }
+        }
+    }
+}
+
+impl Drop for EventFdWaker {
+    fn drop(&mut self) {
+        if self.fd >= 0 {
+            unsafe {
+                close(self.fd);
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{display::desktop::{manager::WindowManager, manager::WindowProvider, Window, property::{KeyboardMode, WindowEventWaitMode}}, error::StudioError};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::{display::desktop::{application::WindowId, event::Event, gamepad::GamepadList, manager::WindowManager, manager::WindowProvider, Window, property::{KeyboardMode, WindowEventWaitMode}}, error::StudioError};
 
 /// Static cache to know if wayland is supported
 #[doc(hidden)]
@@ -23,6 +26,33 @@ impl WaylandWindowManager {
     }
 }
 
+/// Wayland application sharing a display connection between windows.
+pub(crate) struct WaylandApplicationManager {
+
+}
+
+impl WaylandApplicationManager {
+    pub fn new() -> Result<WaylandApplicationManager, StudioError> {
+        todo!()
+    }
+
+    pub fn create_window_manager(&self) -> WaylandWindowManager {
+        todo!()
+    }
+
+    pub fn dispatch_events(&mut self, _windows : &mut [(WindowId, Window)], _events : &mut VecDeque<(WindowId, Event)>, _wait : bool, _timeout : Option<Duration>) {
+        todo!()
+    }
+
+    pub fn get_gamepads(&self) -> &GamepadList {
+        todo!()
+    }
+
+    pub fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        todo!()
+    }
+}
+
 impl<'window> WindowManager<'window> for WaylandWindowManager {
     fn new() -> Result<Self, StudioError> where Self : Sized {
         todo!()
@@ -69,6 +99,46 @@ impl<'window> WindowManager<'window> for WaylandWindowManager {
         todo!()
     }
 
+    fn get_gamepads(&self) -> &crate::display::desktop::gamepad::GamepadList {
+        todo!()
+    }
+
+    fn get_gamepads_mut(&mut self) -> &mut crate::display::desktop::gamepad::GamepadList {
+        todo!()
+    }
+
+    fn create_proxy(&self) -> crate::display::desktop::proxy::EventProxy {
+        todo!()
+    }
+
+    fn take_user_payload(&self, _key : u64, _type_id : std::any::TypeId) -> Option<Box<dyn std::any::Any + Send>> {
+        todo!()
+    }
+
+    fn get_timers(&self) -> &crate::display::desktop::timer::TimerList {
+        todo!()
+    }
+
+    fn get_timers_mut(&mut self) -> &mut crate::display::desktop::timer::TimerList {
+        todo!()
+    }
+
+    fn get_fd_sources(&self) -> &crate::display::desktop::source::FdSourceList {
+        todo!()
+    }
+
+    fn get_fd_sources_mut(&mut self) -> &mut crate::display::desktop::source::FdSourceList {
+        todo!()
+    }
+
+    fn get_damage_region(&self) -> &crate::display::desktop::region::DamageRegion {
+        todo!()
+    }
+
+    fn request_redraw(&mut self) {
+        todo!()
+    }
+
     #[cfg(any(doc,target_os = "linux"))]
 #[cfg_attr(docsrs,doc(cfg(target_os = "linux")))]
 #[doc = " Get the OS Window manager display handle."]
@@ -76,6 +146,14 @@ fn get_display_handle(&self) ->  *const usize {
         todo!()
     }
 
+    fn get_event_fds(&self) -> Vec<std::ffi::c_int> {
+        todo!()
+    }
+
+    fn has_queued_events(&self) -> bool {
+        todo!()
+    }
+
    
 
     fn remove_parent(&mut self) -> bool {
@@ -94,6 +172,54 @@ fn get_display_handle(&self) ->  *const usize {
         todo!()
     }
 
+    fn set_min_size(&mut self, _min_size : Option<(u32, u32)>) -> bool {
+        todo!()
+    }
+
+    fn set_max_size(&mut self, _max_size : Option<(u32, u32)>) -> bool {
+        todo!()
+    }
+
+    fn set_aspect_ratio(&mut self, _aspect_ratio : Option<(u32, u32)>) -> bool {
+        todo!()
+    }
+
+    fn set_resize_increment(&mut self, _resize_increment : Option<(u32, u32)>) -> bool {
+        todo!()
+    }
+
+    fn set_resizable(&mut self, _resizable : bool) -> bool {
+        todo!()
+    }
+
+    fn set_icon(&mut self, _icon : &[crate::display::desktop::icon::WindowIcon]) -> bool {
+        todo!()
+    }
+
+    fn set_window_type(&mut self, _window_type : crate::display::desktop::property::WindowType) -> bool {
+        todo!()
+    }
+
+    fn set_above(&mut self, _above : bool) -> bool {
+        todo!()
+    }
+
+    fn set_below(&mut self, _below : bool) -> bool {
+        todo!()
+    }
+
+    fn set_sticky(&mut self, _sticky : bool) -> bool {
+        todo!()
+    }
+
+    fn set_skip_taskbar(&mut self, _skip_taskbar : bool) -> bool {
+        todo!()
+    }
+
+    fn set_skip_pager(&mut self, _skip_pager : bool) -> bool {
+        todo!()
+    }
+
     fn show_decoration(&mut self) -> bool {
         todo!()
     }
@@ -115,6 +241,16 @@ fn get_display_handle(&self) ->  *const usize {
         todo!()
     }
 
+    #[inline(always)]
+    fn set_event_mask(&mut self, _mask : crate::display::desktop::event::EventCategoryMask) -> bool {
+        todo!()
+    }
+
+    #[inline(always)]
+    fn set_event_coalescing(&mut self, _mask : crate::display::desktop::event::EventCategoryMask) -> bool {
+        todo!()
+    }
+
     fn set_fullscreen(&mut self, _fsmode : crate::display::desktop::property::FullScreenMode) -> bool {
         todo!()
     }
@@ -183,6 +319,10 @@ fn get_display_handle(&self) ->  *const usize {
         todo!()
     }
 
+    fn get_key_state(_modifiers : u8) -> u32 {
+        todo!()
+    }
+
 
 }
 
@@ -0,0 +1,163 @@
+//! Contains X11 application sharing a display connection between windows.
+
+use std::collections::VecDeque;
+use std::ffi::{c_int, c_ulong};
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::display::DisplayError;
+use crate::display::desktop::application::WindowId;
+use crate::display::desktop::event::Event;
+use crate::display::desktop::gamepad::GamepadList;
+use crate::display::desktop::window::Window;
+use crate::error::StudioError;
+
+use super::super::cbind::{PollFd, poll};
+use super::super::get_poll_timeout;
+use super::cbind::constants::GenericEvent;
+use super::cbind::functs::{XQueryExtension, XPending, XNextEvent, XConnectionNumber, XGetEventData, XFreeEventData};
+use super::cbind::structs::{XEvent, X11Display, X11Handle};
+use super::cbind::xinput2::*;
+use super::{X11Connection, X11WindowManager};
+
+/// X11 application reading events of a shared display connection and routing them to windows.
+pub(crate) struct X11ApplicationManager {
+    /// Display connection shared with windows.
+    connection : Rc<X11Connection>,
+
+    /// Display connection pointer
+    display : *mut X11Display,
+
+    /// XInput extension opcode. None if not supported by server.
+    xinput_opcode : Option<c_int>,
+
+    /// Connected gamepads.
+    gamepads : GamepadList,
+}
+
+impl X11ApplicationManager {
+    /// Open the display connection shared by windows.
+    ///
+    /// Returns Ok([X11ApplicationManager]) on success, Err([StudioError]) if display can't be opened.
+    pub(crate) fn new() -> Result<X11ApplicationManager, StudioError> {
+        let connection = Rc::new(X11Connection::open());
+        let display = connection.display;
+
+        if display.is_null() {
+            return Err(StudioError::Display(DisplayError::NoDisplayServer));
+        }
+
+        let xinput_opcode = unsafe {
+            let mut opcode : c_int = 0;
+            let mut event : c_int = 0;
+            let mut error : c_int = 0;
+
+            if XQueryExtension(display, XInputExtensionName.as_ptr() as *const i8, &mut opcode, &mut event, &mut error) {
+                Some(opcode)
+            } else {
+                None
+            }
+        };
+
+        Ok(X11ApplicationManager { connection, display, xinput_opcode, gamepads: GamepadList::new() })
+    }
+
+    /// Create a window manager using the shared display connection.
+    pub(crate) fn create_window_manager<'app>(&self) -> X11WindowManager<'app> {
+        X11WindowManager::new_shared(self.connection.clone())
+    }
+
+    /// Get immutable reference to the connected gamepads.
+    pub(crate) fn get_gamepads(&self) -> &GamepadList {
+        &self.gamepads
+    }
+
+    /// Get mutable reference to the connected gamepads.
+    pub(crate) fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        &mut self.gamepads
+    }
+
+    /// Read all X11 events queued and push the events of windows into events. If wait is true and no X11 event
+    /// is queued, wait until the display connection, a gamepad or a user event is readable or until timeout expires.
+    pub(crate) fn dispatch_events<'app>(&self, windows : &mut [(WindowId, Window<'app>)], events : &mut VecDeque<(WindowId, Event)>, wait : bool, timeout : Option<Duration>) {
+        unsafe {
+            if wait && XPending(self.display) == 0 {
+                self.wait_fds(windows, get_poll_timeout(timeout));
+            }
+
+            let mut xevent = XEvent{ _type:0 };
+            for _ in 0..XPending(self.display) {
+                XNextEvent(self.display, &mut xevent);
+                self.dispatch_event(xevent, windows, events);
+            }
+        }
+    }
+
+    /// Route a X11 event to its window. XInput2 events data are fetched here so that the target window can be read.
+    #[allow(non_upper_case_globals)]
+    fn dispatch_event<'app>(&self, mut xevent : XEvent, windows : &mut [(WindowId, Window<'app>)], events : &mut VecDeque<(WindowId, Event)>) {
+        unsafe {
+            if xevent._type == GenericEvent {
+                let mut cookie = xevent._xcookie;
+
+                if Some(cookie._extension) == self.xinput_opcode && XGetEventData(self.display, &mut cookie) {
+                    let target = match cookie._evtype {
+                        XI_Motion | XI_ButtonPress | XI_ButtonRelease | XI_KeyPress | XI_KeyRelease =>
+                            Some((*(cookie._data as *const XIDeviceEvent))._event),
+                        XI_Enter | XI_Leave =>
+                            Some((*(cookie._data as *const XIEnterEvent))._event),
+                        XI_GesturePinchBegin | XI_GesturePinchUpdate | XI_GesturePinchEnd =>
+                            Some((*(cookie._data as *const XIGesturePinchEvent))._event),
+                        XI_GestureSwipeBegin | XI_GestureSwipeUpdate | XI_GestureSwipeEnd =>
+                            Some((*(cookie._data as *const XIGestureSwipeEvent))._event),
+                        _ => None,  // Device events are sent to all windows.
+                    };
+
+                    xevent._xcookie = cookie;
+                    Self::route_event(&xevent, target, windows, events);
+                    XFreeEventData(self.display, &mut cookie);
+                }
+            } else {
+                Self::route_event(&xevent, Some(xevent._xany._window), windows, events);
+            }
+        }
+    }
+
+    /// Push a X11 event to the created window with the target handle, or to all created windows if no target,
+    /// then poll the resulting events.
+    fn route_event<'app>(xevent : &XEvent, target : Option<X11Handle>, windows : &mut [(WindowId, Window<'app>)], events : &mut VecDeque<(WindowId, Event)>) {
+        for (id, window) in windows.iter_mut() {
+            let routed = match window.manager.get_x11_mut() {
+                Some(wm) if !wm.window.is_null() && target.is_none_or(|target| wm.window as X11Handle == target) => {
+                    wm.pending_events.push_back(*xevent);
+                    true
+                },
+                _ => false,
+            };
+
+            if routed {
+                loop {
+                    match *window.poll_event() {
+                        Event::None => break,
+                        event => events.push_back((*id, event)),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Wait until the X11 connection, a gamepad, a user event or an external file descriptor of windows is ready, or
+    /// until timeout in milliseconds expires. A negative timeout wait forever.
+    fn wait_fds<'app>(&self, windows : &mut [(WindowId, Window<'app>)], timeout : c_int) {
+        unsafe {
+            let mut fds : Vec<PollFd> = vec![PollFd::new(XConnectionNumber(self.display))];
+            fds.extend(self.gamepads.get_fds().into_iter().map(PollFd::new));
+            for wm in windows.iter_mut().filter_map(|(_, window)| window.manager.get_x11_mut()) {
+                fds.extend(wm.user_events.get_fd().map(PollFd::new));
+                fds.extend(wm.fd_sources.get_poll_fds());
+            }
+
+            poll(fds.as_mut_ptr(), fds.len() as c_ulong, timeout);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 // Generated with "script/rustify_x11_constants.sh"
-use std::os::raw::{ c_int, c_long };
+use std::os::raw::{ c_int, c_long, c_ulong };
 
 /* Definitions for the X window system likely to be used by applications */
 /***********************************************************
@@ -283,6 +283,8 @@ pub const LowerHighest:c_int=1;
 pub const PropModeReplace:c_int=0;
 pub const PropModePrepend:c_int=1;
 pub const PropModeAppend:c_int=2;
+/* Predefined atoms */
+pub const XA_CARDINAL:c_ulong=6;
 /*****************************************************************
 * GRAPHICS DEFINITIONS
 *****************************************************************/
@@ -456,4 +458,19 @@ pub const TrueColor:c_int=4;
 pub const DirectColor:c_int=5;
 /* Byte order  used in imageByteOrder and bitmapBitOrder */
 pub const LSBFirst:c_int=0;
-pub const MSBFirst:c_int=1;
\ No newline at end of file
+pub const MSBFirst:c_int=1;
+/* Modes of XEventsQueued */
+pub const QueuedAlready:c_int=0;
+pub const QueuedAfterReading:c_int=1;
+pub const QueuedAfterFlush:c_int=2;
+/* Flags of XSizeHints */
+pub const USPosition:c_long=1 << 0;
+pub const USSize:c_long=1 << 1;
+pub const PPosition:c_long=1 << 2;
+pub const PSize:c_long=1 << 3;
+pub const PMinSize:c_long=1 << 4;
+pub const PMaxSize:c_long=1 << 5;
+pub const PResizeInc:c_long=1 << 6;
+pub const PAspect:c_long=1 << 7;
+pub const PBaseSize:c_long=1 << 8;
+pub const PWinGravity:c_long=1 << 9;
@@ -3,8 +3,9 @@ use std::ffi::c_void;
 use std::os::raw::{c_uchar, c_char, c_int, c_long, c_uint, c_ulong};
 
 use super::attributes::{XWindowAttributes, Visual, XSetWindowAttributes, Screen};
-use super::structs::{ XEvent, Atom, XClientMessageEvent, X11Display, X11Handle, XKeyPressedEvent, XKeyEvent};
+use super::structs::{ XEvent, Atom, XClientMessageEvent, X11Display, X11Handle, XKeyPressedEvent, XKeyEvent, XGenericEventCookie, XSizeHints};
 use super::xinput::{XIM, XIMStyles, XIMStyle, XIC};
+use super::xinput2::{XIEventMask, XIDeviceInfo};
 use super::xkb::{XkbDesc, X11Keysim};
 
 
@@ -22,6 +23,14 @@ extern {
         border_width : c_uint, depth : c_int, class : c_uint, visual : *mut Visual, valuemask : c_uint, 
         attributes : *mut XSetWindowAttributes) -> *mut X11Handle;
 
+    /// The XChangeWindowAttributes function uses the x11window attributes in the XSetWindowAttributes structure to change 
+    /// the specified x11window attributes. The valuemask argument specifies which x11window attributes are defined.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XChangeWindowAttributes>
+    pub(crate) fn XChangeWindowAttributes(x11display : *mut X11Display, w : *mut X11Handle, valuemask : c_ulong, 
+        attributes : *mut XSetWindowAttributes);
+
     /// The XOpenDisplay function returns a X11Display structure that serves as the connection to the 
     /// X server and that contains all the information about that X server.
     /// 
@@ -54,6 +63,18 @@ extern {
     /// <https://tronche.com/gui/x/xlib/event-handling/XEventsQueued.html>
     pub(crate) fn XEventsQueued(x11display : *mut X11Display, mode : c_int) -> c_int;
 
+    /// The XPending function returns the number of events that have been received from the X server but have not been removed from the event queue.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XPending>
+    pub(crate) fn XPending(x11display : *mut X11Display) -> c_int;
+
+    /// The XConnectionNumber function returns a connection number for the specified display.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XConnectionNumber>
+    pub(crate) fn XConnectionNumber(x11display : *mut X11Display) -> c_int;
+
     /// Returns the root x11window for the default screen. 
     /// 
     /// # Reference(s)
@@ -104,6 +125,12 @@ extern {
     /// <https://tronche.com/gui/x/xlib/event-handling/manipulating-event-queue/XNextEvent.html>
     pub(crate) fn XNextEvent(x11display : *mut X11Display, event_return : *mut XEvent);
 
+    /// The XPeekEvent() function returns the first event from the event queue, but it does not remove the event from the queue.
+    /// 
+    /// # Reference(s)
+    /// <https://tronche.com/gui/x/xlib/event-handling/manipulating-event-queue/XPeekEvent.html>
+    pub(crate) fn XPeekEvent(x11display : *mut X11Display, event_return : *mut XEvent);
+
     /// Move the pointer to an arbitrary point in a x11window.
     /// 
     /// Reference(s)
@@ -159,7 +186,20 @@ extern {
     /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XChangeProperty>
     pub(crate) fn  XChangeProperty(x11display : *mut X11Display, w : *mut X11Handle, property : Atom, property_type : Atom, 
         format : c_int, mode : c_int, data : *mut c_uchar, nelements : c_int);
+
+    /// The XDeleteProperty function deletes the specified property only if the property was defined on the specified window
+    /// and causes the X server to generate a PropertyNotify event on the window unless the property does not exist.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XDeleteProperty>
+    pub(crate) fn XDeleteProperty(x11display : *mut X11Display, w : *mut X11Handle, property : Atom);
     
+    /// The XGetSelectionOwner function returns the window ID associated with the window that currently owns the specified selection.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XGetSelectionOwner>
+    pub(crate) fn XGetSelectionOwner(x11display : *mut X11Display, selection : Atom) -> *mut X11Handle;
+
     /// The function is a general-purpose Xlib routine that frees the specified data.
     /// 
     /// Reference(s)
@@ -245,6 +285,12 @@ extern {
     /// <https://www.x.org/releases/X11R7.5/doc/man/man3/XSetWMProtocols.3.html>
     pub(crate) fn XSetWMProtocols(x11display : *mut X11Display, w : *mut X11Handle, protocols : *mut Atom, count : c_int);
 
+    /// The XSetWMNormalHints function replaces the size hints for the WM_NORMAL_HINTS property on the specified window.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/X11R7.7/doc/libX11/libX11/libX11.html#XSetWMNormalHints>
+    pub(crate) fn XSetWMNormalHints(x11display : *mut X11Display, w : *mut X11Handle, hints : *const XSizeHints);
+
     /// XkbGetKeyboard - Retrieves one or more components of a keyboard device description 
     /// 
     /// Reference(s)
@@ -329,6 +375,63 @@ extern {
     /// Reference(s)
     /// <https://www.x.org/releases/current/doc/libX11/libX11/libX11.html#XAutoRepeatOff>
     pub(crate) fn XAutoRepeatOff(x11display : *mut X11Display);
+
+    /// The XQueryExtension function determines if the named extension is present. If the extension is not present, 
+    /// XQueryExtension returns False; otherwise, it returns True.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/libX11/libX11/libX11.html#XQueryExtension>
+    pub(crate) fn XQueryExtension(x11display : *mut X11Display, name : *const c_char, major_opcode_return : *mut c_int, 
+        first_event_return : *mut c_int, first_error_return : *mut c_int) -> bool;
+
+    /// The XGetEventData function retrieves the data of a generic event cookie. Data must be freed with XFreeEventData.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/libX11/libX11/libX11.html#Generic_Event_Cookies>
+    pub(crate) fn XGetEventData(x11display : *mut X11Display, cookie : *mut XGenericEventCookie) -> bool;
+
+    /// The XFreeEventData function frees the data retrieved with XGetEventData.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/libX11/libX11/libX11.html#Generic_Event_Cookies>
+    pub(crate) fn XFreeEventData(x11display : *mut X11Display, cookie : *mut XGenericEventCookie);
+}
+
+// XInput2 bindings.
+#[link(name = "Xi")]
+#[allow(dead_code)]
+extern "C" {
+    /// Announce the XI2 version supported by the client and returns the version supported by the server.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/man/man3/XIQueryVersion.3.xhtml>
+    pub(crate) fn XIQueryVersion(x11display : *mut X11Display, major_version_inout : *mut c_int, minor_version_inout : *mut c_int) -> c_int;
+
+    /// Select XI2 events of one or more devices on a window.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/man/man3/XISelectEvents.3.xhtml>
+    pub(crate) fn XISelectEvents(x11display : *mut X11Display, w : *mut X11Handle, masks : *mut XIEventMask, num_masks : c_int) -> c_int;
+
+    /// Returns information about one or more input devices. Returned information must be freed with XIFreeDeviceInfo.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/man/man3/XIQueryDevice.3.xhtml>
+    pub(crate) fn XIQueryDevice(x11display : *mut X11Display, deviceid : c_int, ndevices_return : *mut c_int) -> *mut XIDeviceInfo;
+
+    /// Free the information returned by XIQueryDevice.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/man/man3/XIQueryDevice.3.xhtml>
+    pub(crate) fn XIFreeDeviceInfo(info : *mut XIDeviceInfo);
+
+    /// Get a device property. Returned data must be freed with XFree.
+    /// 
+    /// Reference(s)
+    /// <https://www.x.org/releases/current/doc/man/man3/XIChangeProperty.3.xhtml>
+    pub(crate) fn XIGetProperty(x11display : *mut X11Display, deviceid : c_int, property : Atom, offset : c_long, length : c_long, 
+        delete_property : bool, req_type : Atom, type_return : *mut Atom, format_return : *mut c_int, num_items_return : *mut c_ulong, 
+        bytes_after_return : *mut c_ulong, data : *mut *mut c_uchar) -> c_int;
 }
 
 // XFixes bindings.
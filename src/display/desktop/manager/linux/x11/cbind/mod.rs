@@ -24,4 +24,9 @@ pub mod xkb;
 #[allow(unused)]                    // Remove unused variable notification
 #[allow(non_snake_case)]            // Imported C members aren't formatted according to convention.
 #[allow(non_upper_case_globals)]    // Imported C global aren't formatted according to convention.
-pub mod xinput;
\ No newline at end of file
+pub mod xinput;
+// Contains xinput2 extension struct definition.
+#[allow(unused)]                    // Remove unused variable notification
+#[allow(non_snake_case)]            // Imported C members aren't formatted according to convention.
+#[allow(non_upper_case_globals)]    // Imported C global aren't formatted according to convention.
+pub mod xinput2;
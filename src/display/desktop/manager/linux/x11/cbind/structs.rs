@@ -29,12 +29,24 @@ pub type Cursor = c_ulong;
 
 /// Union 'data' of XClientMessageEvent struct.
 #[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct XClientMessageEvent_data {
+#[derive(Clone, Copy)]
+pub union XClientMessageEvent_data {
 pub _b : [c_char; 20],
 pub _s : [c_short; 10],
 pub _l : [c_long; 5]
 }
+
+impl std::fmt::Debug for XClientMessageEvent_data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        unsafe { f.debug_struct("XClientMessageEvent_data").field("_l", &self._l).finish() }
+    }
+}
+
+impl PartialEq for XClientMessageEvent_data {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { self._l == other._l }
+    }
+}
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct XKeyEvent {
@@ -477,9 +489,7 @@ pub struct XAnyEvent {
 	pub _type:c_int,
 	pub _serial:c_ulong,
 	pub _send_event:bool,
-	pub _Display:X11Display,
-	pub _the:X11Display,
-	pub _event:X11Display,
+	pub _display:*mut X11Display,
 	pub _window:X11Handle,
 }
 
@@ -507,6 +517,36 @@ pub struct XGenericEventCookie {
 	pub _data:*mut c_void,
 }
 
+/// Aspect ratio of XSizeHints.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct XAspect {
+	pub _x:c_int,
+	pub _y:c_int,
+}
+
+/// Size hints of a window given to the window manager.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct XSizeHints {
+	pub _flags:c_long,
+	pub _x:c_int,
+	pub _y:c_int,
+	pub _width:c_int,
+	pub _height:c_int,
+	pub _min_width:c_int,
+	pub _min_height:c_int,
+	pub _max_width:c_int,
+	pub _max_height:c_int,
+	pub _width_inc:c_int,
+	pub _height_inc:c_int,
+	pub _min_aspect:XAspect,
+	pub _max_aspect:XAspect,
+	pub _base_width:c_int,
+	pub _base_height:c_int,
+	pub _win_gravity:c_int,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub union XEvent {
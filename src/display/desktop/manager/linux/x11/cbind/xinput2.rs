@@ -0,0 +1,334 @@
+//! Structs and constants of the X Input Extension 2 (XI2).
+//!
+//! Reference(s)
+//! <https://www.x.org/releases/current/doc/inputproto/XI2proto.txt>
+
+use std::os::raw::{ c_int, c_uchar, c_ulong, c_char, c_double };
+
+use super::structs::{X11Display, X11Handle, Atom, Time};
+
+/// Name of the XInput extension used with XQueryExtension.
+pub const XInputExtensionName : &str = "XInputExtension\0";
+
+/// XI2 major version requested.
+pub const XI_2_Major : c_int = 2;
+
+/// XI2 minor version requested.
+pub const XI_2_Minor : c_int = 4;
+
+/// XI2 minor version supporting touchpad gestures.
+pub const XI_2_GestureMinor : c_int = 4;
+
+// Device ids that can be used when selecting events.
+pub const XIAllDevices : c_int = 0;
+pub const XIAllMasterDevices : c_int = 1;
+
+// Device use.
+pub const XIMasterPointer : c_int = 1;
+pub const XIMasterKeyboard : c_int = 2;
+pub const XISlavePointer : c_int = 3;
+pub const XISlaveKeyboard : c_int = 4;
+pub const XIFloatingSlave : c_int = 5;
+
+// Device classes.
+pub const XIKeyClass : c_int = 0;
+pub const XIButtonClass : c_int = 1;
+pub const XIValuatorClass : c_int = 2;
+pub const XIScrollClass : c_int = 3;
+pub const XITouchClass : c_int = 8;
+pub const XIGestureClass : c_int = 9;
+
+// Touch class modes.
+pub const XIDirectTouch : c_int = 1;
+pub const XIDependentTouch : c_int = 2;
+
+// Property event `what` values.
+pub const XIPropertyDeleted : c_int = 0;
+pub const XIPropertyCreated : c_int = 1;
+pub const XIPropertyModified : c_int = 2;
+
+// XI2 event types.
+pub const XI_DeviceChanged : c_int = 1;
+pub const XI_KeyPress : c_int = 2;
+pub const XI_KeyRelease : c_int = 3;
+pub const XI_ButtonPress : c_int = 4;
+pub const XI_ButtonRelease : c_int = 5;
+pub const XI_Motion : c_int = 6;
+pub const XI_Enter : c_int = 7;
+pub const XI_Leave : c_int = 8;
+pub const XI_FocusIn : c_int = 9;
+pub const XI_FocusOut : c_int = 10;
+pub const XI_HierarchyChanged : c_int = 11;
+pub const XI_PropertyEvent : c_int = 12;
+pub const XI_GesturePinchBegin : c_int = 27;
+pub const XI_GesturePinchUpdate : c_int = 28;
+pub const XI_GesturePinchEnd : c_int = 29;
+pub const XI_GestureSwipeBegin : c_int = 30;
+pub const XI_GestureSwipeUpdate : c_int = 31;
+pub const XI_GestureSwipeEnd : c_int = 32;
+pub const XI_LASTEVENT : c_int = 32;
+
+// Gesture event flags.
+pub const XIGesturePinchEventCancelled : c_int = 1 << 0;
+pub const XIGestureSwipeEventCancelled : c_int = 1 << 0;
+
+/// Length in bytes of a mask able to contain all XI2 events.
+pub const XI_MASK_LEN : usize = (XI_LASTEVENT as usize >> 3) + 1;
+
+/// Set the bit of an XI2 event type into an event mask.
+#[inline(always)]
+pub fn xi_set_mask(mask : &mut [c_uchar], event : c_int) {
+    mask[(event >> 3) as usize] |= 1 << (event & 7);
+}
+
+/// Returns true if the bit at index is set into an XI2 mask.
+#[inline(always)]
+pub fn xi_mask_is_set(mask : &[c_uchar], index : usize) -> bool {
+    mask[index >> 3] & (1 << (index & 7)) > 0
+}
+
+/// Mask of XI2 events to select for a device.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIEventMask {
+    pub deviceid : c_int,
+    pub mask_len : c_int,
+    pub mask : *mut c_uchar,
+}
+
+/// Common header of all device classes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIAnyClassInfo {
+    pub _type : c_int,
+    pub sourceid : c_int,
+}
+
+/// Valuator (axis) class of a device.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIValuatorClassInfo {
+    pub _type : c_int,
+    pub sourceid : c_int,
+    pub number : c_int,
+    pub label : Atom,
+    pub min : c_double,
+    pub max : c_double,
+    pub value : c_double,
+    pub resolution : c_int,
+    pub mode : c_int,
+}
+
+/// Button class of a device.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIButtonClassInfo {
+    pub _type : c_int,
+    pub sourceid : c_int,
+    pub num_buttons : c_int,
+    pub labels : *mut Atom,
+    pub state : XIButtonState,
+}
+
+/// Key class of a device.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIKeyClassInfo {
+    pub _type : c_int,
+    pub sourceid : c_int,
+    pub num_keycodes : c_int,
+    pub keycodes : *mut c_int,
+}
+
+/// Touch class of a device.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XITouchClassInfo {
+    pub _type : c_int,
+    pub sourceid : c_int,
+    pub mode : c_int,
+    pub num_touches : c_int,
+}
+
+/// Device details returned by XIQueryDevice.
+///
+/// Note : `Bool` members are declared as c_int since Xlib Bool is an int.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIDeviceInfo {
+    pub deviceid : c_int,
+    pub name : *mut c_char,
+    pub _use : c_int,
+    pub attachment : c_int,
+    pub enabled : c_int,
+    pub num_classes : c_int,
+    pub classes : *mut *mut XIAnyClassInfo,
+}
+
+/// Modifiers state of a device event.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIModifierState {
+    pub base : c_int,
+    pub latched : c_int,
+    pub locked : c_int,
+    pub effective : c_int,
+}
+
+/// Group state of a device event.
+pub type XIGroupState = XIModifierState;
+
+/// Buttons state of a device event.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIButtonState {
+    pub mask_len : c_int,
+    pub mask : *mut c_uchar,
+}
+
+/// Valuators state of a device event. Values only contains valuators set in mask.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIValuatorState {
+    pub mask_len : c_int,
+    pub mask : *mut c_uchar,
+    pub values : *mut c_double,
+}
+
+/// Device event (XI_KeyPress, XI_KeyRelease, XI_ButtonPress, XI_ButtonRelease, XI_Motion).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIDeviceEvent {
+    pub _type : c_int,
+    pub _serial : c_ulong,
+    pub _send_event : c_int,
+    pub _display : *mut X11Display,
+    pub _extension : c_int,
+    pub _evtype : c_int,
+    pub _time : Time,
+    pub _deviceid : c_int,
+    pub _sourceid : c_int,
+    pub _detail : c_int,
+    pub _root : X11Handle,
+    pub _event : X11Handle,
+    pub _child : X11Handle,
+    pub _root_x : c_double,
+    pub _root_y : c_double,
+    pub _event_x : c_double,
+    pub _event_y : c_double,
+    pub _flags : c_int,
+    pub _buttons : XIButtonState,
+    pub _valuators : XIValuatorState,
+    pub _mods : XIModifierState,
+    pub _group : XIGroupState,
+}
+
+/// Pointer crossing event (XI_Enter, XI_Leave).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIEnterEvent {
+    pub _type : c_int,
+    pub _serial : c_ulong,
+    pub _send_event : c_int,
+    pub _display : *mut X11Display,
+    pub _extension : c_int,
+    pub _evtype : c_int,
+    pub _time : Time,
+    pub _deviceid : c_int,
+    pub _sourceid : c_int,
+    pub _detail : c_int,
+    pub _root : X11Handle,
+    pub _event : X11Handle,
+    pub _child : X11Handle,
+    pub _root_x : c_double,
+    pub _root_y : c_double,
+    pub _event_x : c_double,
+    pub _event_y : c_double,
+    pub _mode : c_int,
+    pub _focus : c_int,
+    pub _same_screen : c_int,
+    pub _buttons : XIButtonState,
+    pub _mods : XIModifierState,
+    pub _group : XIGroupState,
+}
+
+/// Pointer leave event (XI_Leave).
+pub type XILeaveEvent = XIEnterEvent;
+
+/// Device property changed event (XI_PropertyEvent).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIPropertyEvent {
+    pub _type : c_int,
+    pub _serial : c_ulong,
+    pub _send_event : c_int,
+    pub _display : *mut X11Display,
+    pub _extension : c_int,
+    pub _evtype : c_int,
+    pub _time : Time,
+    pub _deviceid : c_int,
+    pub _property : Atom,
+    pub _what : c_int,
+}
+
+/// Touchpad pinch gesture event (XI_GesturePinchBegin, XI_GesturePinchUpdate, XI_GesturePinchEnd). Since XI 2.4.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIGesturePinchEvent {
+    pub _type : c_int,
+    pub _serial : c_ulong,
+    pub _send_event : c_int,
+    pub _display : *mut X11Display,
+    pub _extension : c_int,
+    pub _evtype : c_int,
+    pub _time : Time,
+    pub _deviceid : c_int,
+    pub _sourceid : c_int,
+    pub _detail : c_int,
+    pub _root : X11Handle,
+    pub _event : X11Handle,
+    pub _child : X11Handle,
+    pub _root_x : c_double,
+    pub _root_y : c_double,
+    pub _event_x : c_double,
+    pub _event_y : c_double,
+    pub _delta_x : c_double,
+    pub _delta_y : c_double,
+    pub _delta_unaccel_x : c_double,
+    pub _delta_unaccel_y : c_double,
+    pub _scale : c_double,
+    pub _delta_angle : c_double,
+    pub _flags : c_int,
+    pub _mods : XIModifierState,
+    pub _group : XIGroupState,
+}
+
+/// Touchpad swipe gesture event (XI_GestureSwipeBegin, XI_GestureSwipeUpdate, XI_GestureSwipeEnd). Since XI 2.4.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XIGestureSwipeEvent {
+    pub _type : c_int,
+    pub _serial : c_ulong,
+    pub _send_event : c_int,
+    pub _display : *mut X11Display,
+    pub _extension : c_int,
+    pub _evtype : c_int,
+    pub _time : Time,
+    pub _deviceid : c_int,
+    pub _sourceid : c_int,
+    pub _detail : c_int,
+    pub _root : X11Handle,
+    pub _event : X11Handle,
+    pub _child : X11Handle,
+    pub _root_x : c_double,
+    pub _root_y : c_double,
+    pub _event_x : c_double,
+    pub _event_y : c_double,
+    pub _delta_x : c_double,
+    pub _delta_y : c_double,
+    pub _delta_unaccel_x : c_double,
+    pub _delta_unaccel_y : c_double,
+    pub _flags : c_int,
+    pub _mods : XIModifierState,
+    pub _group : XIGroupState,
+}
@@ -1,12 +1,27 @@
 //! Contains inline event functions.
 
-use std::{ffi::{c_int, c_ulong, c_char, c_void}, ptr::null_mut};
+use std::{ffi::{c_int, c_uint, c_ulong, c_char, c_void}, ptr::null_mut, time::{Duration, Instant}};
 
-use crate::display::desktop::{event::{Event, keyboard::{EventKeyboard, Key}, pointer::{EventPointer, PointerButton}, window::EventWindow}, manager::WindowManager, property::{PointerMode, WindowEventWaitMode, KeyboardMode}};
+use crate::display::desktop::{event::{Event, EventCategoryMask, EventSource, keyboard::{EventKeyboard, Key}, pointer::{EventPointer, PointerButton}, window::EventWindow}, manager::WindowManager, property::{PointerMode, WindowEventWaitMode, KeyboardMode}};
 
-use super::{ cbind::{structs::{XEvent, Atom}, constants::VisibilityUnobscured, functs::{XGetWindowProperty, XFree, XNextEvent, XEventsQueued, XSync, Xutf8LookupString, XFilterEvent}, xinput::{XBufferOverflow, XLookupChars}}, X11WindowManager};
+use super::{ cbind::{structs::{XEvent, Atom, Time}, constants::VisibilityUnobscured, functs::{XGetWindowProperty, XFree, XNextEvent, XPeekEvent, XEventsQueued, XSync, Xutf8LookupString, XFilterEvent, XPending}, xinput::{XBufferOverflow, XLookupChars}}, X11WindowManager};
 use super::cbind::{constants::* };
-
+use super::super::cbind::{PollFd, poll};
+use super::super::get_poll_timeout;
+
+
+/// States of window read from _NET_WM_STATE.
+#[derive(Default)]
+struct NetWmState {
+    hidden : bool,
+    maximized : bool,
+    fullscreen : bool,
+    above : bool,
+    below : bool,
+    sticky : bool,
+    skip_taskbar : bool,
+    skip_pager : bool,
+}
 
 /// Constant value of the window closing message.
 pub const WINDOW_CLOSING_MESSAGE_TYPE:u64 = 327;
@@ -62,23 +77,70 @@ impl<'window> X11WindowManager<'window> {
     pub(super) fn fetch_event(&mut self) -> Event {
         unsafe {
 
-            if self.retained_events.borrow().len() > 0 { // Always pop event from retained first.
-               self.retained_events.borrow_mut().pop().unwrap()
+            if let Some(event) = self.retained_events.get_mut().pop_front() { // Always pop event from retained first.
+               event
+            } else if let Some(value) = self.user_events.pop() {
+                Event::User(value)
+            } else if let Some(id) = self.timers.poll_timer() {
+                Event::Timer(id)
+            } else if let Some((token, readiness)) = self.fd_sources.pop_ready() {
+                Event::FdReady(token, readiness)
+            } else if self.shared {     // X11 events routed by the application.
+                match self.pending_events.pop_front() {
+                    Some(xevent) => {
+                        let xevent = self.coalesce_event(xevent);
+                        self.get_matched_event(&xevent)
+                    },
+                    Option::None => self.get_redraw_event(),
+                }
+            } else if let Some(event) = self.gamepads.get_mut().and_then(|gamepads| gamepads.poll_event()) {
+                Event::Gamepad(event)
             } else {
                 match self.property.wait_mode{
                     WindowEventWaitMode::NeverWait => {
                         if self.event_count > 0 {    // If event count > 0, preventing window lock
                             self.event_count -= 1;  // Decrease event count
                             XNextEvent(self.display, &mut self.x_event);
-                            let xevent = self.x_event; 
+                            let xevent = self.coalesce_event(self.x_event);
                             self.get_matched_event(&xevent)
                         } else {
-                           Event::None
+                           self.get_redraw_event()
                         }
                     },
-                    WindowEventWaitMode::AlwaysWait => {
-                        XNextEvent(self.display, &mut self.x_event);    // Will lock window waiting for events
-                        let xevent = self.x_event; 
+                    WindowEventWaitMode::AlwaysWait | WindowEventWaitMode::WaitTimeout(_) | WindowEventWaitMode::WaitUntil(_) => {
+                        if self.redraw_requested && XPending(self.display) == 0 {  // Redraw before waiting
+                            return self.get_redraw_event();
+                        }
+
+                        // Deadline is computed once per poll_event, swallowed events fetching the next one must not extend it.
+                        let deadline = self.wait_deadline;
+
+                        // Will lock window waiting for X11, gamepad or user events, the next timer or the deadline
+                        while XPending(self.display) == 0 {
+                            let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+                            if remaining == Some(Duration::ZERO) {
+                                return Event::None;
+                            }
+
+                            let timeout = [remaining, self.timers.get_timeout()].into_iter().flatten().min();
+                            self.wait_fds(get_poll_timeout(timeout));
+                            if let Some(event) = self.gamepads.get_mut().and_then(|gamepads| gamepads.poll_event()) {
+                                return Event::Gamepad(event);
+                            }
+                            if let Some(value) = self.user_events.pop() {
+                                return Event::User(value);
+                            }
+                            if let Some(id) = self.timers.poll_timer() {
+                                return Event::Timer(id);
+                            }
+                            self.fd_sources.update();
+                            if let Some((token, readiness)) = self.fd_sources.pop_ready() {
+                                return Event::FdReady(token, readiness);
+                            }
+                        }
+
+                        XNextEvent(self.display, &mut self.x_event);
+                        let xevent = self.coalesce_event(self.x_event);
                         self.get_matched_event(&xevent)
                     },
                 }
@@ -86,6 +148,120 @@ impl<'window> X11WindowManager<'window> {
         }
     }
 
+    /// Get the redraw event if requested, clearing the request.
+    /// 
+    /// Returns [EventWindow::RedrawRequested] or [Event::None] if no redraw requested.
+    #[inline(always)]
+    fn get_redraw_event(&mut self) -> Event {
+        if std::mem::take(&mut self.redraw_requested) {
+            Event::Window(EventWindow::RedrawRequested)
+        } else {
+            Event::None
+        }
+    }
+
+    /// Wait until the X11 connection, a gamepad, a user event or an external file descriptor is ready, or until timeout
+    /// in milliseconds expires. A negative timeout wait forever.
+    #[inline(always)]
+    pub(crate) fn wait_fds(&self, timeout : c_int) {
+        unsafe {
+            let mut fds : Vec<PollFd> = self.get_event_fds().into_iter().map(PollFd::new).collect();
+            fds.extend(self.fd_sources.get_poll_fds());
+
+            poll(fds.as_mut_ptr(), fds.len() as c_ulong, timeout);
+        }
+    }
+
+    /// Merge the next queued X11 events of same type and window into xevent if coalescing is enabled for its category.
+    /// 
+    /// Motion keeps the last position (only in [PointerMode::Cursor]), configure keeps the last geometry and expose keeps
+    /// the bounding box of exposed areas. XI2 motions between core motions are consumed to tag the merged motion with
+    /// the last device. A XI2 motion giving an event, such as a pen motion, is retained and stops the merge.
+    #[inline(always)]
+    #[allow(non_upper_case_globals)]
+    fn coalesce_event(&mut self, mut xevent : XEvent) -> XEvent {
+        unsafe {
+            let category = match xevent._type {
+                MotionNotify if self.property.pointer.mode == PointerMode::Cursor => EventCategoryMask::POINTER_MOTION,
+                ConfigureNotify => EventCategoryMask::CONFIGURE,
+                Expose => EventCategoryMask::EXPOSE,
+                _ => return xevent,
+            };
+
+            if !self.property.coalescing.contains(category) {
+                return xevent;
+            }
+
+            while let Some(next) = self.peek_x_event() {
+                if xevent._type == MotionNotify && self.is_xinput2_motion(&next) {
+                    let next = self.pop_x_event();
+                    match self.decode_xinput2_event(&next) {
+                        Event::None => continue,
+                        event => {
+                            self.push_event(event);
+                            break;
+                        },
+                    }
+                }
+
+                if next._type != xevent._type || next._xany._window != xevent._xany._window {
+                    break;
+                }
+
+                self.pop_x_event();
+                if xevent._type == Expose {
+                    let (x, y) = (xevent._xexpose._x.min(next._xexpose._x), xevent._xexpose._y.min(next._xexpose._y));
+                    let right = (xevent._xexpose._x + xevent._xexpose._width).max(next._xexpose._x + next._xexpose._width);
+                    let bottom = (xevent._xexpose._y + xevent._xexpose._height).max(next._xexpose._y + next._xexpose._height);
+
+                    xevent._xexpose._x = x;
+                    xevent._xexpose._y = y;
+                    xevent._xexpose._width = right - x;
+                    xevent._xexpose._height = bottom - y;
+                    xevent._xexpose._count = next._xexpose._count;
+                } else {
+                    xevent = next;
+                }
+            }
+
+            xevent
+        }
+    }
+
+    /// Get the next X11 event of window without removing it from queue.
+    /// 
+    /// Returns Some([XEvent]) or None if no event is queued.
+    #[inline(always)]
+    fn peek_x_event(&self) -> Option<XEvent> {
+        unsafe {
+            if self.shared {
+                self.pending_events.front().copied()
+            } else if XEventsQueued(self.display, QueuedAlready) > 0 {
+                let mut xevent = XEvent{ _type:0 };
+                XPeekEvent(self.display, &mut xevent);
+                Some(xevent)
+            } else {
+                Option::None
+            }
+        }
+    }
+
+    /// Remove the next X11 event of window from queue.
+    /// 
+    /// Returns the [XEvent] removed.
+    #[inline(always)]
+    fn pop_x_event(&mut self) -> XEvent {
+        unsafe {
+            if self.shared {
+                self.pending_events.pop_front().unwrap_or(XEvent{ _type:0 })
+            } else {
+                XNextEvent(self.display, &mut self.x_event);
+                self.event_count = self.event_count.saturating_sub(1);
+                self.x_event
+            }
+        }
+    }
+
     /// Get matched event from X11 Event.
     #[inline(always)]
     #[allow(non_upper_case_globals)]
@@ -151,7 +327,7 @@ impl<'window> X11WindowManager<'window> {
     /// Get Event created from KeyPress
     #[inline(always)]
     #[allow(non_upper_case_globals)]
-    pub(super) fn get_key_press_event(&self, xevent : &XEvent) -> Event {
+    pub(super) fn get_key_press_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {           
             
             let key : Key =  Key::new(xevent._xkey._state, xevent._xkey._keycode, 
@@ -177,15 +353,15 @@ impl<'window> X11WindowManager<'window> {
                     Option::None    // No char associated
                 });
 
-            Event::Keyboard(EventKeyboard::KeyPress(key))
+            Event::Keyboard(EventKeyboard::KeyPress(key), self.get_keyboard_event_source(xevent._xkey._time))
         }
     }
 
     /// Get Event created from keydown
     #[inline(always)]
-    pub(super) fn get_key_down_event(&self, xevent : &XEvent) -> Event {
+    pub(super) fn get_key_down_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {
-            Event::Keyboard(EventKeyboard::KeyDown(xevent._xkey._keycode))            
+            Event::Keyboard(EventKeyboard::KeyDown(xevent._xkey._keycode), self.get_keyboard_event_source(xevent._xkey._time))
         }
     }
 
@@ -198,27 +374,18 @@ impl<'window> X11WindowManager<'window> {
     #[inline(always)]
     pub fn get_key_up_event(&mut self, xevent : &XEvent) -> Event{
         unsafe {
-            Event::Keyboard(EventKeyboard::KeyUp(xevent._xkey._keycode))
+            Event::Keyboard(EventKeyboard::KeyUp(xevent._xkey._keycode), self.get_keyboard_event_source(xevent._xkey._time))
         }
     }
 
     /// Get Event created from ButtonPress
     /// Mouse button press.
     #[inline(always)]
-    pub(super) fn get_button_press_event(&self, xevent : &XEvent) -> Event {
+    pub(super) fn get_button_press_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {
-            Event::Pointer(EventPointer::ButtonDown( match xevent._xbutton._button {
-                POINTER_LEFT_BUTTON => PointerButton::LeftButton,
-                POINTER_MIDDLE_BUTTON => PointerButton::MiddleButton,
-                POINTER_RIGHT_BUTTON => PointerButton::RightButton,
-                POINTER_PREVIOUS_BUTTON => PointerButton::PreviousButton,
-                POINTER_NEXT_BUTTON => PointerButton::NextButton,
-                POINTER_SCROLL_UP => PointerButton::ScrollUp,
-                POINTER_SCROLL_DOWN => PointerButton::ScrollDown,
-                POINTER_SCROLL_LEFT => PointerButton::ScrollLeft,
-                POINTER_SCROLL_RIGHT => PointerButton::ScrollRight,
-                _ => PointerButton::Other(xevent._xbutton._button.try_into().unwrap()),
-            }, (xevent._xbutton._x, xevent._xbutton._y)))
+            let source = self.get_pointer_event_source(xevent._xbutton._time);
+            Event::Pointer(EventPointer::ButtonDown(Self::get_pointer_button(xevent._xbutton._button), 
+                (xevent._xbutton._x, xevent._xbutton._y)), source)
         }
     }
 
@@ -227,18 +394,26 @@ impl<'window> X11WindowManager<'window> {
     #[inline(always)]
     pub(super) fn get_button_release_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {
-            Event::Pointer(EventPointer::ButtonUp( match xevent._xbutton._button {
-                POINTER_LEFT_BUTTON => PointerButton::LeftButton,
-                POINTER_MIDDLE_BUTTON => PointerButton::MiddleButton,
-                POINTER_RIGHT_BUTTON => PointerButton::RightButton,
-                POINTER_PREVIOUS_BUTTON => PointerButton::PreviousButton,
-                POINTER_NEXT_BUTTON => PointerButton::NextButton,
-                POINTER_SCROLL_UP => PointerButton::ScrollUp,
-                POINTER_SCROLL_DOWN => PointerButton::ScrollDown,
-                POINTER_SCROLL_LEFT => PointerButton::ScrollLeft,
-                POINTER_SCROLL_RIGHT => PointerButton::ScrollRight,
-                _ => PointerButton::Other(xevent._xbutton._button.try_into().unwrap()),
-            }, (xevent._xbutton._x, xevent._xbutton._y)))
+            let source = self.get_pointer_event_source(xevent._xbutton._time);
+            Event::Pointer(EventPointer::ButtonUp(Self::get_pointer_button(xevent._xbutton._button), 
+                (xevent._xbutton._x, xevent._xbutton._y)), source)
+        }
+    }
+
+    /// Get [PointerButton] from X11 button number.
+    #[inline(always)]
+    pub(super) fn get_pointer_button(button : c_uint) -> PointerButton {
+        match button {
+            POINTER_LEFT_BUTTON => PointerButton::LeftButton,
+            POINTER_MIDDLE_BUTTON => PointerButton::MiddleButton,
+            POINTER_RIGHT_BUTTON => PointerButton::RightButton,
+            POINTER_PREVIOUS_BUTTON => PointerButton::PreviousButton,
+            POINTER_NEXT_BUTTON => PointerButton::NextButton,
+            POINTER_SCROLL_UP => PointerButton::ScrollUp,
+            POINTER_SCROLL_DOWN => PointerButton::ScrollDown,
+            POINTER_SCROLL_LEFT => PointerButton::ScrollLeft,
+            POINTER_SCROLL_RIGHT => PointerButton::ScrollRight,
+            _ => PointerButton::Other(button.try_into().unwrap()),
         }
     }
 
@@ -247,27 +422,71 @@ impl<'window> X11WindowManager<'window> {
     #[inline(always)]
     pub(super) fn get_motion_notify_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {
-            match self.property.pointer.mode{
-                PointerMode::Cursor => Event::Pointer(EventPointer::Moved((xevent._xmotion._x, xevent._xmotion._y))),
-                PointerMode::Acceleration => {
-                    // Calc delta acceleration
-                    let acceleration = (xevent._xmotion._x - self.property.center.0, 
-                        xevent._xmotion._y - self.property.center.1);
-
-                    if acceleration.0 != 0 && acceleration.1 != 0 { // Send acceleration only if it moved.
-                        // Reset pointer to center
-                        self.set_pointer_position(self.property.center);
-
-                        // Send acceleration event.
-                        Event::Pointer(EventPointer::Acceleration(acceleration))
-                    } else {
-                        self.fetch_event()   // Ignore and poll next event
-                    }
-                },     
-            }
+            let source = self.get_pointer_event_source(xevent._xmotion._time);
+            self.get_pointer_moved_event((xevent._xmotion._x, xevent._xmotion._y), source)
+        }
+    }
+
+    /// Get pointer moved or acceleration event according to pointer mode.
+    #[inline(always)]
+    pub(super) fn get_pointer_moved_event(&mut self, position : (i32, i32), source : EventSource) -> Event {
+        match self.property.pointer.mode{
+            PointerMode::Cursor => Event::Pointer(EventPointer::Moved(position), source),
+            PointerMode::Acceleration => {
+                // Calc delta acceleration
+                let acceleration = (position.0 - self.property.center.0, 
+                    position.1 - self.property.center.1);
+
+                if acceleration.0 != 0 && acceleration.1 != 0 { // Send acceleration only if it moved.
+                    // Reset pointer to center
+                    self.set_pointer_position(self.property.center);
+
+                    // Send acceleration event.
+                    Event::Pointer(EventPointer::Acceleration(acceleration), source)
+                } else {
+                    self.fetch_event()   // Ignore and poll next event
+                }
+            },     
         }
     }
 
+    /// Get the [EventSource] of a keyboard event from X11 time.
+    #[inline(always)]
+    pub(super) fn get_keyboard_event_source(&mut self, time : Time) -> EventSource {
+        let device = match &self.xinput2 {
+            Some(xi2) => xi2.keyboard,
+            Option::None => 0,
+        };
+
+        EventSource::new(device, self.get_timestamp(time))
+    }
+
+    /// Get the [EventSource] of a core pointer event from X11 time.
+    #[inline(always)]
+    pub(super) fn get_pointer_event_source(&mut self, time : Time) -> EventSource {
+        let device = match &self.xinput2 {
+            Some(xi2) => xi2.pointer,
+            Option::None => 0,
+        };
+
+        EventSource::new(device, self.get_timestamp(time))
+    }
+
+    /// Get a monotonic timestamp in milliseconds from X11 time.
+    /// 
+    /// X11 time is a 32 bits value that wrap around after about 49.7 days.
+    #[inline(always)]
+    pub(super) fn get_timestamp(&mut self, time : Time) -> u64 {
+        let time = time & (u32::MAX as Time);
+
+        if time < self.last_time && self.last_time - time > (u32::MAX as Time) / 2 {
+            self.time_base += u32::MAX as u64 + 1;    // X11 time wrapped around.
+        }
+        self.last_time = time;
+
+        self.time_base + time
+    }
+
     /// Get Event created from EnterNotify.
     /// Pointer entered window
     #[inline(always)]
@@ -333,9 +552,19 @@ impl<'window> X11WindowManager<'window> {
     /// Get Event created from Expose
     /// Part of window need to be redrawed 
     #[inline(always)]
-    pub(super) fn get_expose_event(&self, xevent : &XEvent) -> Event {
+    pub(super) fn get_expose_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {
-            Event::Window(EventWindow::Exposed((xevent._xexpose._x, xevent._xexpose._y), (xevent._xexpose._width as u32, xevent._xexpose._height as u32)))
+            self.damage.add(((xevent._xexpose._x, xevent._xexpose._y), (xevent._xexpose._width as u32, xevent._xexpose._height as u32)));
+
+            if xevent._xexpose._count > 0 {    // More Expose follow in series
+                return self.fetch_event();
+            }
+
+            self.exposed = std::mem::take(&mut self.damage);
+            match self.exposed.get_bounds() {
+                Some((position, size)) => Event::Window(EventWindow::Exposed(position, size)),
+                Option::None => self.fetch_event(),
+            }
         }
     }
 
@@ -443,10 +672,10 @@ impl<'window> X11WindowManager<'window> {
     /// Get Event created from ConfigureNotify
     /// Window position and/or size changed
     #[inline(always)]
-    pub(super) fn get_configure_notify_event(&mut self, _xevent : &XEvent) -> Event {
+    pub(super) fn get_configure_notify_event(&mut self, xevent : &XEvent) -> Event {
         unsafe {
-            let position = (self.x_event._xconfigure._x, self.x_event._xconfigure._y);
-            let size = (self.x_event._xconfigure._width as u32, self.x_event._xconfigure._height as u32);
+            let position = (xevent._xconfigure._x, xevent._xconfigure._y);
+            let size = (xevent._xconfigure._width as u32, xevent._xconfigure._height as u32);
         
             // By default, set event as none.
             let mut event = Event::None;
@@ -462,6 +691,11 @@ impl<'window> X11WindowManager<'window> {
             self.property.position = position;
             self.property.size = size;
 
+            if self.property.is_normal() {  // Normal geometry is kept while maximized or fullscreen.
+                self.property.normal_position = position;
+                self.property.normal_size = size;
+            }
+
             event
         }
     }
@@ -516,9 +750,7 @@ impl<'window> X11WindowManager<'window> {
     pub(super) fn get_property_notify_event(&mut self, _xevent : &XEvent) -> Event {
         unsafe {
             // State values returned
-            let mut hidden = false;
-            let mut maximized = false;
-            let mut fullscreen = false;
+            let mut state = NetWmState::default();
 
             // Used to capture XGetWindowProperty
             let mut actual_type_return : Atom = 0;
@@ -538,77 +770,21 @@ impl<'window> X11WindowManager<'window> {
                     // 8 bits
                     8 => {
                         // Convert properties to u8
-                        let states: &mut [u8] = core::slice::from_raw_parts_mut(prop_return as *mut u8, nitems_return as usize);
-                        for state in states{
-                            match *state as Atom {
-                                state if self.atoms._NET_WM_STATE_HIDDEN == state => {
-                                    hidden = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_FULLSCREEN == state => {
-                                    fullscreen = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_MAXIMIZED_HORZ == state => {
-                                    maximized = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_MAXIMIZED_VERT == state => {
-                                    maximized = true;
-                                },
-                                0 => {},   // Do nothing with 0 atoms
-                                _ => {},
-                            }
-                        }
-                        
+                        let atoms: &[u8] = core::slice::from_raw_parts(prop_return as *const u8, nitems_return as usize);
+                        atoms.iter().for_each(|atom| self.apply_net_wm_state(*atom as Atom, &mut state));
                     },
                     // 16 bits
                     16 => {
                         // Convert properties to u16
-                        let states: &mut [u16] = core::slice::from_raw_parts_mut(prop_return as *mut u16, nitems_return as usize);
-                        for state in states{
-                            match *state as Atom {
-                                state if self.atoms._NET_WM_STATE_HIDDEN == state => {
-                                    hidden = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_FULLSCREEN == state => {
-                                    fullscreen = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_MAXIMIZED_HORZ == state => {
-                                    maximized = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_MAXIMIZED_VERT == state => {
-                                    maximized = true;
-                                },
-                                0 => {},   // Do nothing with 0 atoms
-                                _ => {},
-                            }
-                        }
+                        let atoms: &[u16] = core::slice::from_raw_parts(prop_return as *const u16, nitems_return as usize);
+                        atoms.iter().for_each(|atom| self.apply_net_wm_state(*atom as Atom, &mut state));
                     },
 
                     // 32 bits
                     32 => {
                         // Convert properties to Atom
-                        let states: &mut [Atom] = core::slice::from_raw_parts_mut(prop_return as *mut Atom, nitems_return as usize);
-                        
-                        for state in states{
-                            match *state as Atom {
-                                state if self.atoms._NET_WM_STATE_HIDDEN == state => {
-                                    hidden = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_FULLSCREEN == state => {
-                                    fullscreen = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_MAXIMIZED_HORZ == state => {
-                                    maximized = true;
-                                },
-                                state if self.atoms._NET_WM_STATE_MAXIMIZED_VERT == state => {
-                                    maximized = true;
-                                },
-                                0 => {},   // Do nothing with 0 atoms
-                                // Ignore unknown state name
-                                _state => { 
-                                    //println!("State={:?}", CStr::from_ptr(XGetAtomName(self.display, state)).to_str().unwrap());
-                                }
-                            }
-                        }
+                        let atoms: &[Atom] = core::slice::from_raw_parts(prop_return as *const Atom, nitems_return as usize);
+                        atoms.iter().for_each(|atom| self.apply_net_wm_state(*atom, &mut state));
                     },
 
                     // Anything else is an error
@@ -623,35 +799,67 @@ impl<'window> X11WindowManager<'window> {
             let mut event = Event::None;
 
             // Return event. By priority > Fullscreen > Minimized > Maximized > Restored > None
-            if fullscreen {   // Send fullscreen if not already registered.
+            if state.fullscreen {   // Send fullscreen if not already registered.
                 if !self.fullscreen {
                     event = Event::Window(EventWindow::Fullscreen);
                 }
-            } else if hidden {   // Send minimized if not already registered.
+            } else if state.hidden {   // Send minimized if not already registered.
                     if !self.property.minimized {
                         event = Event::Window(EventWindow::Minimized);
                     }
-            } else if maximized {   // Send maximized if not already registered.
+            } else if state.maximized {   // Send maximized if not already registered.
                 if !self.property.maximized {
                     event = Event::Window(EventWindow::Maximized);
                 }
             } else {    // Send restore if not already registered.
-                if self.fullscreen != fullscreen || 
-                    self.property.maximized != maximized || 
-                    self.property.minimized != hidden {
+                if self.fullscreen != state.fullscreen || 
+                    self.property.maximized != state.maximized || 
+                    self.property.minimized != state.hidden {
                         event = Event::Window(EventWindow::Restored);
                     }
             }
 
+            // Retain state changed if stacking or taskbar states changed.
+            if (self.property.above, self.property.below, self.property.sticky, self.property.skip_taskbar, self.property.skip_pager) != 
+                (state.above, state.below, state.sticky, state.skip_taskbar, state.skip_pager) {
+                self.push_event(Event::Window(EventWindow::StateChanged));
+            }
+
             // Update window properties
-            self.fullscreen = fullscreen;
-            self.property.maximized = maximized;
-            self.property.minimized = hidden;
+            self.fullscreen = state.fullscreen;
+            self.property.maximized = state.maximized;
+            self.property.minimized = state.hidden;
+            self.property.above = state.above;
+            self.property.below = state.below;
+            self.property.sticky = state.sticky;
+            self.property.skip_taskbar = state.skip_taskbar;
+            self.property.skip_pager = state.skip_pager;
 
             event
         }
     }
 
+    /// Set the state of a _NET_WM_STATE atom. Unknown atoms are ignored.
+    #[inline(always)]
+    fn apply_net_wm_state(&self, atom : Atom, state : &mut NetWmState) {
+        match atom {
+            0 => {},   // Do nothing with 0 atoms
+            atom if self.atoms._NET_WM_STATE_HIDDEN == atom => state.hidden = true,
+            atom if self.atoms._NET_WM_STATE_FULLSCREEN == atom => state.fullscreen = true,
+            atom if self.atoms._NET_WM_STATE_MAXIMIZED_HORZ == atom => state.maximized = true,
+            atom if self.atoms._NET_WM_STATE_MAXIMIZED_VERT == atom => state.maximized = true,
+            atom if self.atoms._NET_WM_STATE_ABOVE == atom => state.above = true,
+            atom if self.atoms._NET_WM_STATE_BELOW == atom => state.below = true,
+            atom if self.atoms._NET_WM_STATE_STICKY == atom => state.sticky = true,
+            atom if self.atoms._NET_WM_STATE_SKIP_TASKBAR == atom => state.skip_taskbar = true,
+            atom if self.atoms._NET_WM_STATE_SKIP_PAGER == atom => state.skip_pager = true,
+            // Ignore unknown state name
+            _atom => {
+                //println!("State={:?}", CStr::from_ptr(XGetAtomName(self.display, atom)).to_str().unwrap());
+            },
+        }
+    }
+
     /// Get Event created from SelectionClear
     /// Unknown use for now.
     #[inline(always)]
@@ -724,14 +932,10 @@ impl<'window> X11WindowManager<'window> {
     }
 
     /// Get Event created from GenericEvent
-    /// Unknown use for now.
+    /// Extensions events such as XInput2.
     #[inline(always)]
     pub(super) fn get_generic_event(&mut self, xevent : &XEvent) -> Event {
-        unsafe {
-            #[cfg(debug_assertions)]
-            println!("Display({:p}), GenericEvent({})", self, xevent._type); 
-            self.fetch_event()
-        }
+        self.get_xinput2_event(xevent)
     }
 
     /// Get Event created from unknown
@@ -0,0 +1,697 @@
+//! Contains XInput2 devices and events handling.
+
+use std::ffi::{c_int, c_ulong, c_uchar, c_void, CStr, CString};
+use std::ptr::null_mut;
+
+use crate::display::DisplayError;
+use crate::display::desktop::device::{InputDeviceList, InputDevice, InputDeviceType};
+use crate::display::desktop::event::{Event, EventCategoryMask};
+use crate::display::desktop::event::pen::{EventPen, PenState, PenTool};
+use crate::display::desktop::gesture::{TouchpadGestureDecoder, TouchpadGestureInput, TouchpadGesturePhase};
+use crate::display::desktop::manager::WindowManager;
+use crate::error::StudioError;
+
+use super::X11WindowManager;
+use super::cbind::structs::{XEvent, Atom, X11Display, XGenericEventCookie};
+use super::cbind::constants::GenericEvent;
+use super::cbind::functs::{XQueryExtension, XIQueryVersion, XIQueryDevice, XIFreeDeviceInfo, XISelectEvents, XIGetProperty,
+    XGetEventData, XFreeEventData, XGetAtomName, XInternAtom, XFree, XDefaultRootWindow, XOpenDisplay, XCloseDisplay};
+use super::cbind::xinput2::*;
+
+/// Valuator label of pen pressure.
+const LABEL_PRESSURE : &str = "Abs Pressure";
+
+/// Valuator label of pen horizontal tilt.
+const LABEL_TILT_X : &str = "Abs Tilt X";
+
+/// Valuator label of pen vertical tilt.
+const LABEL_TILT_Y : &str = "Abs Tilt Y";
+
+/// Valuator labels of pen rotation. Wacom driver use `Abs Wheel` while libinput driver use `Abs Z`.
+const LABEL_ROTATION : [&str; 2] = ["Abs Wheel", "Abs Z"];
+
+/// Wacom driver property that contains the serial of the tool in proximity (0 when out of proximity).
+const WACOM_SERIAL_IDS : &str = "Wacom Serial IDs";
+
+/// Wacom driver property that contains the atom of the tool type of a device.
+const WACOM_TOOL_TYPE : &str = "Wacom Tool Type";
+
+/// Tool type of the eraser end of a stylus in `Wacom Tool Type` property.
+const WACOM_TOOL_ERASER : &str = "ERASER";
+
+/// Index of the tool serial in proximity into `Wacom Serial IDs` property.
+const WACOM_SERIAL_IN_PROXIMITY : usize = 3;
+
+/// Tip button number of a pen.
+const PEN_TIP_BUTTON : c_int = 1;
+
+/// XInput2 extension details of a display connection.
+pub(crate) struct X11XInput2 {
+    /// Major opcode of the extension, used to identify generic events.
+    pub opcode : c_int,
+
+    /// Pen devices detected.
+    pub pens : Vec<X11PenDevice>,
+
+    /// Slave pointer devices ids.
+    pub pointers : Vec<c_int>,
+
+    /// Slave keyboard devices ids.
+    pub keyboards : Vec<c_int>,
+
+    /// Id of the last slave pointer that generated an event. 0 if unknown.
+    pub pointer : u16,
+
+    /// Id of the last slave keyboard that generated an event. 0 if unknown.
+    pub keyboard : u16,
+
+    /// Touchpad gestures are supported (XI 2.4).
+    pub gestures : bool,
+
+    /// Decoder of touchpad pinch and swipe gestures.
+    touchpad : TouchpadGestureDecoder,
+
+    /// Atom of the `Wacom Serial IDs` property. 0 if driver doesn't provide it.
+    serial_ids : Atom,
+}
+
+/// Range of a device valuator (axis).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct X11Valuator {
+    /// Valuator number.
+    number : usize,
+
+    /// Minimum value of valuator.
+    min : f64,
+
+    /// Maximum value of valuator.
+    max : f64,
+}
+
+impl X11Valuator {
+    /// Returns value normalized from 0.0 to 1.0.
+    #[inline(always)]
+    fn normalize(&self, value : f64) -> f64 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Pen device detected from XInput2 valuators.
+pub(crate) struct X11PenDevice {
+    /// Device id.
+    pub id : c_int,
+
+    /// Pressure valuator.
+    pressure : Option<X11Valuator>,
+
+    /// Horizontal tilt valuator.
+    tilt_x : Option<X11Valuator>,
+
+    /// Vertical tilt valuator.
+    tilt_y : Option<X11Valuator>,
+
+    /// Rotation valuator.
+    rotation : Option<X11Valuator>,
+
+    /// Pen is in proximity of the tablet.
+    proximity : bool,
+
+    /// Pen is handled by the Wacom driver which reports proximity changes.
+    wacom : bool,
+
+    /// Last known state of the pen.
+    pub state : PenState,
+}
+
+impl X11PenDevice {
+    /// Update pen state from XI2 event valuators.
+    #[inline(always)]
+    fn update_valuators(&mut self, valuators : &XIValuatorState) {
+        unsafe {
+            let mask = core::slice::from_raw_parts(valuators.mask, valuators.mask_len as usize);
+
+            // Values only contains valuators set in mask.
+            let mut value_index = 0;
+            for number in 0..(valuators.mask_len as usize * 8) {
+                if xi_mask_is_set(mask, number) {
+                    let value = *valuators.values.add(value_index);
+                    value_index += 1;
+
+                    match number {
+                        n if Self::is_valuator(&self.pressure, n) => self.state.pressure = self.pressure.unwrap().normalize(value),
+                        n if Self::is_valuator(&self.tilt_x, n) => self.state.tilt.0 = self.tilt_x.unwrap().normalize(value) * 2.0 - 1.0,
+                        n if Self::is_valuator(&self.tilt_y, n) => self.state.tilt.1 = self.tilt_y.unwrap().normalize(value) * 2.0 - 1.0,
+                        n if Self::is_valuator(&self.rotation, n) => self.state.rotation = self.rotation.unwrap().normalize(value) * 360.0,
+                        _ => {},
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns true if valuator number is the number of the option valuator.
+    #[inline(always)]
+    fn is_valuator(valuator : &Option<X11Valuator>, number : usize) -> bool {
+        match valuator {
+            Some(valuator) => valuator.number == number,
+            None => false,
+        }
+    }
+}
+
+impl X11XInput2 {
+    /// Query XInput2 extension of display.
+    ///
+    /// Returns Some([X11XInput2]) if XI2 is supported by server, None otherwise.
+    pub(crate) fn new(display : *mut X11Display) -> Option<X11XInput2> {
+        unsafe {
+            let mut opcode : c_int = 0;
+            let mut event : c_int = 0;
+            let mut error : c_int = 0;
+
+            if !XQueryExtension(display, XInputExtensionName.as_ptr() as *const i8, &mut opcode, &mut event, &mut error) {
+                return None;    // XInput extension not available.
+            }
+
+            let mut major = XI_2_Major;
+            let mut minor = XI_2_Minor;
+            if XIQueryVersion(display, &mut major, &mut minor) != 0 || major < XI_2_Major {
+                return None;    // XI2 not supported by server.
+            }
+
+            let wacom_serial = CString::new(WACOM_SERIAL_IDS).unwrap();
+            let (pointers, keyboards) = Self::query_slave_devices(display);
+
+            Some(X11XInput2 { opcode, pens: Self::query_pen_devices(display), pointers, keyboards, pointer: 0, keyboard: 0,
+                gestures: major > XI_2_Major || minor >= XI_2_GestureMinor, touchpad: TouchpadGestureDecoder::new(),
+                serial_ids: XInternAtom(display, wacom_serial.as_ptr(), true) })
+        }
+    }
+
+    /// Query ids of all enabled slave pointers and slave keyboards.
+    /// 
+    /// Returns a pair of (pointers, keyboards).
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn query_slave_devices(display : *mut X11Display) -> (Vec<c_int>, Vec<c_int>) {
+        unsafe {
+            let mut pointers : Vec<c_int> = Vec::new();
+            let mut keyboards : Vec<c_int> = Vec::new();
+            let mut count : c_int = 0;
+
+            let devices = XIQueryDevice(display, XIAllDevices, &mut count);
+            if devices.is_null() {
+                return (pointers, keyboards);
+            }
+
+            for device in core::slice::from_raw_parts(devices, count as usize) {
+                if device.enabled != 0 {
+                    match device._use {
+                        XISlavePointer => pointers.push(device.deviceid),
+                        XISlaveKeyboard => keyboards.push(device.deviceid),
+                        _ => {},
+                    }
+                }
+            }
+
+            XIFreeDeviceInfo(devices);
+
+            (pointers, keyboards)
+        }
+    }
+
+    /// Query all XInput2 devices as [InputDevice].
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn query_input_devices(display : *mut X11Display) -> Vec<InputDevice> {
+        unsafe {
+            let mut list : Vec<InputDevice> = Vec::new();
+            let mut count : c_int = 0;
+
+            let devices = XIQueryDevice(display, XIAllDevices, &mut count);
+            if devices.is_null() {
+                return list;
+            }
+
+            for device in core::slice::from_raw_parts(devices, count as usize) {
+                let mut buttons : u32 = 0;
+                let mut axes : u32 = 0;
+                let mut touches : u32 = 0;
+                let mut touch_mode : c_int = 0;
+                let mut pressure = false;
+
+                for class in core::slice::from_raw_parts(device.classes, device.num_classes as usize) {
+                    match (**class)._type {
+                        XIButtonClass => buttons = (*(*class as *const XIButtonClassInfo)).num_buttons as u32,
+                        XIValuatorClass => {
+                            axes += 1;
+                            if Self::get_atom_name(display, (*(*class as *const XIValuatorClassInfo)).label) == LABEL_PRESSURE {
+                                pressure = true;
+                            }
+                        },
+                        XITouchClass => {
+                            let class = &*(*class as *const XITouchClassInfo);
+                            touches = class.num_touches as u32;
+                            touch_mode = class.mode;
+                        },
+                        _ => {},
+                    }
+                }
+
+                let device_type = match device._use {
+                    XIMasterKeyboard | XISlaveKeyboard => InputDeviceType::Keyboard,
+                    _ if touch_mode == XIDirectTouch => InputDeviceType::Touchscreen,
+                    _ if touch_mode == XIDependentTouch => InputDeviceType::Touchpad,
+                    _ if pressure => InputDeviceType::Pen,
+                    XIMasterPointer | XISlavePointer | XIFloatingSlave if buttons > 0 => InputDeviceType::Mouse,
+                    _ => InputDeviceType::Other,
+                };
+
+                list.push(InputDevice::new(device.deviceid as u16, CStr::from_ptr(device.name).to_string_lossy().to_string(), device_type,
+                    device._use == XIMasterPointer || device._use == XIMasterKeyboard, device.enabled != 0, buttons, axes, touches));
+            }
+
+            XIFreeDeviceInfo(devices);
+
+            list
+        }
+    }
+
+    /// Query all slave pointers and keep those with a pressure valuator.
+    pub(crate) fn query_pen_devices(display : *mut X11Display) -> Vec<X11PenDevice> {
+        unsafe {
+            let mut pens : Vec<X11PenDevice> = Vec::new();
+            let mut count : c_int = 0;
+
+            let devices = XIQueryDevice(display, XIAllDevices, &mut count);
+            if devices.is_null() {
+                return pens;
+            }
+
+            for device in core::slice::from_raw_parts(devices, count as usize) {
+                // Only slave pointers can be pens.
+                if device._use != XISlavePointer && device._use != XIFloatingSlave {
+                    continue;
+                }
+
+                // Tool type of Wacom driver, or eraser in device name otherwise.
+                let wacom_tool = Self::query_wacom_tool(display, device.deviceid);
+                let tool = wacom_tool.unwrap_or_else(|| {
+                    let name = CStr::from_ptr(device.name).to_string_lossy().to_lowercase();
+                    if name.contains("eraser") { PenTool::Eraser } else { PenTool::Pen }
+                });
+
+                let mut pen = X11PenDevice { id: device.deviceid, pressure: None, tilt_x: None, tilt_y: None, rotation: None, proximity: false,
+                    wacom: wacom_tool.is_some(), state: PenState::new(device.deviceid as u16, tool) };
+
+                for class in core::slice::from_raw_parts(device.classes, device.num_classes as usize) {
+                    if (**class)._type == XIValuatorClass {
+                        let class = &*(*class as *const XIValuatorClassInfo);
+                        let valuator = Some(X11Valuator { number: class.number as usize, min: class.min, max: class.max });
+
+                        match Self::get_atom_name(display, class.label).as_str() {
+                            LABEL_PRESSURE => pen.pressure = valuator,
+                            LABEL_TILT_X => pen.tilt_x = valuator,
+                            LABEL_TILT_Y => pen.tilt_y = valuator,
+                            label if LABEL_ROTATION.contains(&label) => pen.rotation = valuator,
+                            _ => {},
+                        }
+                    }
+                }
+
+                // Device is a pen only if it reports pressure.
+                if pen.pressure.is_some() {
+                    pens.push(pen);
+                }
+            }
+
+            XIFreeDeviceInfo(devices);
+
+            pens
+        }
+    }
+
+    /// Query the tool of a device from the `Wacom Tool Type` property.
+    ///
+    /// Returns Some([PenTool]) or None if device isn't handled by the Wacom driver.
+    fn query_wacom_tool(display : *mut X11Display, deviceid : c_int) -> Option<PenTool> {
+        unsafe {
+            let tool_type = CString::new(WACOM_TOOL_TYPE).unwrap();
+            let property = XInternAtom(display, tool_type.as_ptr(), true);
+            if property == 0 {
+                return None;    // Wacom driver not loaded.
+            }
+
+            // Used to capture XIGetProperty
+            let mut type_return : Atom = 0;
+            let mut format_return : c_int = 0;
+            let mut nitems_return : c_ulong = 0;
+            let mut bytes_after_return : c_ulong = 0;
+            let mut data : *mut c_uchar = null_mut();
+
+            if XIGetProperty(display, deviceid, property, 0, 1, false, 0, &mut type_return, &mut format_return,
+                &mut nitems_return, &mut bytes_after_return, &mut data) != 0 {
+                return None;
+            }
+
+            let tool = if format_return == 32 && nitems_return > 0 {
+                // XI2 properties of format 32 are returned as 32 bits values.
+                let tool_atom = *(data as *const u32) as Atom;
+                Some(if Self::get_atom_name(display, tool_atom) == WACOM_TOOL_ERASER { PenTool::Eraser } else { PenTool::Pen })
+            } else {
+                None    // Device without tool type.
+            };
+
+            XFree(data as *mut c_void);
+
+            tool
+        }
+    }
+
+    /// Get the name of an atom. Returns an empty string if atom is 0.
+    pub(crate) fn get_atom_name(display : *mut X11Display, atom : Atom) -> String {
+        unsafe {
+            if atom == 0 {
+                return String::new();
+            }
+
+            let name = XGetAtomName(display, atom);
+            if name.is_null() {
+                String::new()
+            } else {
+                let value = CStr::from_ptr(name).to_string_lossy().to_string();
+                XFree(name as *mut c_void);
+                value
+            }
+        }
+    }
+}
+
+/// Get input device list in [InputDeviceList].
+pub(crate) fn get_x11_input_device_list() -> Result<InputDeviceList, StudioError> {
+    unsafe {
+        let display = XOpenDisplay(std::ptr::null());
+        if display.is_null() {
+            return Err(StudioError::Display(DisplayError::NoDisplayServer));
+        }
+
+        let list = match X11XInput2::new(display) {
+            Some(_) => Ok(InputDeviceList::create(X11XInput2::query_input_devices(display))),
+            None => Err(StudioError::Display(DisplayError::NotSupported)),
+        };
+
+        XCloseDisplay(display);
+
+        list
+    }
+}
+
+impl<'window> X11WindowManager<'window> {
+
+    /// Select XInput2 events of pen devices, slave pointers and slave keyboards on window, following the event categories
+    /// of window. Devices of masked categories are selected with an empty mask to clear a previous selection.
+    /// 
+    /// Slave events are only used to know which physical device generated the next core event.
+    pub(super) fn select_xinput2_events(&mut self) {
+        unsafe {
+            if let Some(xi2) = &self.xinput2 {
+                let category = self.property.event_mask;
+                let motion = category.contains(EventCategoryMask::POINTER_MOTION);
+                let button = category.contains(EventCategoryMask::POINTER_BUTTON);
+                let pen = category.contains(EventCategoryMask::PEN);
+
+                let mut masks : Vec<[c_uchar; XI_MASK_LEN]> = Vec::new();
+                let mut devices : Vec<c_int> = Vec::new();
+
+                for device in &xi2.pens {
+                    let mut mask = [0; XI_MASK_LEN];
+                    Self::xi_select(&mut mask, motion || pen, &[XI_Motion]);
+                    Self::xi_select(&mut mask, button || pen, &[XI_ButtonPress, XI_ButtonRelease]);
+                    Self::xi_select(&mut mask, pen, &[XI_PropertyEvent]);
+                    masks.push(mask);
+                    devices.push(device.id);
+                }
+
+                for pointer in xi2.pointers.iter().filter(|id| !devices.contains(id)).copied().collect::<Vec<c_int>>() {
+                    let mut mask = [0; XI_MASK_LEN];
+                    Self::xi_select(&mut mask, motion, &[XI_Motion]);
+                    Self::xi_select(&mut mask, button, &[XI_ButtonPress, XI_ButtonRelease]);
+                    masks.push(mask);
+                    devices.push(pointer);
+                }
+
+                for keyboard in &xi2.keyboards {
+                    let mut mask = [0; XI_MASK_LEN];
+                    Self::xi_select(&mut mask, category.contains(EventCategoryMask::KEYBOARD), &[XI_KeyPress, XI_KeyRelease]);
+                    masks.push(mask);
+                    devices.push(*keyboard);
+                }
+
+                // Touchpad gestures are sent by master pointers only. Leave is used for proximity of pens without Wacom driver.
+                let mut mask = [0; XI_MASK_LEN];
+                Self::xi_select(&mut mask, pen, &[XI_Leave]);
+                Self::xi_select(&mut mask, xi2.gestures && category.contains(EventCategoryMask::GESTURE), 
+                    &(XI_GesturePinchBegin..=XI_GestureSwipeEnd).collect::<Vec<c_int>>());
+                masks.push(mask);
+                devices.push(XIAllMasterDevices);
+
+                let mut xi_masks : Vec<XIEventMask> = masks.iter_mut().zip(devices.iter()).map(|(mask, id)|
+                    XIEventMask { deviceid: *id, mask_len: XI_MASK_LEN as c_int, mask: mask.as_mut_ptr() }).collect();
+
+                if !xi_masks.is_empty() {
+                    XISelectEvents(self.display, self.window, xi_masks.as_mut_ptr(), xi_masks.len() as c_int);
+                }
+
+                // Hierarchy changes are used to detect pens plugged or unplugged.
+                let mut hierarchy = [0; XI_MASK_LEN];
+                xi_set_mask(&mut hierarchy, XI_HierarchyChanged);
+                let mut hierarchy_mask = XIEventMask { deviceid: XIAllDevices, mask_len: XI_MASK_LEN as c_int, mask: hierarchy.as_mut_ptr() };
+                XISelectEvents(self.display, XDefaultRootWindow(self.display), &mut hierarchy_mask, 1);
+            }
+        }
+    }
+
+    /// Set evtypes in XI2 event mask if selected.
+    #[inline(always)]
+    fn xi_select(mask : &mut [c_uchar; XI_MASK_LEN], selected : bool, evtypes : &[c_int]) {
+        if selected {
+            evtypes.iter().for_each(|evtype| xi_set_mask(mask, *evtype));
+        }
+    }
+
+    /// Get Event created from XInput2 GenericEvent.
+    #[inline(always)]
+    pub(super) fn get_xinput2_event(&mut self, xevent : &XEvent) -> Event {
+        match self.decode_xinput2_event(xevent) {
+            Event::None => self.fetch_event(),  // Ignore and poll next event
+            event => event,
+        }
+    }
+
+    /// Returns true if xevent is a XI2 motion GenericEvent, without fetching its data.
+    #[inline(always)]
+    pub(super) fn is_xinput2_motion(&self, xevent : &XEvent) -> bool {
+        unsafe {
+            xevent._type == GenericEvent && self.xinput2.as_ref().is_some_and(|xi2| xevent._xcookie._extension == xi2.opcode)
+                && xevent._xcookie._evtype == XI_Motion
+        }
+    }
+
+    /// Decode XInput2 GenericEvent without polling the next event.
+    /// 
+    /// Returns the decoded [Event] or [Event::None] if ignored or only used to tag the following core event.
+    #[allow(non_upper_case_globals)]
+    pub(super) fn decode_xinput2_event(&mut self, xevent : &XEvent) -> Event {
+        unsafe {
+            let mut cookie : XGenericEventCookie = xevent._xcookie;
+
+            let opcode = match &self.xinput2 {
+                Some(xi2) => xi2.opcode,
+                None => return Event::None,
+            };
+
+            // Data of events routed by an application is already fetched and freed by the application.
+            let fetched = !cookie._data.is_null();
+            if cookie._extension != opcode || (!fetched && !XGetEventData(self.display, &mut cookie)) {
+                return Event::None;  // Not a XInput2 event.
+            }
+
+            let event = match cookie._evtype {
+                XI_Motion | XI_ButtonPress | XI_ButtonRelease => {
+                    let xi_event = &*(cookie._data as *const XIDeviceEvent);
+                    if let Some(xi2) = &mut self.xinput2 {
+                        xi2.pointer = xi_event._sourceid as u16;   // Used to tag the following core event.
+                    }
+                    self.get_pen_device_event(cookie._evtype, xi_event)
+                },
+                XI_KeyPress | XI_KeyRelease => {
+                    if let Some(xi2) = &mut self.xinput2 {
+                        xi2.keyboard = (*(cookie._data as *const XIDeviceEvent))._sourceid as u16;   // Used to tag the following core event.
+                    }
+                    Event::None
+                },
+                XI_PropertyEvent => self.get_pen_property_event(&*(cookie._data as *const XIPropertyEvent)),
+                XI_Leave => self.get_pen_leave_event(&*(cookie._data as *const XILeaveEvent)),
+                XI_GesturePinchBegin | XI_GesturePinchUpdate | XI_GesturePinchEnd => 
+                    self.get_pinch_gesture_event(cookie._evtype, &*(cookie._data as *const XIGesturePinchEvent)),
+                XI_GestureSwipeBegin | XI_GestureSwipeUpdate | XI_GestureSwipeEnd => 
+                    self.get_swipe_gesture_event(cookie._evtype, &*(cookie._data as *const XIGestureSwipeEvent)),
+                XI_HierarchyChanged => {
+                    // Refresh devices and selection.
+                    if let Some(xi2) = &mut self.xinput2 {
+                        xi2.pens = X11XInput2::query_pen_devices(self.display);
+                        (xi2.pointers, xi2.keyboards) = X11XInput2::query_slave_devices(self.display);
+                    }
+                    self.select_xinput2_events();
+                    Event::None
+                },
+                _ => Event::None,
+            };
+
+            if !fetched {
+                XFreeEventData(self.display, &mut cookie);
+            }
+
+            event
+        }
+    }
+
+    /// Get [EventPen] from XI2 device event of a pen.
+    #[inline(always)]
+    #[allow(non_upper_case_globals)]
+    fn get_pen_device_event(&mut self, evtype : c_int, xi_event : &XIDeviceEvent) -> Event {
+        let pen = match self.xinput2.as_mut().and_then(|xi2| xi2.pens.iter_mut().find(|pen| pen.id == xi_event._deviceid)) {
+            Some(pen) => pen,
+            None => return Event::None,
+        };
+
+        pen.state.position = (xi_event._event_x, xi_event._event_y);
+        pen.update_valuators(&xi_event._valuators);
+        let state = pen.state;
+
+        let event = match evtype {
+            XI_ButtonPress if xi_event._detail == PEN_TIP_BUTTON => EventPen::Down(state),
+            XI_ButtonRelease if xi_event._detail == PEN_TIP_BUTTON => EventPen::Up(state),
+            XI_ButtonPress => EventPen::ButtonDown((xi_event._detail - 1) as u8, state),
+            XI_ButtonRelease => EventPen::ButtonUp((xi_event._detail - 1) as u8, state),
+            _ => EventPen::Moved(state),
+        };
+
+        // Pen without proximity property enter proximity on first event.
+        if !pen.proximity {
+            pen.proximity = true;
+            self.push_event(Event::Pen(event));
+            Event::Pen(EventPen::ProximityIn(state))
+        } else {
+            Event::Pen(event)
+        }
+    }
+
+    /// Get pinch gesture [Event] from XI2 gesture event.
+    #[inline(always)]
+    #[allow(non_upper_case_globals)]
+    fn get_pinch_gesture_event(&mut self, evtype : c_int, xi_event : &XIGesturePinchEvent) -> Event {
+        let xi2 = match &mut self.xinput2 {
+            Some(xi2) => xi2,
+            None => return Event::None,
+        };
+
+        Event::Gesture(xi2.touchpad.decode_pinch(&TouchpadGestureInput { phase: Self::get_gesture_phase(evtype), 
+            fingers: xi_event._detail as u32, position: (xi_event._event_x, xi_event._event_y), 
+            delta: (xi_event._delta_x, xi_event._delta_y), scale: xi_event._scale, delta_angle: xi_event._delta_angle, 
+            cancelled: xi_event._flags & XIGesturePinchEventCancelled != 0 }))
+    }
+
+    /// Get swipe gesture [Event] from XI2 gesture event.
+    #[inline(always)]
+    fn get_swipe_gesture_event(&self, evtype : c_int, xi_event : &XIGestureSwipeEvent) -> Event {
+        let xi2 = match &self.xinput2 {
+            Some(xi2) => xi2,
+            None => return Event::None,
+        };
+
+        Event::Gesture(xi2.touchpad.decode_swipe(&TouchpadGestureInput { phase: Self::get_gesture_phase(evtype), 
+            fingers: xi_event._detail as u32, position: (xi_event._event_x, xi_event._event_y), 
+            delta: (xi_event._delta_x, xi_event._delta_y), scale: 1.0, delta_angle: 0.0, 
+            cancelled: xi_event._flags & XIGestureSwipeEventCancelled != 0 }))
+    }
+
+    /// Get [TouchpadGesturePhase] of XI2 gesture event type.
+    #[inline(always)]
+    #[allow(non_upper_case_globals)]
+    fn get_gesture_phase(evtype : c_int) -> TouchpadGesturePhase {
+        match evtype {
+            XI_GesturePinchBegin | XI_GestureSwipeBegin => TouchpadGesturePhase::Begin,
+            XI_GesturePinchUpdate | XI_GestureSwipeUpdate => TouchpadGesturePhase::Update,
+            _ => TouchpadGesturePhase::End,
+        }
+    }
+
+    /// Get proximity out [EventPen] of a pen without Wacom driver leaving window.
+    #[inline(always)]
+    fn get_pen_leave_event(&mut self, xi_event : &XILeaveEvent) -> Event {
+        match self.xinput2.as_mut().and_then(|xi2| xi2.pens.iter_mut().find(|pen| pen.id == xi_event._sourceid)) {
+            Some(pen) if !pen.wacom && pen.proximity => {
+                pen.proximity = false;
+                Event::Pen(EventPen::ProximityOut(pen.state))
+            },
+            _ => Event::None,
+        }
+    }
+
+    /// Get proximity [EventPen] from XI2 property event of a pen.
+    #[inline(always)]
+    fn get_pen_property_event(&mut self, xi_event : &XIPropertyEvent) -> Event {
+        unsafe {
+            let xi2 = match &mut self.xinput2 {
+                Some(xi2) => xi2,
+                None => return Event::None,
+            };
+
+            if xi2.serial_ids == 0 || xi_event._property != xi2.serial_ids {
+                return Event::None; // Not a proximity change.
+            }
+
+            let serial_ids = xi2.serial_ids;
+            let pen = match xi2.pens.iter_mut().find(|pen| pen.id == xi_event._deviceid) {
+                Some(pen) => pen,
+                None => return Event::None,
+            };
+
+            // Used to capture XIGetProperty
+            let mut type_return : Atom = 0;
+            let mut format_return : c_int = 0;
+            let mut nitems_return : c_ulong = 0;
+            let mut bytes_after_return : c_ulong = 0;
+            let mut data : *mut c_uchar = null_mut();
+
+            if XIGetProperty(self.display, pen.id, serial_ids, 0, 5, false, 0, &mut type_return, &mut format_return,
+                &mut nitems_return, &mut bytes_after_return, &mut data) != 0 {
+                return Event::None;
+            }
+
+            let mut event = Event::None;
+            if format_return == 32 && nitems_return as usize > WACOM_SERIAL_IN_PROXIMITY {
+                // XI2 properties of format 32 are returned as 32 bits values.
+                let serials = core::slice::from_raw_parts(data as *const u32, nitems_return as usize);
+                let proximity = serials[WACOM_SERIAL_IN_PROXIMITY] != 0;
+
+                if proximity != pen.proximity {
+                    pen.proximity = proximity;
+                    event = if proximity {
+                        Event::Pen(EventPen::ProximityIn(pen.state))
+                    } else {
+                        Event::Pen(EventPen::ProximityOut(pen.state))
+                    };
+                }
+            }
+
+            XFree(data as *mut c_void);
+
+            event
+        }
+    }
+
+}
@@ -1,14 +1,25 @@
-use std::cell::RefCell;
-use std::ffi::{CString, c_int, CStr, c_void};
+use std::any::{Any, TypeId};
+use std::cell::{OnceCell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::ffi::{CString, c_int, CStr, c_void, c_uchar, c_ulong, c_long};
 use std::panic::catch_unwind;
 use std::ptr::{null_mut};
 use std::thread;
-
-use crate::display::desktop::event::Event;
+use std::time::Instant;
+
+use crate::display::desktop::event::{Event, EventCategoryMask};
+use crate::display::desktop::event::keyboard::KeyModifier;
+use crate::display::desktop::gamepad::GamepadList;
+use crate::display::desktop::icon::WindowIcon;
+use crate::display::desktop::proxy::{EventProxy, UserEventReceiver};
+use crate::display::desktop::region::DamageRegion;
+use crate::display::desktop::source::FdSourceList;
+use crate::display::desktop::timer::TimerList;
 use crate::display::desktop::event::window::EventWindow;
 use crate::display::desktop::manager::WindowManager;
 use crate::display::desktop::manager::linux::x11::cbind::xinput::{XNQueryInputStyle, XIMStyle, XIMPreeditNothing, XIMStatusNothing, XNInputStyle, XNClientWindow, XNFocusWindow};
-use crate::display::desktop::property::{WindowProperty, SubWindowOption, WindowPositionOption, get_absolute_position_from_relative, PointerMode, KeyboardMode, WindowEventWaitMode};
+use crate::display::desktop::property::{WindowProperty, SubWindowOption, WindowPositionOption, get_absolute_position_from_relative, PointerMode, KeyboardMode, WindowEventWaitMode, WindowType};
 use crate::display::desktop::window::Window;
 use crate::error::StudioError;
 use self::cbind::structs::XEvent;
@@ -26,35 +37,48 @@ pub(crate) mod atom;
 /// Contains X11 Events handling
 pub(crate) mod event;
 
+/// Contains X11 XInput2 devices handling
+pub(crate) mod input;
+
+/// Contains X11 XSETTINGS reading
+pub mod xsettings;
+
+/// Contains X11 application sharing a display connection between windows
+pub(crate) mod application;
+
 use cbind::{attributes::*, constants::*, functs::*, structs::* };
 
 
 use super::{WindowProvider}; 
 use atom::X11Atoms;
+use input::X11XInput2;
 
 
-/// Event mask used with x11 to capture and dispatch event.
-const EVENT_MASK : i64 =    KeyPressMask | KeyReleaseMask |             // Keyboard Button Down and Up
-                            ButtonPressMask | ButtonReleaseMask |       // Controller button??? TBD 
-                            EnterWindowMask | LeaveWindowMask |         // Window focus, blur
-                            PointerMotionMask | Button1MotionMask | 
-                            Button2MotionMask | Button3MotionMask |
-                            Button4MotionMask | Button5MotionMask |
-                            ButtonMotionMask |                          // Mouse motion??? TBD
-                            StructureNotifyMask |                       // ResizeRedirectMask |
-                            VisibilityChangeMask | FocusChangeMask |
-                            PropertyChangeMask | ExposureMask;          // Window event I guess??
+/// Event mask always used with x11 to capture window structure changes.
+const EVENT_MASK_BASE : i64 = StructureNotifyMask |                     // ResizeRedirectMask |
+                            VisibilityChangeMask | PropertyChangeMask;
+
+/// Pairs of event category and x11 event mask used to capture and dispatch events of that category.
+const EVENT_MASK_CATEGORIES : [(EventCategoryMask, i64); 6] = [
+    (EventCategoryMask::KEYBOARD, KeyPressMask | KeyReleaseMask),                   // Keyboard Button Down and Up
+    (EventCategoryMask::POINTER_BUTTON, ButtonPressMask | ButtonReleaseMask),       // Pointer button and scroll
+    (EventCategoryMask::POINTER_MOTION, PointerMotionMask | Button1MotionMask | Button2MotionMask | Button3MotionMask |
+        Button4MotionMask | Button5MotionMask | ButtonMotionMask),                  // Mouse motion
+    (EventCategoryMask::CURSOR_CROSSING, EnterWindowMask | LeaveWindowMask),        // Cursor enter, leave
+    (EventCategoryMask::FOCUS, FocusChangeMask),                                    // Window focus, blur
+    (EventCategoryMask::EXPOSE, ExposureMask),
+];
 
 /// Shortcut macro used to change x11 atoms properties
 macro_rules! x11_change_property {
     ($display:expr, $window:expr, $x11_property:expr, $property:ident $(,$atoms:ident)+) => {
 
         // Put atoms in 1 array.
-        let atoms_arr = [$($x11_property.$atoms,)+];
+        let mut atoms_arr = [$($x11_property.$atoms,)+];
 
         // Push properties change
         XChangeProperty($display, $window, $x11_property.$property,
-            $x11_property.xa_atom, 32, PropModeReplace, std::mem::transmute(&atoms_arr), atoms_arr.len() as i32);
+            $x11_property.xa_atom, 32, PropModeReplace, atoms_arr.as_mut_ptr() as *mut std::ffi::c_uchar, atoms_arr.len() as i32);
     }
 }
 
@@ -74,6 +98,32 @@ macro_rules! key_identity_matchs {
 #[doc(hidden)]
 pub static mut X11_SUPPORTED : Option<bool> = Option::None;
 
+/// X11 display connection closed when dropped. Shared by the windows of an application.
+pub(crate) struct X11Connection {
+    /// Display connection pointer
+    pub(crate) display : *mut X11Display,
+}
+
+impl X11Connection {
+    /// Open a new connection to the default display.
+    pub(crate) fn open() -> X11Connection {
+        unsafe {
+            X11Connection { display: XOpenDisplay(std::ptr::null()) }
+        }
+    }
+}
+
+impl Drop for X11Connection {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.display.is_null() {
+                // Close display server connection.
+                XCloseDisplay(self.display);
+            }
+        }
+    }
+}
+
 pub(crate) struct X11WindowManager<'window> {
     /// Used to fetch X11 events
     pub(crate) x_event : XEvent,  
@@ -87,8 +137,8 @@ pub(crate) struct X11WindowManager<'window> {
     /// Xinput context
     xic : XIC,
 
-    /// Retained events that will be sent next poll_event 
-    pub(crate) retained_events : RefCell<Vec<Event>>,
+    /// Retained events that will be sent next poll_event, in the order they were pushed.
+    pub(crate) retained_events : RefCell<VecDeque<Event>>,
 
     /// C-compatible string for window title
     wm_title : CString,
@@ -96,15 +146,58 @@ pub(crate) struct X11WindowManager<'window> {
     /// Display connection pointer
     pub(crate) display : *mut X11Display,
 
+    /// Display connection, closed when the last window or application using it is dropped.
+    _connection : Rc<X11Connection>,
+
+    /// Display connection is shared with other windows. X11 events are pushed by the application into 
+    /// pending_events instead of being read from display.
+    pub(crate) shared : bool,
+
+    /// X11 events routed to this window by the application
+    pub(crate) pending_events : VecDeque<XEvent>,
+
     /// Window handle pointer
     pub(crate) window : *mut X11Handle,
 
     /// Atoms for handling x11 window properties
     pub(crate) atoms : X11Atoms,
 
+    /// XInput2 extension. None if not supported by server.
+    pub(crate) xinput2 : Option<X11XInput2>,
+
     /// Count of event to poll
     pub(crate) event_count : usize,
 
+    /// Deadline of the wait of the current poll_event, kept while swallowed events fetch the next one.
+    pub(crate) wait_deadline : Option<Instant>,
+
+    /// Connected gamepads, opened on first use so windows without gamepads don't scan devices.
+    pub(crate) gamepads : OnceCell<GamepadList>,
+
+    /// User events posted by proxies.
+    pub(crate) user_events : UserEventReceiver,
+
+    /// Timers of window.
+    pub(crate) timers : TimerList,
+
+    /// External file descriptors watched by window.
+    pub(crate) fd_sources : FdSourceList,
+
+    /// Exposed rectangles accumulated until the last Expose of a series.
+    pub(crate) damage : DamageRegion,
+
+    /// Damaged region of the last exposed event.
+    pub(crate) exposed : DamageRegion,
+
+    /// A redraw event is sent when no other event is pending.
+    pub(crate) redraw_requested : bool,
+
+    /// Last X11 time received, used to detect X11 time wrap around.
+    pub(crate) last_time : Time,
+
+    /// Base added to X11 time to get a monotonic timestamp.
+    pub(crate) time_base : u64,
+
     /// [Window] properties
     pub(super) property : WindowProperty<'window>,
 
@@ -118,29 +211,7 @@ pub(crate) struct X11WindowManager<'window> {
 
 impl<'window> WindowManager<'window> for X11WindowManager<'window> {
     fn new() -> Result<Self, StudioError> {
-
-        unsafe{
-            let display = XOpenDisplay(std::ptr::null());      // Display connection
-            let atoms = X11Atoms::new(display);                         // X11 Atoms
-
-
-            Ok(X11WindowManager {
-                x_event: XEvent{ _type:0 }, 
-                retained_events: RefCell::new(Vec::new()),
-                wm_title: CString::new("").unwrap(), 
-                display,
-                window: null_mut(),
-                atoms,
-                event_count: 0,
-                property: WindowProperty::new(),
-                mapped: false,
-                fullscreen: false,
-                event: Event::None,
-                xim: 0,
-                xic: 0,
-            })
-        }
-        
+        Ok(Self::create(Rc::new(X11Connection::open()), false))
     }
 
     #[inline(always)]
@@ -151,25 +222,95 @@ impl<'window> WindowManager<'window> for X11WindowManager<'window> {
     #[inline(always)]
     fn poll_event(&mut self) -> &Event  {
 
-        match self.property.wait_mode {
-            WindowEventWaitMode::NeverWait => {
+        // Discarded masked events must not restart the wait.
+        self.wait_deadline = self.property.wait_mode.get_deadline();
+
+        loop {
+            if self.shared {    // Events are routed by the application.
                 if self.event == Event::None {
-                    self.sync();
-                    self.event_count = self.get_event_count();
+                    self.fd_sources.update();
                 }
                 self.event = self.fetch_event();
-            },
-            WindowEventWaitMode::AlwaysWait => {
-                self.sync();
-                self.event = self.fetch_event();
-            },
+            } else {
+                match self.property.wait_mode {
+                    WindowEventWaitMode::NeverWait => {
+                        if self.event == Event::None {
+                            self.sync();
+                            self.event_count = self.get_event_count();
+                            self.fd_sources.update();
+                        }
+                        self.event = self.fetch_event();
+                    },
+                    WindowEventWaitMode::AlwaysWait | WindowEventWaitMode::WaitTimeout(_) | WindowEventWaitMode::WaitUntil(_) => {
+                        self.sync();
+                        self.fd_sources.update();
+                        self.event = self.fetch_event();
+                    },
+                }
+            }
+
+            // Discard events of masked categories.
+            if self.property.event_mask.accepts(&self.event) {
+                break;
+            }
         }
         
         &self.event
     }
 
     fn push_event(&self, retain: Event){
-        self.retained_events.borrow_mut().push(retain);
+        self.retained_events.borrow_mut().push_back(retain);
+    }
+
+    #[inline(always)]
+    fn get_gamepads(&self) -> &GamepadList {
+        self.gamepads.get_or_init(GamepadList::new)
+    }
+
+    #[inline(always)]
+    fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        self.gamepads.get_or_init(GamepadList::new);
+        self.gamepads.get_mut().unwrap()
+    }
+
+    #[inline(always)]
+    fn create_proxy(&self) -> EventProxy {
+        self.user_events.create_proxy()
+    }
+
+    #[inline(always)]
+    fn take_user_payload(&self, key : u64, type_id : TypeId) -> Option<Box<dyn Any + Send>> {
+        self.user_events.take_payload(key, type_id)
+    }
+
+    #[inline(always)]
+    fn get_timers(&self) -> &TimerList {
+        &self.timers
+    }
+
+    #[inline(always)]
+    fn get_timers_mut(&mut self) -> &mut TimerList {
+        &mut self.timers
+    }
+
+    #[inline(always)]
+    fn get_fd_sources(&self) -> &FdSourceList {
+        &self.fd_sources
+    }
+
+    #[inline(always)]
+    fn get_fd_sources_mut(&mut self) -> &mut FdSourceList {
+        &mut self.fd_sources
+    }
+
+    #[inline(always)]
+    fn get_damage_region(&self) -> &DamageRegion {
+        &self.exposed
+    }
+
+    #[inline(always)]
+    fn request_redraw(&mut self) {
+        self.redraw_requested = true;
     }
 
     #[inline(always)]
@@ -219,6 +360,25 @@ impl<'window> WindowManager<'window> for X11WindowManager<'window> {
         false
     }
 
+    #[inline(always)]
+    fn set_event_mask(&mut self, mask : EventCategoryMask) -> bool {
+        self.property.event_mask = mask;
+
+        if self.property.created {  // Update events requested from server
+            unsafe {
+                XSelectInput(self.display, self.window, self.get_x11_event_mask());
+            }
+            self.select_xinput2_events();
+        }
+        false
+    }
+
+    #[inline(always)]
+    fn set_event_coalescing(&mut self, mask : EventCategoryMask) -> bool {
+        self.property.coalescing = mask;
+        false
+    }
+
     #[inline(always)]
     fn set_title(&mut self, title : &String) -> bool {
         unsafe {
@@ -234,22 +394,132 @@ impl<'window> WindowManager<'window> for X11WindowManager<'window> {
         unsafe {
             self.property.relative_position = option.clone();
             self.property.position = get_absolute_position_from_relative(self.property.size, self.property.parent, &option);
-            XMoveWindow(self.display, self.window, self.property.position.0, self.property.position.1);
+            if self.property.is_normal() {
+                self.property.normal_position = self.property.position;
+            }
+
+            if self.property.created {  // Window is created at property position otherwise
+                XMoveWindow(self.display, self.window, self.property.position.0, self.property.position.1);
+            }
         }
         false
     }
 
     #[inline(always)]
     fn set_size(&mut self, size : &(u32,u32)) -> bool {
-        unsafe {
-            // Keep real window position
-            let position = X11WindowManager::get_x11_window_position(self.display, self.window);
+        if !self.property.created {  // Window is created with property size
+            self.property.size = *size;
+            if self.property.is_normal() {
+                self.property.normal_size = *size;
+            }
+        } else {    // Property size is updated by ConfigureNotify, once window manager accepted the size.
+            if !self.property.resizable {   // Move the size lock to new size
+                self.set_size_hints(*size);
+            }
 
-            XResizeWindow(self.display, self.window, size.0, size.1);
-            
-            // Reposition window since resize put it back at 0,0
-            XMoveWindow(self.display, self.window, position.0, position.1);
+            unsafe {
+                // Keep real window position
+                let position = X11WindowManager::get_x11_window_position(self.display, self.window);
+
+                XResizeWindow(self.display, self.window, size.0, size.1);
+                
+                // Reposition window since resize put it back at 0,0
+                XMoveWindow(self.display, self.window, position.0, position.1);
+            }
+        }
+        false
+    }
+
+    #[inline(always)]
+    fn set_min_size(&mut self, min_size : Option<(u32, u32)>) -> bool {
+        self.property.min_size = min_size;
+        self.set_size_hints(self.property.size);
+        false
+    }
+
+    #[inline(always)]
+    fn set_max_size(&mut self, max_size : Option<(u32, u32)>) -> bool {
+        self.property.max_size = max_size;
+        self.set_size_hints(self.property.size);
+        false
+    }
+
+    #[inline(always)]
+    fn set_aspect_ratio(&mut self, aspect_ratio : Option<(u32, u32)>) -> bool {
+        self.property.aspect_ratio = aspect_ratio;
+        self.set_size_hints(self.property.size);
+        false
+    }
+
+    #[inline(always)]
+    fn set_resize_increment(&mut self, resize_increment : Option<(u32, u32)>) -> bool {
+        self.property.resize_increment = resize_increment;
+        self.set_size_hints(self.property.size);
+        false
+    }
+
+    #[inline(always)]
+    fn set_resizable(&mut self, resizable : bool) -> bool {
+        self.property.resizable = resizable;
+        self.set_size_hints(self.property.size);
+        self.set_allowed_actions();
+        false
+    }
+
+    #[inline(always)]
+    fn set_icon(&mut self, icon : &[WindowIcon]) -> bool {
+        self.property.icon = icon.to_vec();
+        self.set_x11_icon();
+        false
+    }
+
+    #[inline(always)]
+    fn set_window_type(&mut self, window_type : WindowType) -> bool {
+        self.property.window_type = window_type;
+        self.set_x11_window_type();
+        false
+    }
+
+    #[inline(always)]
+    fn set_above(&mut self, above : bool) -> bool {
+        if above && self.property.below {     // Above and below are exclusive
+            self.property.below = false;
+            self.set_x11_state(false, self.atoms._NET_WM_STATE_BELOW);
+        }
+        self.property.above = above;
+        self.set_x11_state(above, self.atoms._NET_WM_STATE_ABOVE);
+        false
+    }
+
+    #[inline(always)]
+    fn set_below(&mut self, below : bool) -> bool {
+        if below && self.property.above {     // Above and below are exclusive
+            self.property.above = false;
+            self.set_x11_state(false, self.atoms._NET_WM_STATE_ABOVE);
         }
+        self.property.below = below;
+        self.set_x11_state(below, self.atoms._NET_WM_STATE_BELOW);
+        false
+    }
+
+    #[inline(always)]
+    fn set_sticky(&mut self, sticky : bool) -> bool {
+        self.property.sticky = sticky;
+        self.set_x11_state(sticky, self.atoms._NET_WM_STATE_STICKY);
+        false
+    }
+
+    #[inline(always)]
+    fn set_skip_taskbar(&mut self, skip_taskbar : bool) -> bool {
+        self.property.skip_taskbar = skip_taskbar;
+        self.set_x11_state(skip_taskbar, self.atoms._NET_WM_STATE_SKIP_TASKBAR);
+        false
+    }
+
+    #[inline(always)]
+    fn set_skip_pager(&mut self, skip_pager : bool) -> bool {
+        self.property.skip_pager = skip_pager;
+        self.set_x11_state(skip_pager, self.atoms._NET_WM_STATE_SKIP_PAGER);
         false
     }
 
@@ -350,6 +620,20 @@ impl<'window> WindowManager<'window> for X11WindowManager<'window> {
         self.display as *const usize
     }
 
+    fn get_event_fds(&self) -> Vec<c_int> {
+        let mut fds = vec![unsafe { XConnectionNumber(self.display) }];
+        if let Some(gamepads) = self.gamepads.get() {
+            fds.extend(gamepads.get_fds());
+        }
+        fds.extend(self.user_events.get_fd());
+        fds
+    }
+
+    #[inline(always)]
+    fn has_queued_events(&self) -> bool {
+        unsafe { XEventsQueued(self.display, QueuedAfterFlush) > 0 }
+    }
+
     #[inline(always)]
     fn get_properties(&self) -> &WindowProperty {
         &self.property
@@ -371,7 +655,7 @@ impl<'window> WindowManager<'window> for X11WindowManager<'window> {
     #[inline(always)]
     fn set_fullscreen(&mut self, fsmode : crate::display::desktop::property::FullScreenMode) -> bool {
         self.property.fullscreen = Some(fsmode);
-        true
+        self.property.created       // Window is created fullscreen if not created yet.
     }
 
     #[inline(always)]
@@ -424,12 +708,243 @@ impl<'window> WindowManager<'window> for X11WindowManager<'window> {
         state & Mod2Mask as u32 > 0
     }
 
+    #[inline(always)]
+    fn get_key_state(modifiers : u8) -> u32 {
+        [(KeyModifier::SHIFT, ShiftMask), (KeyModifier::CTRL, ControlMask), (KeyModifier::ALT, Mod1Mask), (KeyModifier::META, Mod3Mask),
+            (KeyModifier::COMMAND, Mod4Mask), (KeyModifier::HYPER, Mod5Mask), (KeyModifier::CAPSLOCK, LockMask), (KeyModifier::NUMLOCK, Mod2Mask)]
+            .iter().filter(|(modifier, _)| modifiers & modifier > 0).fold(0, |state, (_, mask)| state | *mask as u32)
+    }
+
     
 }
 
 
 impl<'window> X11WindowManager<'window> {
 
+    /// Create a new X11WindowManager using a display connection shared with other windows.
+    /// 
+    /// Gamepads are handled by the application and not by the window.
+    pub(crate) fn new_shared(connection : Rc<X11Connection>) -> X11WindowManager<'window> {
+        Self::create(connection, true)
+    }
+
+    /// Create a X11WindowManager on a display connection.
+    fn create(connection : Rc<X11Connection>, shared : bool) -> X11WindowManager<'window> {
+        let display = connection.display;                           // Display connection
+        let atoms = X11Atoms::new(display);                         // X11 Atoms
+        let xinput2 = X11XInput2::new(display);                     // XInput2 extension
+
+        X11WindowManager {
+            x_event: XEvent{ _type:0 }, 
+            retained_events: RefCell::new(VecDeque::new()),
+            wm_title: CString::new("").unwrap(), 
+            display,
+            _connection: connection,
+            shared,
+            pending_events: VecDeque::new(),
+            window: null_mut(),
+            atoms,
+            xinput2,
+            event_count: 0,
+            wait_deadline: Option::None,
+            gamepads: if shared { OnceCell::from(GamepadList::empty()) } else { OnceCell::new() },
+            user_events: UserEventReceiver::new(),
+            timers: TimerList::new(),
+            fd_sources: FdSourceList::new(),
+            damage: DamageRegion::new(),
+            exposed: DamageRegion::new(),
+            redraw_requested: false,
+            last_time: 0,
+            time_base: 0,
+            property: WindowProperty::new(),
+            mapped: false,
+            fullscreen: false,
+            event: Event::None,
+            xim: 0,
+            xic: 0,
+        }
+    }
+
+    /// Set the actions allowed by window manager. Resize and maximize are only allowed if window is resizable.
+    #[inline(always)]
+    fn set_allowed_actions(&self) {
+        if self.window.is_null() {     // Actions are set when window is created.
+            return;
+        }
+
+        unsafe {
+            if self.property.resizable {
+                x11_change_property!(self.display, self.window, self.atoms, _NET_WM_ALLOWED_ACTIONS, _NET_WM_ACTION_FULLSCREEN, _NET_WM_ACTION_MINIMIZE, _NET_WM_ACTION_CHANGE_DESKTOP,
+                    _NET_WM_ACTION_CLOSE, _NET_WM_ACTION_ABOVE, _NET_WM_ACTION_BELOW, _NET_WM_ACTION_RESIZE, _NET_WM_ACTION_MAXIMIZE_HORZ, _NET_WM_ACTION_MAXIMIZE_VERT);
+            } else {
+                x11_change_property!(self.display, self.window, self.atoms, _NET_WM_ALLOWED_ACTIONS, _NET_WM_ACTION_FULLSCREEN, _NET_WM_ACTION_MINIMIZE, _NET_WM_ACTION_CHANGE_DESKTOP,
+                    _NET_WM_ACTION_CLOSE, _NET_WM_ACTION_ABOVE, _NET_WM_ACTION_BELOW);
+            }
+        }
+    }
+
+    /// Get the _NET_WM_STATE atoms of window properties.
+    #[inline(always)]
+    fn get_x11_states(&self) -> Vec<Atom> {
+        let mut states = Vec::new();
+
+        if self.property.fullscreen.is_some() {
+            // TODO: Set fullscreen according to mode.
+            states.extend([self.atoms._NET_WM_STATE_MAXIMIZED_HORZ, self.atoms._NET_WM_STATE_MAXIMIZED_VERT, self.atoms._NET_WM_STATE_FULLSCREEN]);
+        } else if self.property.maximized {
+            states.extend([self.atoms._NET_WM_STATE_MAXIMIZED_HORZ, self.atoms._NET_WM_STATE_MAXIMIZED_VERT]);
+        }
+
+        for (enabled, state) in [(self.property.above, self.atoms._NET_WM_STATE_ABOVE), (self.property.below, self.atoms._NET_WM_STATE_BELOW),
+            (self.property.sticky, self.atoms._NET_WM_STATE_STICKY), (self.property.skip_taskbar, self.atoms._NET_WM_STATE_SKIP_TASKBAR),
+            (self.property.skip_pager, self.atoms._NET_WM_STATE_SKIP_PAGER)] {
+            if enabled {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+
+    /// Add or remove a _NET_WM_STATE state of window. States of a mapped window are changed by sending a client message to
+    /// root window, others are written directly in window property.
+    ///
+    /// Reference(s)
+    /// <https://specifications.freedesktop.org/wm-spec/latest/ar01s05.html#id-1.6.8>
+    #[inline(always)]
+    fn set_x11_state(&self, enabled : bool, state : Atom) {
+        if self.window.is_null() {     // States are set when window is created.
+            return;
+        }
+
+        unsafe {
+            if self.mapped {
+                let mut message = XClientMessageEvent { _type: ClientMessage, _serial: 0, _send_event: true, _display: self.display,
+                    _window: self.window as X11Handle, _message_type: self.atoms._NET_WM_STATE, _format: 32,
+                    _data: XClientMessageEvent_data { _l: [if enabled { self.atoms._NET_WM_STATE_ADD } else { self.atoms._NET_WM_STATE_REMOVE } as c_long,
+                        state as c_long, 0, 1, 0] } };  // Source indication 1 is normal application.
+
+                XSendEvent(self.display, Self::get_x11_default_root_window(self.display), false, SubstructureRedirectMask | SubstructureNotifyMask, &mut message);
+            } else {
+                let mut states = self.get_x11_states();
+                XChangeProperty(self.display, self.window, self.atoms._NET_WM_STATE, self.atoms.xa_atom, 32, PropModeReplace,
+                    states.as_mut_ptr() as *mut c_uchar, states.len() as c_int);
+            }
+        }
+    }
+
+    /// Set the _NET_WM_WINDOW_TYPE property of window from window type. Tooltips and popup menus are override-redirect 
+    /// so that window manager doesn't manage them. Override-redirect change of a shown window applies when shown again.
+    ///
+    /// Reference(s)
+    /// <https://specifications.freedesktop.org/wm-spec/latest/ar01s05.html#id-1.6.7>
+    #[inline(always)]
+    fn set_x11_window_type(&self) {
+        if self.window.is_null() {     // Type is set when window is created.
+            return;
+        }
+
+        unsafe {
+            let mut window_type = match self.property.window_type {
+                WindowType::Normal => self.atoms._NET_WM_WINDOW_TYPE_NORMAL,
+                WindowType::Dialog => self.atoms._NET_WM_WINDOW_TYPE_DIALOG,
+                WindowType::Utility => self.atoms._NET_WM_WINDOW_TYPE_UTILITY,
+                WindowType::Splash => self.atoms._NET_WM_WINDOW_TYPE_SPLASH,
+                WindowType::Tooltip => self.atoms._NET_WM_WINDOW_TYPE_TOOLTIP,
+                WindowType::PopupMenu => self.atoms._NET_WM_WINDOW_TYPE_POPUP_MENU,
+                WindowType::Notification => self.atoms._NET_WM_WINDOW_TYPE_NOTIFICATION,
+            };
+            XChangeProperty(self.display, self.window, self.atoms._NET_WM_WINDOW_TYPE, self.atoms.xa_atom, 32, PropModeReplace,
+                &mut window_type as *mut Atom as *mut c_uchar, 1);
+
+            // Zeroed so that padding of C Bool is 0.
+            let mut attributes : XSetWindowAttributes = std::mem::zeroed();
+            attributes.override_redirect = self.property.window_type.is_unmanaged();
+            XChangeWindowAttributes(self.display, self.window, CWOverrideRedirect as c_ulong, &mut attributes);
+        }
+    }
+
+    /// Set the _NET_WM_ICON property of window from window icons, or delete it if window has no icon.
+    ///
+    /// Reference(s)
+    /// <https://specifications.freedesktop.org/wm-spec/latest/ar01s05.html#id-1.6.13>
+    #[inline(always)]
+    fn set_x11_icon(&self) {
+        if self.window.is_null() {     // Icon is set when window is created.
+            return;
+        }
+
+        unsafe {
+            if self.property.icon.is_empty() {
+                XDeleteProperty(self.display, self.window, self.atoms._NET_WM_ICON);
+            } else {
+                // Each icon is its width, height then its ARGB pixels as 32 bits cardinals, stored in longs.
+                let mut data : Vec<c_ulong> = Vec::new();
+                for icon in &self.property.icon {
+                    data.extend([icon.get_size().0 as c_ulong, icon.get_size().1 as c_ulong]);
+                    data.extend(icon.get_argb().map(|pixel| pixel as c_ulong));
+                }
+
+                XChangeProperty(self.display, self.window, self.atoms._NET_WM_ICON, XA_CARDINAL, 32, PropModeReplace,
+                    data.as_mut_ptr() as *mut c_uchar, data.len() as c_int);
+            }
+        }
+    }
+
+    /// Set the size hints of window manager from window size constraints. A non-resizable window is locked at size.
+    #[inline(always)]
+    fn set_size_hints(&self, size : (u32, u32)) {
+        if self.window.is_null() {     // Hints are set when window is created.
+            return;
+        }
+
+        let mut hints = XSizeHints::default();
+
+        if !self.property.resizable {     // Size locked with equal minimum and maximum
+            hints._flags |= PMinSize | PMaxSize;
+            (hints._min_width, hints._min_height) = (size.0 as c_int, size.1 as c_int);
+            (hints._max_width, hints._max_height) = (hints._min_width, hints._min_height);
+            unsafe {
+                XSetWMNormalHints(self.display, self.window, &hints);
+            }
+            return;
+        }
+
+        if let Some((width, height)) = self.property.min_size {
+            hints._flags |= PMinSize;
+            (hints._min_width, hints._min_height) = (width as c_int, height as c_int);
+        }
+
+        if let Some((width, height)) = self.property.max_size {
+            hints._flags |= PMaxSize;
+            (hints._max_width, hints._max_height) = (width as c_int, height as c_int);
+        }
+
+        if let Some((width, height)) = self.property.aspect_ratio {
+            hints._flags |= PAspect;
+            hints._min_aspect = XAspect { _x: width as c_int, _y: height as c_int };
+            hints._max_aspect = hints._min_aspect;
+        }
+
+        if let Some((width, height)) = self.property.resize_increment {
+            let base = self.property.min_size.unwrap_or((0, 0));
+            hints._flags |= PResizeInc | PBaseSize;
+            (hints._width_inc, hints._height_inc) = (width as c_int, height as c_int);
+            (hints._base_width, hints._base_height) = (base.0 as c_int, base.1 as c_int);
+        }
+
+        unsafe {
+            XSetWMNormalHints(self.display, self.window, &hints);
+        }
+    }
+
+    /// Get the x11 event mask of the event categories received by window.
+    #[inline(always)]
+    fn get_x11_event_mask(&self) -> i64 {
+        EVENT_MASK_CATEGORIES.iter().filter(|(category, _)| self.property.event_mask.contains(*category))
+            .fold(EVENT_MASK_BASE, |mask, (_, x11_mask)| mask | x11_mask)
+    }
+
     /// Create the window according to window properties.
     #[inline(always)]
     fn create_window(&mut self){
@@ -443,28 +958,34 @@ impl<'window> X11WindowManager<'window> {
             self.window = XCreateSimpleWindow(self.display, root, self.property.position.0,self.property.position.1,
                 self.property.size.0, self.property.size.1, 0, 0, 0);
 
-            // Set window Type to normal
-            x11_change_property!(self.display, self.window, self.atoms, _NET_WM_WINDOW_TYPE, _NET_WM_WINDOW_TYPE_NORMAL);
+            // Set window type
+            self.set_x11_window_type();
 
             // Set window protocols to capture window closing
             XSetWMProtocols(self.display, self.window, &mut self.atoms.WM_DELETE_WINDOW, 1);
 
             // Allowed actions
-            x11_change_property!(self.display, self.window, self.atoms, _NET_WM_ALLOWED_ACTIONS, _NET_WM_ACTION_FULLSCREEN, _NET_WM_ACTION_MINIMIZE, _NET_WM_ACTION_CHANGE_DESKTOP,
-                _NET_WM_ACTION_CLOSE, _NET_WM_ACTION_ABOVE, _NET_WM_ACTION_BELOW);
-
-            match &self.property.fullscreen{
-                Some(_) => {
-                    // TODO: Set fullscreen according to mode.
-                    // Set as fullscreen
-                    x11_change_property!(self.display, self.window, self.atoms, _NET_WM_STATE, _NET_WM_STATE_MAXIMIZED_HORZ, _NET_WM_STATE_MAXIMIZED_VERT, _NET_WM_STATE_FULLSCREEN);
-                    self.fullscreen = true;
-                },
-                Option::None => self.fullscreen = false,     // No fullscreen mode
+            self.set_allowed_actions();
+
+            // Window icons
+            self.set_x11_icon();
+
+            // Set fullscreen, maximized, stacking and taskbar states
+            let mut states = self.get_x11_states();
+            if !states.is_empty() {
+                XChangeProperty(self.display, self.window, self.atoms._NET_WM_STATE, self.atoms.xa_atom, 32, PropModeReplace,
+                    states.as_mut_ptr() as *mut c_uchar, states.len() as c_int);
             }
+            self.fullscreen = self.property.fullscreen.is_some();
+
+            // Size constraints
+            self.set_size_hints(self.property.size);
 
             // Mask of events to receive
-            XSelectInput(self.display, self.window, EVENT_MASK);
+            XSelectInput(self.display, self.window, self.get_x11_event_mask());
+
+            // XInput2 events of pen devices
+            self.select_xinput2_events();
 
             // Flush buffer
             XFlush(self.display);
@@ -720,12 +1241,3 @@ impl<'window> X11WindowManager<'window> {
     }
     */
 }
-
-impl<'window> Drop for X11WindowManager<'window> {
-    fn drop(&mut self) {
-        unsafe {
-            // Close display server connection.
-            XCloseDisplay(self.display);
-        }
-    }
-}
\ No newline at end of file
@@ -0,0 +1,121 @@
+//! Contains XSETTINGS desktop settings reading.
+//!
+//! Reference(s)
+//! <https://specifications.freedesktop.org/xsettings-spec/0.5/>
+
+use std::ffi::{c_int, c_long, c_ulong, c_char, c_void, CString};
+use std::ptr::null_mut;
+
+use super::cbind::functs::{XOpenDisplay, XCloseDisplay, XInternAtom, XGetSelectionOwner, XGetWindowProperty, XFree};
+use super::cbind::structs::Atom;
+
+/// Selection owned by the XSETTINGS manager of screen 0.
+const XSETTINGS_SELECTION : &str = "_XSETTINGS_S0";
+
+/// Property containing the settings on the manager window.
+const XSETTINGS_PROPERTY : &str = "_XSETTINGS_SETTINGS";
+
+/// Setting type of an integer.
+const XSETTINGS_TYPE_INTEGER : u8 = 0;
+
+/// Setting type of a string.
+const XSETTINGS_TYPE_STRING : u8 = 1;
+
+/// Setting type of a color.
+const XSETTINGS_TYPE_COLOR : u8 = 2;
+
+/// Get the XSETTINGS property data, to read settings with [parse_xsettings_int].
+///
+/// Returns Some(data) or None if no XSETTINGS manager is found.
+pub(crate) fn get_x11_xsettings_data() -> Option<Vec<u8>> {
+    unsafe {
+        let display = XOpenDisplay(std::ptr::null());
+        if display.is_null() {
+            return None;
+        }
+
+        let selection = CString::new(XSETTINGS_SELECTION).unwrap();
+        let property = CString::new(XSETTINGS_PROPERTY).unwrap();
+        let selection = XInternAtom(display, selection.as_ptr(), true);
+        let property = XInternAtom(display, property.as_ptr(), true);
+
+        let mut data = None;
+
+        if selection != 0 && property != 0 {
+            let owner = XGetSelectionOwner(display, selection);
+
+            if !owner.is_null() {
+                // Used to capture XGetWindowProperty
+                let mut actual_type_return : Atom = 0;
+                let mut actual_format_return : c_int = 0;
+                let mut nitems_return : c_ulong = 0;
+                let mut bytes_after_return : c_ulong = 0;
+                let mut prop_return : *mut c_char = null_mut();
+
+                XGetWindowProperty(display, owner, property, 0, i32::MAX as c_long, false, property, &mut actual_type_return,
+                    &mut actual_format_return, &mut nitems_return, &mut bytes_after_return, &mut prop_return);
+
+                if !prop_return.is_null() {
+                    if actual_format_return == 8 {
+                        data = Some(core::slice::from_raw_parts(prop_return as *const u8, nitems_return as usize).to_vec());
+                    }
+                    XFree(prop_return as *mut c_void);
+                }
+            }
+        }
+
+        XCloseDisplay(display);
+
+        data
+    }
+}
+
+/// Parse XSETTINGS property data and return the integer value of setting name.
+///
+/// Returns Some(value) if the setting is found, None if not found or data is truncated or malformed.
+pub fn parse_xsettings_int(data : &[u8], name : &str) -> Option<i32> {
+    // Byte order of the data. 0 is LSBFirst, 1 is MSBFirst.
+    let msb = *data.first()? == 1;
+
+    let read_u16 = |offset : usize| -> Option<u16> {
+        let bytes : [u8; 2] = data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if msb { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) })
+    };
+
+    let read_u32 = |offset : usize| -> Option<u32> {
+        let bytes : [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if msb { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) })
+    };
+
+    // Header : byte-order(1), unused(3), serial(4), count(4)
+    let count = read_u32(8)?;
+    let mut offset : usize = 12;
+
+    for _ in 0..count {
+        // Setting header : type(1), unused(1), name-len(2), name(padded to 4), last-change-serial(4)
+        let setting_type = *data.get(offset)?;
+        let name_len = read_u16(offset + 2)? as usize;
+        let setting_name = data.get(offset + 4..offset + 4 + name_len)?;
+        offset += 4 + pad_to_4(name_len) + 4;
+
+        match setting_type {
+            XSETTINGS_TYPE_INTEGER => {
+                if setting_name == name.as_bytes() {
+                    return Some(read_u32(offset)? as i32);
+                }
+                offset += 4;
+            },
+            XSETTINGS_TYPE_STRING => offset += 4 + pad_to_4(read_u32(offset)? as usize),
+            XSETTINGS_TYPE_COLOR => offset += 8,
+            _ => return None,   // Unknown type, can't go further.
+        }
+    }
+
+    None
+}
+
+/// Returns length padded to a multiple of 4.
+#[inline(always)]
+fn pad_to_4(length : usize) -> usize {
+    (length + 3) & !3
+}
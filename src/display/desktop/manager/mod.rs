@@ -4,7 +4,18 @@ use cfg_boost::target_cfg;
 
 use crate::error::StudioError;
 
-use super::{property::{WindowProperty, SubWindowOption, WindowPositionOption, FullScreenMode, PointerMode, KeyboardMode, WindowEventWaitMode}, event::{Event}, Window};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use super::application::WindowId;
+use super::gamepad::GamepadList;
+use super::icon::WindowIcon;
+use super::proxy::EventProxy;
+use std::any::{Any, TypeId};
+use super::region::DamageRegion;
+use super::source::FdSourceList;
+use super::timer::TimerList;
+use super::{property::{WindowProperty, SubWindowOption, WindowPositionOption, FullScreenMode, PointerMode, KeyboardMode, WindowEventWaitMode, WindowType}, event::{Event, EventCategoryMask}, Window};
 
 /// Enumeration of [Display server](https://en.wikipedia.org/wiki/Windowing_system#Display_server)
 /// and/or [Window manager](https://en.wikipedia.org/wiki/Window_manager) providers.
@@ -32,10 +43,37 @@ target_cfg! {
         // Linux implementation of WindowManager trait
         pub mod linux;
         pub type WindowManagerType<'window> = super::manager::linux::LinuxWindowManager<'window>;
+        pub type ApplicationManagerType = super::manager::linux::LinuxApplicationManager;
+        pub(crate) type EventWakerType = super::manager::linux::waker::EventFdWaker;
+        #[cfg(feature = "async")]
+        pub(crate) type FdWaiterType = super::manager::linux::waiter::FdWaiter;
     },
 
 }
 
+/// Manager that share one display connection between the [Window] of an [Application](super::application::Application)
+/// and route display events to them.
+pub trait ApplicationManager {
+    /// Create a new ApplicationManager instance, opening the display connection.
+    fn new() -> Result<Self, StudioError> where Self : Sized;
+
+    /// Get the window provider id
+    fn get_window_provider(&self) -> WindowProvider;
+
+    /// Create a window manager using the shared display connection.
+    fn create_window_manager<'window>(&self) -> WindowManagerType<'window>;
+
+    /// Read queued display events and push the events of windows into events. If wait is true and no display event
+    /// is queued, wait until a display, gamepad or user event is available or until timeout expires (None wait forever).
+    fn dispatch_events<'window>(&mut self, windows : &mut [(WindowId, Window<'window>)], events : &mut VecDeque<(WindowId, Event)>, wait : bool, timeout : Option<Duration>);
+
+    /// Get immutable reference to the connected gamepads.
+    fn get_gamepads(&self) -> &GamepadList;
+
+    /// Get mutable reference to the connected gamepads.
+    fn get_gamepads_mut(&mut self) -> &mut GamepadList;
+}
+
 /// Manager that manage the [Window].
 /// 
 /// Each property set returns either true or false depending if window need to be recreate.
@@ -70,13 +108,50 @@ pub trait WindowManager<'window> {
     /// Get the OS Window manager window handle.
     fn get_window_handle(&self) -> Option<*const usize>;
 
-    /// Push an event that will be poll during poll_event.
+    /// Push an event that will be poll during poll_event. Pushed events are polled in the order they were pushed.
     fn push_event(&self, event: Event);
 
+    /// Get immutable reference to the connected gamepads.
+    fn get_gamepads(&self) -> &GamepadList;
+
+    /// Get mutable reference to the connected gamepads.
+    fn get_gamepads_mut(&mut self) -> &mut GamepadList;
+
+    /// Create a new [EventProxy] posting user events to this window.
+    fn create_proxy(&self) -> EventProxy;
+
+    /// Take the payload of type_id posted with key by an [EventProxy] of this window.
+    fn take_user_payload(&self, key : u64, type_id : TypeId) -> Option<Box<dyn Any + Send>>;
+
+    /// Get immutable reference to the timers of window.
+    fn get_timers(&self) -> &TimerList;
+
+    /// Get mutable reference to the timers of window.
+    fn get_timers_mut(&mut self) -> &mut TimerList;
+
+    /// Get immutable reference to the external file descriptors watched by window.
+    fn get_fd_sources(&self) -> &FdSourceList;
+
+    /// Get mutable reference to the external file descriptors watched by window.
+    fn get_fd_sources_mut(&mut self) -> &mut FdSourceList;
+
+    /// Get the damaged region of the last exposed event.
+    fn get_damage_region(&self) -> &DamageRegion;
+
+    /// Request a redraw event when no other event is pending.
+    fn request_redraw(&mut self);
+
     target_cfg! {
         linux => {
             /// Get the OS Window manager display handle.
             fn get_display_handle(&self) -> *const usize;
+
+            /// Get the file descriptors readable when an event is available : display connection, gamepads and user events.
+            fn get_event_fds(&self) -> Vec<std::ffi::c_int>;
+
+            /// Returns true if events were already read from the display connection, which doesn't make its file
+            /// descriptor readable.
+            fn has_queued_events(&self) -> bool;
         }
     }
 
@@ -95,6 +170,42 @@ pub trait WindowManager<'window> {
     /// Set window size.
     fn set_size(&mut self, size : &(u32,u32)) -> bool;
 
+    /// Set the window minimum size.
+    fn set_min_size(&mut self, min_size : Option<(u32, u32)>) -> bool;
+
+    /// Set the window maximum size.
+    fn set_max_size(&mut self, max_size : Option<(u32, u32)>) -> bool;
+
+    /// Set the window aspect ratio.
+    fn set_aspect_ratio(&mut self, aspect_ratio : Option<(u32, u32)>) -> bool;
+
+    /// Set the window resize increment.
+    fn set_resize_increment(&mut self, resize_increment : Option<(u32, u32)>) -> bool;
+
+    /// Set if the window can be resized by user.
+    fn set_resizable(&mut self, resizable : bool) -> bool;
+
+    /// Set the window icons.
+    fn set_icon(&mut self, icon : &[WindowIcon]) -> bool;
+
+    /// Set the window type.
+    fn set_window_type(&mut self, window_type : WindowType) -> bool;
+
+    /// Set if the window is kept above other windows.
+    fn set_above(&mut self, above : bool) -> bool;
+
+    /// Set if the window is kept below other windows.
+    fn set_below(&mut self, below : bool) -> bool;
+
+    /// Set if the window is shown on all workspaces.
+    fn set_sticky(&mut self, sticky : bool) -> bool;
+
+    /// Set if the window is excluded from taskbar.
+    fn set_skip_taskbar(&mut self, skip_taskbar : bool) -> bool;
+
+    /// Set if the window is excluded from pager.
+    fn set_skip_pager(&mut self, skip_pager : bool) -> bool;
+
     /// Show window decoration such as title bar, buttons, etc...
     fn show_decoration(&mut self) -> bool;
 
@@ -110,6 +221,12 @@ pub trait WindowManager<'window> {
     /// Set the [WindowEventWaitMode].
     fn set_event_wait_mode(&mut self, mode : WindowEventWaitMode) -> bool;
 
+    /// Set the categories of events received by window.
+    fn set_event_mask(&mut self, mask : EventCategoryMask) -> bool;
+
+    /// Set the categories of events coalesced.
+    fn set_event_coalescing(&mut self, mask : EventCategoryMask) -> bool;
+
     /// Setting window fullscreen trigger 
     /// window recreate.
     fn set_fullscreen(&mut self, fsmode : FullScreenMode) -> bool;
@@ -171,4 +288,7 @@ pub trait WindowManager<'window> {
     /// Return true if state indicate that numlock was enabled.
     fn is_numlock_on(state : u32) -> bool;
 
+    /// Return the state of a key pressed with the [KeyModifier](super::event::keyboard::KeyModifier) flags.
+    fn get_key_state(modifiers : u8) -> u32;
+
 }
\ No newline at end of file
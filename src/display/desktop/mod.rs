@@ -8,12 +8,51 @@ pub mod error;
 // Hardware screen list
 pub mod screen;
 
+// Hardware input device list
+pub mod device;
+
+// Gamepads and joysticks
+pub mod gamepad;
+
 // Window events
 pub mod event;
 
+// Pointer gestures recognition
+pub mod gesture;
+
+// Post user events from other threads
+pub mod proxy;
+
+// Timers of windows
+pub mod timer;
+
+// Async stream of window events
+#[cfg(feature = "async")]
+pub mod stream;
+
+// External file descriptors watched by windows
+pub mod source;
+
+// Damaged region of windows
+pub mod region;
+
+// Record and replay window events
+pub mod record;
+
+// Window geometry and state persistence
+pub mod state;
+
+// Window icons
+pub mod icon;
+
 // Window managers
 pub mod manager;
 
+// Application sharing a display connection between windows
+pub mod application;
+
+pub use application::Application as Application;
+
 // Window
 #[doc(hidden)]
 pub mod window;
@@ -1,5 +1,10 @@
+use std::time::{Duration, Instant};
+
 use crate::{display::desktop::screen::Screen};
 
+use super::event::EventCategoryMask;
+use super::gesture::GestureSettings;
+use super::icon::WindowIcon;
 use super::window::Window;
 
  /// Minimum [Window] width allowed.
@@ -23,6 +28,7 @@ pub const DEFAULT_HEIGHT : u32 = 480;
 
 /// [Window] event wait mode.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindowEventWaitMode {
     /// This mode is more suitable for games and interfaces that must refresh
     /// often. This mode is MANDATORY if you want to achieve [IMMEDIATE](https://en.wikipedia.org/wiki/Immediate_mode_GUI) user interfaces.
@@ -32,10 +38,34 @@ pub enum WindowEventWaitMode {
     /// computing power and is suited for applications. This mode is MANDATORY if you want to 
     /// achieve [RETAINED](https://en.wikipedia.org/wiki/Retained_mode) user interfaces.
     AlwaysWait,
+
+    /// This mode will lock the window thread until an event occurred or until the duration elapsed
+    /// since poll_event was called, returning [Event::None](super::event::Event::None) on timeout. Suited for
+    /// animations that idle when static.
+    WaitTimeout(Duration),
+
+    /// This mode will lock the window thread until an event occurred or until the instant is reached,
+    /// returning [Event::None](super::event::Event::None) on timeout.
+    /// 
+    /// Can't be serialized since an instant is only meaningful within the process.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    WaitUntil(Instant),
+}
+
+impl WindowEventWaitMode {
+    /// Returns the instant at which a wait started now ends. None if the wait doesn't end or if the mode never wait.
+    pub(crate) fn get_deadline(&self) -> Option<Instant> {
+        match self {
+            WindowEventWaitMode::NeverWait | WindowEventWaitMode::AlwaysWait => None,
+            WindowEventWaitMode::WaitTimeout(timeout) => Some(Instant::now() + *timeout),
+            WindowEventWaitMode::WaitUntil(deadline) => Some(*deadline),
+        }
+    }
 }
 
 /// [Window] fullscreen mode enumeration.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FullScreenMode {
 
     /// Window will be set fullscreen in the current screen this window belong to.
@@ -53,6 +83,7 @@ pub enum FullScreenMode {
 
 /// Enumeration of possible window positions when setting position.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindowPositionOption {
     /// Position window on desktop from an absolute pair of x,y coordinates.
     Desktop((i32, i32)),
@@ -71,6 +102,7 @@ pub enum WindowPositionOption {
 }
 
 /// Contains keyboard properties that can be set.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyboardPropertySet {
 
     /// Set [KeyboardMode].
@@ -79,6 +111,7 @@ pub enum KeyboardPropertySet {
 }
 
 /// [Window](super::window::Window) pointer properties such as mode, position, etc.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerPropertySet {
 
     /// [PointerMode] used for [EventMouse](super::event::EventMouse) events.
@@ -102,6 +135,7 @@ pub enum PointerPropertySet {
 
 /// Enumeration of possible [Window](super::window::Window) pointer mode.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerMode {
     /// [EventMouse](super::event::EventMouse) events will give the (x,y) location of the cursor on the window. 
     /// 
@@ -116,6 +150,7 @@ pub enum PointerMode {
 
 /// Enumeration of possible sub window display options.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubWindowOption {
 
     /// Child is showed as normal window.
@@ -141,6 +176,14 @@ pub enum WindowPropertySet<'window> {
     /// Set the window event wait mode.
     SetEventWaitMode(WindowEventWaitMode),
 
+    /// Set the categories of events received by window. Events of other categories are not requested from the
+    /// display server when possible and are discarded otherwise.
+    SetEventMask(EventCategoryMask),
+
+    /// Set the categories of events merged when consecutive. Only [EventCategoryMask::POINTER_MOTION],
+    /// [EventCategoryMask::CONFIGURE] and [EventCategoryMask::EXPOSE] can be coalesced.
+    SetEventCoalescing(EventCategoryMask),
+
     /// Remove window from parent, making it a parentless window.
     RemoveParent,
 
@@ -150,9 +193,45 @@ pub enum WindowPropertySet<'window> {
     /// Set window position.
     Position(WindowPositionOption),
 
-    /// Set window size.
+    /// Set window size. Size is constrained by the minimum and maximum sizes, aspect ratio and resize increment.
     Size((u32, u32)),
 
+    /// Set the minimum (width, height) of window. None removes the minimum.
+    MinSize(Option<(u32, u32)>),
+
+    /// Set the maximum (width, height) of window. None removes the maximum.
+    MaxSize(Option<(u32, u32)>),
+
+    /// Set the aspect ratio (width, height) of window, such as (16, 9). None removes the aspect ratio.
+    AspectRatio(Option<(u32, u32)>),
+
+    /// Set the (width, height) steps window is resized by, from its minimum size. None removes the increment.
+    ResizeIncrement(Option<(u32, u32)>),
+
+    /// Set if window can be resized and maximized by user. A non-resizable window keeps its size.
+    Resizable(bool),
+
+    /// Set window icons, of different sizes if possible. An empty list removes the window icons.
+    Icon(Vec<WindowIcon>),
+
+    /// Set window type. Tooltips and popup menus are not managed by window manager.
+    Type(WindowType),
+
+    /// Keep window above other windows. Removes the below state.
+    Above(bool),
+
+    /// Keep window below other windows. Removes the above state.
+    Below(bool),
+
+    /// Show window on all workspaces.
+    Sticky(bool),
+
+    /// Exclude window from taskbar.
+    SkipTaskbar(bool),
+
+    /// Exclude window from pager and workspace switcher.
+    SkipPager(bool),
+
     /// Show window decoration
     ShowDecoration,
 
@@ -176,6 +255,11 @@ pub enum WindowPropertySet<'window> {
 
     /// Set window pointer properties.
     Pointer(PointerPropertySet),
+
+    /// Enable [EventGesture](super::event::gesture::EventGesture) recognition with [GestureSettings]. None disable gestures.
+    /// 
+    /// Use [GestureSettings::from_desktop()] to get thresholds of the desktop.
+    Gesture(Option<GestureSettings>),
 }   
 
 
@@ -194,6 +278,12 @@ pub struct WindowProperty<'window> {
     /// Window event wait mode
     pub wait_mode : WindowEventWaitMode,
 
+    /// Categories of events received by window
+    pub event_mask : EventCategoryMask,
+
+    /// Categories of events coalesced
+    pub coalescing : EventCategoryMask,
+
     /// Window title
     pub title : String,
 
@@ -206,6 +296,48 @@ pub struct WindowProperty<'window> {
     /// Size of window as pair of u32 (width, height).
     pub size : (u32, u32),
 
+    /// Absolute position of window when neither maximized nor fullscreen, kept while maximized or fullscreen.
+    pub normal_position : (i32, i32),
+
+    /// Size of window when neither maximized nor fullscreen, kept while maximized or fullscreen.
+    pub normal_size : (u32, u32),
+
+    /// Minimum size of window. None if no minimum.
+    pub min_size : Option<(u32, u32)>,
+
+    /// Maximum size of window. None if no maximum.
+    pub max_size : Option<(u32, u32)>,
+
+    /// Aspect ratio (width, height) of window. None if no aspect ratio.
+    pub aspect_ratio : Option<(u32, u32)>,
+
+    /// Resize increment (width, height) of window. None if no increment.
+    pub resize_increment : Option<(u32, u32)>,
+
+    /// Window can be resized and maximized by user.
+    pub resizable : bool,
+
+    /// Icons of window. Empty if no icon.
+    pub icon : Vec<WindowIcon>,
+
+    /// Type of window.
+    pub window_type : WindowType,
+
+    /// Window is kept above other windows.
+    pub above : bool,
+
+    /// Window is kept below other windows.
+    pub below : bool,
+
+    /// Window is shown on all workspaces.
+    pub sticky : bool,
+
+    /// Window is excluded from taskbar.
+    pub skip_taskbar : bool,
+
+    /// Window is excluded from pager.
+    pub skip_pager : bool,
+
     /// Window center,
     pub center : (i32, i32),
 
@@ -237,6 +369,20 @@ impl<'window> WindowProperty<'window>{
             title: String::new(), 
             position : (0,0), 
             size: (DEFAULT_WIDTH, DEFAULT_HEIGHT), 
+            normal_position: (0,0),
+            normal_size: (DEFAULT_WIDTH, DEFAULT_HEIGHT),
+            min_size: Option::None,
+            max_size: Option::None,
+            aspect_ratio: Option::None,
+            resize_increment: Option::None,
+            resizable: true,
+            icon: Vec::new(),
+            window_type: WindowType::Normal,
+            above: false,
+            below: false,
+            sticky: false,
+            skip_taskbar: false,
+            skip_pager: false,
             center: (DEFAULT_WIDTH as i32 / 2, DEFAULT_HEIGHT as i32 / 2), 
             minimized: false, 
             maximized: false, 
@@ -250,10 +396,17 @@ impl<'window> WindowProperty<'window>{
             relative_position: WindowPositionOption::Desktop((0,0)),
             parent: None,
             wait_mode: WindowEventWaitMode::NeverWait,  // Never wait by default to prevent new user confusion.
+            event_mask: EventCategoryMask::ALL,
+            coalescing: EventCategoryMask::NONE,
         }
     }
     
 
+    /// Returns true if window is neither maximized nor fullscreen, its geometry being its normal geometry.
+    pub fn is_normal(&self) -> bool {
+        !self.maximized && self.fullscreen.is_none()
+    }
+
     /// Returns true if size if within MIN and MAX.
     pub fn is_size_within_boundaries(size : &(u32, u32)) -> bool {
 
@@ -267,10 +420,71 @@ impl<'window> WindowProperty<'window>{
 
     }
 
+    /// Get a size constrained by the aspect ratio, resize increment, minimum and maximum sizes of window.
+    /// 
+    /// Minimum and maximum sizes take precedence over aspect ratio and increment.
+    pub fn get_constrained_size(&self, size : (u32, u32)) -> (u32, u32) {
+        let mut size = size;
+
+        if let Some((width, height)) = self.aspect_ratio {     // Keep width, adjust height
+            size.1 = (size.0 as u64 * height as u64 / width as u64).clamp(WINDOW_MIN_HEIGHT as u64, WINDOW_MAX_HEIGHT as u64) as u32;
+        }
+
+        if let Some((width, height)) = self.resize_increment {
+            let base = self.min_size.unwrap_or((0, 0));
+            size.0 = base.0 + size.0.saturating_sub(base.0) / width * width;
+            size.1 = base.1 + size.1.saturating_sub(base.1) / height * height;
+        }
+
+        if let Some(max) = self.max_size {
+            size = (size.0.min(max.0), size.1.min(max.1));
+        }
+
+        if let Some(min) = self.min_size {
+            size = (size.0.max(min.0), size.1.max(min.1));
+        }
+
+        (size.0.clamp(WINDOW_MIN_WIDTH, WINDOW_MAX_WIDTH), size.1.clamp(WINDOW_MIN_HEIGHT, WINDOW_MAX_HEIGHT))
+    }
+
+}
+
+/// Semantic type of [Window] used by window manager to place, decorate and stack it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowType {
+    /// Normal top-level window.
+    Normal,
+
+    /// Dialog window, usually transient for a parent window.
+    Dialog,
+
+    /// Small persistent utility window, such as a palette or toolbox.
+    Utility,
+
+    /// Splash screen shown while application is starting.
+    Splash,
+
+    /// Tooltip shown over the pointer. Not managed by window manager.
+    Tooltip,
+
+    /// Popup menu opened from a menu bar or by a click. Not managed by window manager.
+    PopupMenu,
+
+    /// Notification bubble.
+    Notification,
+}
+
+impl WindowType {
+    /// Returns true if window type must not be managed by window manager.
+    pub fn is_unmanaged(&self) -> bool {
+        matches!(self, WindowType::Tooltip | WindowType::PopupMenu)
+    }
 }
 
 /// Enumeration of possible keyboard mode for input.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyboardMode {
     /// Direct mode is faster and more suitable for games. Provides [EventKeyboard::KeyUp](super::event::keyboard::EventKeyboard)
     /// and [EventKeyboard::KeyDown](super::event::keyboard::EventKeyboard) and disable auto-repeat.
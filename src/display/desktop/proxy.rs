@@ -0,0 +1,136 @@
+//! Post user events to a window or application from another thread.
+//!
+//! An [EventProxy] is created with [Window::create_proxy](super::Window::create_proxy). Posted values are received
+//! as [Event::User](super::event::Event::User) and wake a poll_event waiting with
+//! [WindowEventWaitMode::AlwaysWait](super::property::WindowEventWaitMode::AlwaysWait), including the
+//! [Application](super::Application) owning the window.
+//!
+//! Since [Event](super::event::Event) is `Copy`, the u64 of [Event::User](super::event::Event::User) is a key. It is
+//! either chosen by the user with [EventProxy::post] and refers to storage the user owns, or returned by
+//! [EventProxy::post_payload] and used to take the posted payload with
+//! [Window::take_user_payload](super::Window::take_user_payload). Keys of both methods aren't distinct, a window
+//! should only use one of them.
+
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, VecDeque};
+use std::ffi::c_int;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::display::DisplayError;
+use crate::error::StudioError;
+
+use super::manager::EventWakerType;
+
+/// Queue of user events shared between the proxies and the receiver.
+struct UserEventQueue {
+    /// Values posted and not received yet.
+    values : Mutex<VecDeque<u64>>,
+
+    /// Payloads posted and not taken yet, by key.
+    payloads : Mutex<HashMap<u64, Box<dyn Any + Send>>>,
+
+    /// Key of the next payload posted.
+    next_key : AtomicU64,
+
+    /// Wake the receiver waiting for events.
+    waker : EventWakerType,
+
+    /// Receiver was dropped.
+    closed : AtomicBool,
+}
+
+/// Cloneable handle that can be sent to other threads to post user events.
+#[derive(Clone)]
+pub struct EventProxy {
+    queue : Arc<UserEventQueue>,
+}
+
+impl EventProxy {
+    /// Post a user event received as [Event::User](super::event::Event::User)(value) and wake the receiver
+    /// if it waits for events. Value is a key into storage the user owns.
+    ///
+    /// Returns Ok(()) on success, Err([StudioError]) if the window or application receiving events was dropped.
+    pub fn post(&self, value : u64) -> Result<(), StudioError> {
+        if self.queue.closed.load(Ordering::Acquire) {
+            return Err(StudioError::Display(DisplayError::EventProxyClosed));
+        }
+
+        self.queue.values.lock().unwrap().push_back(value);
+        self.queue.waker.wake();
+        Ok(())
+    }
+
+    /// Post a typed payload received as [Event::User](super::event::Event::User)(key). The payload is taken
+    /// with [Window::take_user_payload](super::Window::take_user_payload)(key). Payloads never taken are dropped
+    /// with the window.
+    ///
+    /// Returns Ok(key) on success, Err([StudioError]) if the window or application receiving events was dropped.
+    pub fn post_payload<T : Any + Send>(&self, payload : T) -> Result<u64, StudioError> {
+        if self.queue.closed.load(Ordering::Acquire) {
+            return Err(StudioError::Display(DisplayError::EventProxyClosed));
+        }
+
+        let key = self.queue.next_key.fetch_add(1, Ordering::Relaxed);
+        self.queue.payloads.lock().unwrap().insert(key, Box::new(payload));
+        self.post(key)?;
+        Ok(key)
+    }
+}
+
+/// Receiving side of user events owned by a window or application manager. Proxies fail to post once dropped.
+pub(crate) struct UserEventReceiver {
+    queue : Arc<UserEventQueue>,
+}
+
+impl UserEventReceiver {
+    /// Create a new [UserEventReceiver] without proxy.
+    pub(crate) fn new() -> UserEventReceiver {
+        UserEventReceiver { queue: Arc::new(UserEventQueue { values: Mutex::new(VecDeque::new()),
+            payloads: Mutex::new(HashMap::new()), next_key: AtomicU64::new(0), waker: EventWakerType::new(),
+            closed: AtomicBool::new(false) }) }
+    }
+
+    /// Create a new [EventProxy] posting to this receiver.
+    pub(crate) fn create_proxy(&self) -> EventProxy {
+        EventProxy { queue: self.queue.clone() }
+    }
+
+    /// Get the file descriptor readable when a user event is posted.
+    pub(crate) fn get_fd(&self) -> Option<c_int> {
+        self.queue.waker.get_fd()
+    }
+
+    /// Pop the next user event value.
+    ///
+    /// Returns Some(u64) or None if no user event.
+    pub(crate) fn pop(&self) -> Option<u64> {
+        let mut values = self.queue.values.lock().unwrap();
+        let value = values.pop_front();
+
+        if values.is_empty() {  // Cleared while locked so that a value posted after is never missed.
+            self.queue.waker.clear();
+        }
+
+        value
+    }
+
+    /// Take the payload posted with key if it has type_id. A payload of another type is kept.
+    ///
+    /// Returns Some(payload) or None if no payload of that type with key.
+    pub(crate) fn take_payload(&self, key : u64, type_id : TypeId) -> Option<Box<dyn Any + Send>> {
+        let mut payloads = self.queue.payloads.lock().unwrap();
+
+        if (**payloads.get(&key)?).type_id() == type_id {
+            payloads.remove(&key)
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for UserEventReceiver {
+    fn drop(&mut self) {
+        self.queue.closed.store(true, Ordering::Release);
+    }
+}
@@ -0,0 +1,659 @@
+//! Record window events and replay them.
+//!
+//! An [EventRecorder] writes each [Event] with the time elapsed since the recording started into a versioned
+//! binary format. An [EventReplayer] reads a recording and gives its events back at original or accelerated
+//! speed with [EventReplayer::poll_event], like [Window::poll_event](super::Window::poll_event), so that the same
+//! handling code can be used. Events can also be replayed through a window with [EventReplayer::push_events], which
+//! pushes due events with [Window::push_event](super::Window::push_event) in recording order.
+//!
+//! Keycodes and key states are recorded as given by the display server, recordings are meant to be replayed on the
+//! same platform.
+//!
+//! # Format
+//! Header of magic `STEV` followed by version as little endian u16, then one record per event made of the elapsed
+//! microseconds as little endian u64 followed by the encoded event.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::display::DisplayError;
+use crate::error::StudioError;
+
+use super::event::{Event, EventSource};
+use super::event::gamepad::EventGamepad;
+use super::event::gesture::EventGesture;
+use super::event::keyboard::{EventKeyboard, Key};
+use super::event::pen::{EventPen, PenState, PenTool};
+use super::event::pointer::{EventPointer, PointerButton};
+use super::event::window::EventWindow;
+use super::gamepad::mapping::{GamepadAxis, GamepadButton};
+use super::source::FdReadiness;
+use super::timer::TimerId;
+use super::window::Window;
+
+/// Magic bytes starting a recording.
+pub const RECORDING_MAGIC : [u8; 4] = *b"STEV";
+
+/// Version of the recording format written by [EventRecorder].
+pub const RECORDING_VERSION : u16 = 1;
+
+/// Gamepad buttons in encoding order.
+const GAMEPAD_BUTTONS : [GamepadButton; 21] = [GamepadButton::A, GamepadButton::B, GamepadButton::X, GamepadButton::Y,
+    GamepadButton::Back, GamepadButton::Guide, GamepadButton::Start, GamepadButton::LeftStick, GamepadButton::RightStick,
+    GamepadButton::LeftShoulder, GamepadButton::RightShoulder, GamepadButton::DPadUp, GamepadButton::DPadDown,
+    GamepadButton::DPadLeft, GamepadButton::DPadRight, GamepadButton::Misc1, GamepadButton::Paddle1, GamepadButton::Paddle2,
+    GamepadButton::Paddle3, GamepadButton::Paddle4, GamepadButton::Touchpad];
+
+/// Gamepad axes in encoding order.
+const GAMEPAD_AXES : [GamepadAxis; 6] = [GamepadAxis::LeftX, GamepadAxis::LeftY, GamepadAxis::RightX, GamepadAxis::RightY,
+    GamepadAxis::LeftTrigger, GamepadAxis::RightTrigger];
+
+/// Event of a recording with the time elapsed since the recording started.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// Time elapsed since the recording started.
+    pub elapsed : Duration,
+
+    /// Event recorded.
+    pub event : Event,
+}
+
+/// Recorder writing events into a writer.
+///
+/// Steps :
+/// new(writer)
+/// record(event) for each event polled
+/// finish()
+pub struct EventRecorder<W : Write> {
+    /// Destination of recording.
+    writer : W,
+
+    /// Instant the recording started.
+    start : Instant,
+}
+
+impl<W : Write> EventRecorder<W> {
+    /// Create a new [EventRecorder] writing the recording header into writer.
+    ///
+    /// Returns Ok([EventRecorder]) on success, Err([StudioError]) if writer failed.
+    pub fn new(mut writer : W) -> Result<EventRecorder<W>, StudioError> {
+        let mut header = RECORDING_MAGIC.to_vec();
+        header.extend_from_slice(&RECORDING_VERSION.to_le_bytes());
+        writer.write_all(&header).map_err(|_| StudioError::Display(DisplayError::RecordingIoFailed))?;
+
+        Ok(EventRecorder { writer, start: Instant::now() })
+    }
+
+    /// Record an event with the time elapsed since the recording started. [Event::None] is not recorded.
+    ///
+    /// Returns Ok(()) on success, Err([StudioError]) if writer failed.
+    pub fn record(&mut self, event : &Event) -> Result<(), StudioError> {
+        self.record_at(self.start.elapsed(), event)
+    }
+
+    /// Record an event with the given time elapsed since the recording started. [Event::None] is not recorded.
+    ///
+    /// Returns Ok(()) on success, Err([StudioError]) if writer failed.
+    pub fn record_at(&mut self, elapsed : Duration, event : &Event) -> Result<(), StudioError> {
+        if *event == Event::None {
+            return Ok(());
+        }
+
+        let mut encoder = Encoder(Vec::new());
+        encoder.u64(elapsed.as_micros().min(u64::MAX as u128) as u64);
+        encoder.event(event);
+
+        self.writer.write_all(&encoder.0).map_err(|_| StudioError::Display(DisplayError::RecordingIoFailed))
+    }
+
+    /// Flush the recording and give back the writer.
+    ///
+    /// Returns Ok(W) on success, Err([StudioError]) if writer failed.
+    pub fn finish(mut self) -> Result<W, StudioError> {
+        self.writer.flush().map_err(|_| StudioError::Display(DisplayError::RecordingIoFailed))?;
+        Ok(self.writer)
+    }
+}
+
+/// Destination of replayed events, polled in the order they were pushed.
+pub trait EventSink {
+    /// Push an event polled after the events pushed before it.
+    fn push_event(&self, event : Event);
+}
+
+impl EventSink for Window<'_> {
+    fn push_event(&self, event : Event) {
+        Window::push_event(self, event)
+    }
+}
+
+impl EventSink for RefCell<VecDeque<Event>> {
+    fn push_event(&self, event : Event) {
+        self.borrow_mut().push_back(event)
+    }
+}
+
+/// Replayer giving back the events of a recording when their time comes.
+pub struct EventReplayer {
+    /// Events not replayed yet.
+    events : VecDeque<RecordedEvent>,
+
+    /// Speed multiplier of replay.
+    speed : f64,
+
+    /// Instant the replay started. None until the first poll.
+    start : Option<Instant>,
+
+    /// Last event polled.
+    event : Event,
+}
+
+impl EventReplayer {
+    /// Create a new [EventReplayer] reading a whole recording from reader.
+    ///
+    /// Returns Ok([EventReplayer]) on success, Err([StudioError]) if reader failed, recording is invalid or
+    /// its version is not supported.
+    pub fn new<R : Read>(mut reader : R) -> Result<EventReplayer, StudioError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(|_| StudioError::Display(DisplayError::RecordingIoFailed))?;
+
+        let mut decoder = Decoder { bytes: &bytes, position: 0 };
+        if decoder.take(RECORDING_MAGIC.len()) != Some(&RECORDING_MAGIC[..]) {
+            return Err(StudioError::Display(DisplayError::RecordingInvalid));
+        }
+
+        match decoder.u16() {
+            Some(RECORDING_VERSION) => {},
+            Some(_) => return Err(StudioError::Display(DisplayError::RecordingVersionNotSupported)),
+            None => return Err(StudioError::Display(DisplayError::RecordingInvalid)),
+        }
+
+        let mut events = VecDeque::new();
+        while !decoder.is_empty() {
+            match (decoder.u64(), decoder.event()) {
+                (Some(elapsed), Some(event)) => events.push_back(RecordedEvent { elapsed: Duration::from_micros(elapsed), event }),
+                _ => return Err(StudioError::Display(DisplayError::RecordingInvalid)),
+            }
+        }
+
+        Ok(EventReplayer { events, speed: 1.0, start: None, event: Event::None })
+    }
+
+    /// Get the speed multiplier of replay.
+    pub fn get_speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Set the speed multiplier of replay. 1.0 is the original speed, 2.0 twice faster, [f64::INFINITY] without delays.
+    /// Speeds lower or equal to 0.0 are ignored.
+    pub fn set_speed(&mut self, speed : f64) {
+        if speed > 0.0 {
+            self.speed = speed;
+        }
+    }
+
+    /// Returns true if all events were replayed.
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Get the events not replayed yet.
+    pub fn get_events(&self) -> &VecDeque<RecordedEvent> {
+        &self.events
+    }
+
+    /// Pop the next event if its time came. The replay clock starts on the first call.
+    ///
+    /// Returns the next [Event] or [Event::None] if no event is due.
+    pub fn poll_event(&mut self) -> &Event {
+        let elapsed = self.start.get_or_insert_with(Instant::now).elapsed();
+
+        self.event = match self.events.front() {
+            Some(recorded) if self.get_delay(recorded.elapsed) <= elapsed => self.events.pop_front().unwrap().event,
+            _ => Event::None,
+        };
+
+        &self.event
+    }
+
+    /// Push all the events whose time came into sink, in recording order. The replay clock starts on the first call.
+    ///
+    /// Returns the count of events pushed.
+    pub fn push_events(&mut self, sink : &impl EventSink) -> usize {
+        let mut count = 0;
+        while *self.poll_event() != Event::None {
+            sink.push_event(self.event);
+            count += 1;
+        }
+        count
+    }
+
+    /// Wait until the next event is due and pop it. The replay clock starts on the first call.
+    ///
+    /// Returns Some([Event]) or None if all events were replayed.
+    pub fn wait_event(&mut self) -> Option<Event> {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let recorded = self.events.pop_front()?;
+
+        let remaining = self.get_delay(recorded.elapsed).saturating_sub(start.elapsed());
+        if !remaining.is_zero() {
+            thread::sleep(remaining);
+        }
+
+        Some(recorded.event)
+    }
+
+    /// Get the delay since the replay started of an event recorded at elapsed, according to speed.
+    fn get_delay(&self, elapsed : Duration) -> Duration {
+        Duration::try_from_secs_f64(elapsed.as_secs_f64() / self.speed).unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Little endian encoder of events.
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    fn u8(&mut self, value : u8) {
+        self.0.push(value);
+    }
+
+    fn u16(&mut self, value : u16) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value : u32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value : u64) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn i32(&mut self, value : i32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value : f64) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bool(&mut self, value : bool) {
+        self.u8(value as u8);
+    }
+
+    fn position(&mut self, position : (i32, i32)) {
+        self.i32(position.0);
+        self.i32(position.1);
+    }
+
+    fn size(&mut self, size : (u32, u32)) {
+        self.u32(size.0);
+        self.u32(size.1);
+    }
+
+    fn point(&mut self, point : (f64, f64)) {
+        self.f64(point.0);
+        self.f64(point.1);
+    }
+
+    fn source(&mut self, source : &EventSource) {
+        self.u16(source.device);
+        self.u64(source.timestamp);
+    }
+
+    fn event(&mut self, event : &Event) {
+        match event {
+            Event::None => self.u8(0),
+            Event::Window(window) => { self.u8(1); self.window(window) },
+            Event::Keyboard(keyboard, source) => { self.u8(2); self.keyboard(keyboard); self.source(source) },
+            Event::Pointer(pointer, source) => { self.u8(3); self.pointer(pointer); self.source(source) },
+            Event::Gamepad(gamepad) => { self.u8(4); self.gamepad(gamepad) },
+            Event::Pen(pen) => { self.u8(5); self.pen(pen) },
+            Event::Gesture(gesture) => { self.u8(6); self.gesture(gesture) },
+            Event::User(value) => { self.u8(7); self.u64(*value) },
+            Event::Timer(id) => { self.u8(8); self.u32(id.0) },
+            Event::FdReady(token, readiness) => {
+                self.u8(9);
+                self.u64(*token);
+                self.u8(readiness.readable as u8 | (readiness.writable as u8) << 1 | (readiness.error as u8) << 2 | (readiness.hangup as u8) << 3);
+            },
+        }
+    }
+
+    fn window(&mut self, event : &EventWindow) {
+        match event {
+            EventWindow::Exposed(position, size) => { self.u8(0); self.position(*position); self.size(*size) },
+            EventWindow::Moved(position) => { self.u8(1); self.position(*position) },
+            EventWindow::MovedResized(position, size) => { self.u8(2); self.position(*position); self.size(*size) },
+            EventWindow::Resized(size) => { self.u8(3); self.size(*size) },
+            EventWindow::Created => self.u8(4),
+            EventWindow::Shown => self.u8(5),
+            EventWindow::Hidden => self.u8(6),
+            EventWindow::Minimized => self.u8(7),
+            EventWindow::Maximized => self.u8(8),
+            EventWindow::Fullscreen => self.u8(9),
+            EventWindow::Restored => self.u8(10),
+            EventWindow::CursorEnter => self.u8(11),
+            EventWindow::CursorLeave => self.u8(12),
+            EventWindow::Focus => self.u8(13),
+            EventWindow::Blur => self.u8(14),
+            EventWindow::CloseRequest => self.u8(15),
+            EventWindow::Closed => self.u8(16),
+            EventWindow::SubWindowClosed => self.u8(17),
+            EventWindow::ModalShowed => self.u8(18),
+            EventWindow::ModalClosed => self.u8(19),
+            EventWindow::RedrawRequested => self.u8(20),
+            EventWindow::StateChanged => self.u8(21),
+        }
+    }
+
+    fn keyboard(&mut self, event : &EventKeyboard) {
+        match event {
+            EventKeyboard::KeyDown(keycode) => { self.u8(0); self.u32(*keycode) },
+            EventKeyboard::KeyUp(keycode) => { self.u8(1); self.u32(*keycode) },
+            EventKeyboard::KeyPress(key) => {
+                self.u8(2);
+                self.u32(key.state);
+                self.u32(key.keycode);
+                self.u32(key.character.map_or(u32::MAX, u32::from));
+            },
+        }
+    }
+
+    fn button(&mut self, button : &PointerButton) {
+        match button {
+            PointerButton::Other(button) => { self.u8(u8::MAX); self.u16(*button) },
+            PointerButton::LeftButton => self.u8(0),
+            PointerButton::RightButton => self.u8(1),
+            PointerButton::MiddleButton => self.u8(2),
+            PointerButton::PreviousButton => self.u8(3),
+            PointerButton::NextButton => self.u8(4),
+            PointerButton::ScrollUp => self.u8(5),
+            PointerButton::ScrollDown => self.u8(6),
+            PointerButton::ScrollLeft => self.u8(7),
+            PointerButton::ScrollRight => self.u8(8),
+        }
+    }
+
+    fn pointer(&mut self, event : &EventPointer) {
+        match event {
+            EventPointer::Moved(position) => { self.u8(0); self.position(*position) },
+            EventPointer::Acceleration(delta) => { self.u8(1); self.position(*delta) },
+            EventPointer::ButtonDown(button, position) => { self.u8(2); self.button(button); self.position(*position) },
+            EventPointer::ButtonUp(button, position) => { self.u8(3); self.button(button); self.position(*position) },
+        }
+    }
+
+    fn gamepad(&mut self, event : &EventGamepad) {
+        match event {
+            EventGamepad::Connected(id) => { self.u8(0); self.u8(*id) },
+            EventGamepad::Disconnected(id) => { self.u8(1); self.u8(*id) },
+            EventGamepad::ButtonDown(id, button) => { self.u8(2); self.u8(*id); self.u8(*button) },
+            EventGamepad::ButtonUp(id, button) => { self.u8(3); self.u8(*id); self.u8(*button) },
+            EventGamepad::Axis(id, axis, value) => { self.u8(4); self.u8(*id); self.u8(*axis); self.u16(*value as u16) },
+            EventGamepad::TriggerDown(id, axis) => { self.u8(5); self.u8(*id); self.u8(*axis) },
+            EventGamepad::TriggerUp(id, axis) => { self.u8(6); self.u8(*id); self.u8(*axis) },
+            EventGamepad::MappedButtonDown(id, button) => { self.u8(7); self.u8(*id); self.u8(*button as u8) },
+            EventGamepad::MappedButtonUp(id, button) => { self.u8(8); self.u8(*id); self.u8(*button as u8) },
+            EventGamepad::MappedAxis(id, axis, value) => { self.u8(9); self.u8(*id); self.u8(*axis as u8); self.u16(*value as u16) },
+        }
+    }
+
+    fn pen_state(&mut self, state : &PenState) {
+        self.u16(state.device);
+        self.bool(state.tool == PenTool::Eraser);
+        self.point(state.position);
+        self.f64(state.pressure);
+        self.point(state.tilt);
+        self.f64(state.rotation);
+    }
+
+    fn pen(&mut self, event : &EventPen) {
+        match event {
+            EventPen::ProximityIn(state) => { self.u8(0); self.pen_state(state) },
+            EventPen::ProximityOut(state) => { self.u8(1); self.pen_state(state) },
+            EventPen::Down(state) => { self.u8(2); self.pen_state(state) },
+            EventPen::Up(state) => { self.u8(3); self.pen_state(state) },
+            EventPen::Moved(state) => { self.u8(4); self.pen_state(state) },
+            EventPen::ButtonDown(button, state) => { self.u8(5); self.u8(*button); self.pen_state(state) },
+            EventPen::ButtonUp(button, state) => { self.u8(6); self.u8(*button); self.pen_state(state) },
+        }
+    }
+
+    fn gesture(&mut self, event : &EventGesture) {
+        match event {
+            EventGesture::Click { button, position, count } => { self.u8(0); self.button(button); self.position(*position); self.u32(*count) },
+            EventGesture::LongPress { button, position } => { self.u8(1); self.button(button); self.position(*position) },
+            EventGesture::DragStart { button, position } => { self.u8(2); self.button(button); self.position(*position) },
+            EventGesture::DragMove { button, position } => { self.u8(3); self.button(button); self.position(*position) },
+            EventGesture::DragEnd { button, position } => { self.u8(4); self.button(button); self.position(*position) },
+            EventGesture::PinchBegin { fingers, position } => { self.u8(5); self.u32(*fingers); self.point(*position) },
+            EventGesture::PinchUpdate { scale, rotation, position } => { self.u8(6); self.f64(*scale); self.f64(*rotation); self.point(*position) },
+            EventGesture::PinchEnd { scale, rotation, cancelled } => { self.u8(7); self.f64(*scale); self.f64(*rotation); self.bool(*cancelled) },
+            EventGesture::SwipeBegin { fingers, position } => { self.u8(8); self.u32(*fingers); self.point(*position) },
+            EventGesture::SwipeUpdate { fingers, delta } => { self.u8(9); self.u32(*fingers); self.point(*delta) },
+            EventGesture::SwipeEnd { fingers, cancelled } => { self.u8(10); self.u32(*fingers); self.bool(*cancelled) },
+        }
+    }
+}
+
+/// Little endian decoder of events. Each read returns None if bytes are missing or invalid.
+struct Decoder<'bytes> {
+    /// Bytes of recording.
+    bytes : &'bytes [u8],
+
+    /// Position of next read.
+    position : usize,
+}
+
+impl<'bytes> Decoder<'bytes> {
+    fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn take(&mut self, count : usize) -> Option<&'bytes [u8]> {
+        let bytes = self.bytes.get(self.position..self.position.checked_add(count)?)?;
+        self.position += count;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        Some(i32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        Some(f64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn bool(&mut self) -> Option<bool> {
+        match self.u8()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    fn position(&mut self) -> Option<(i32, i32)> {
+        Some((self.i32()?, self.i32()?))
+    }
+
+    fn size(&mut self) -> Option<(u32, u32)> {
+        Some((self.u32()?, self.u32()?))
+    }
+
+    fn point(&mut self) -> Option<(f64, f64)> {
+        Some((self.f64()?, self.f64()?))
+    }
+
+    fn source(&mut self) -> Option<EventSource> {
+        Some(EventSource::new(self.u16()?, self.u64()?))
+    }
+
+    fn event(&mut self) -> Option<Event> {
+        Some(match self.u8()? {
+            0 => Event::None,
+            1 => Event::Window(self.window()?),
+            2 => Event::Keyboard(self.keyboard()?, self.source()?),
+            3 => Event::Pointer(self.pointer()?, self.source()?),
+            4 => Event::Gamepad(self.gamepad()?),
+            5 => Event::Pen(self.pen()?),
+            6 => Event::Gesture(self.gesture()?),
+            7 => Event::User(self.u64()?),
+            8 => Event::Timer(TimerId(self.u32()?)),
+            9 => {
+                let token = self.u64()?;
+                let flags = self.u8()?;
+                Event::FdReady(token, FdReadiness { readable: flags & 1 != 0, writable: flags & 2 != 0, error: flags & 4 != 0, hangup: flags & 8 != 0 })
+            },
+            _ => return None,
+        })
+    }
+
+    fn window(&mut self) -> Option<EventWindow> {
+        Some(match self.u8()? {
+            0 => EventWindow::Exposed(self.position()?, self.size()?),
+            1 => EventWindow::Moved(self.position()?),
+            2 => EventWindow::MovedResized(self.position()?, self.size()?),
+            3 => EventWindow::Resized(self.size()?),
+            4 => EventWindow::Created,
+            5 => EventWindow::Shown,
+            6 => EventWindow::Hidden,
+            7 => EventWindow::Minimized,
+            8 => EventWindow::Maximized,
+            9 => EventWindow::Fullscreen,
+            10 => EventWindow::Restored,
+            11 => EventWindow::CursorEnter,
+            12 => EventWindow::CursorLeave,
+            13 => EventWindow::Focus,
+            14 => EventWindow::Blur,
+            15 => EventWindow::CloseRequest,
+            16 => EventWindow::Closed,
+            17 => EventWindow::SubWindowClosed,
+            18 => EventWindow::ModalShowed,
+            19 => EventWindow::ModalClosed,
+            20 => EventWindow::RedrawRequested,
+            21 => EventWindow::StateChanged,
+            _ => return None,
+        })
+    }
+
+    fn keyboard(&mut self) -> Option<EventKeyboard> {
+        Some(match self.u8()? {
+            0 => EventKeyboard::KeyDown(self.u32()?),
+            1 => EventKeyboard::KeyUp(self.u32()?),
+            2 => {
+                let (state, keycode, character) = (self.u32()?, self.u32()?, self.u32()?);
+                EventKeyboard::KeyPress(Key::new(state, keycode, if character == u32::MAX { None } else { Some(char::from_u32(character)?) }))
+            },
+            _ => return None,
+        })
+    }
+
+    fn button(&mut self) -> Option<PointerButton> {
+        Some(match self.u8()? {
+            0 => PointerButton::LeftButton,
+            1 => PointerButton::RightButton,
+            2 => PointerButton::MiddleButton,
+            3 => PointerButton::PreviousButton,
+            4 => PointerButton::NextButton,
+            5 => PointerButton::ScrollUp,
+            6 => PointerButton::ScrollDown,
+            7 => PointerButton::ScrollLeft,
+            8 => PointerButton::ScrollRight,
+            u8::MAX => PointerButton::Other(self.u16()?),
+            _ => return None,
+        })
+    }
+
+    fn pointer(&mut self) -> Option<EventPointer> {
+        Some(match self.u8()? {
+            0 => EventPointer::Moved(self.position()?),
+            1 => EventPointer::Acceleration(self.position()?),
+            2 => EventPointer::ButtonDown(self.button()?, self.position()?),
+            3 => EventPointer::ButtonUp(self.button()?, self.position()?),
+            _ => return None,
+        })
+    }
+
+    fn gamepad_button(&mut self) -> Option<GamepadButton> {
+        GAMEPAD_BUTTONS.get(self.u8()? as usize).copied()
+    }
+
+    fn gamepad_axis(&mut self) -> Option<GamepadAxis> {
+        GAMEPAD_AXES.get(self.u8()? as usize).copied()
+    }
+
+    fn gamepad(&mut self) -> Option<EventGamepad> {
+        Some(match self.u8()? {
+            0 => EventGamepad::Connected(self.u8()?),
+            1 => EventGamepad::Disconnected(self.u8()?),
+            2 => EventGamepad::ButtonDown(self.u8()?, self.u8()?),
+            3 => EventGamepad::ButtonUp(self.u8()?, self.u8()?),
+            4 => EventGamepad::Axis(self.u8()?, self.u8()?, self.u16()? as i16),
+            5 => EventGamepad::TriggerDown(self.u8()?, self.u8()?),
+            6 => EventGamepad::TriggerUp(self.u8()?, self.u8()?),
+            7 => EventGamepad::MappedButtonDown(self.u8()?, self.gamepad_button()?),
+            8 => EventGamepad::MappedButtonUp(self.u8()?, self.gamepad_button()?),
+            9 => EventGamepad::MappedAxis(self.u8()?, self.gamepad_axis()?, self.u16()? as i16),
+            _ => return None,
+        })
+    }
+
+    fn pen_state(&mut self) -> Option<PenState> {
+        let mut state = PenState::new(self.u16()?, if self.bool()? { PenTool::Eraser } else { PenTool::Pen });
+        state.position = self.point()?;
+        state.pressure = self.f64()?;
+        state.tilt = self.point()?;
+        state.rotation = self.f64()?;
+        Some(state)
+    }
+
+    fn pen(&mut self) -> Option<EventPen> {
+        Some(match self.u8()? {
+            0 => EventPen::ProximityIn(self.pen_state()?),
+            1 => EventPen::ProximityOut(self.pen_state()?),
+            2 => EventPen::Down(self.pen_state()?),
+            3 => EventPen::Up(self.pen_state()?),
+            4 => EventPen::Moved(self.pen_state()?),
+            5 => EventPen::ButtonDown(self.u8()?, self.pen_state()?),
+            6 => EventPen::ButtonUp(self.u8()?, self.pen_state()?),
+            _ => return None,
+        })
+    }
+
+    fn gesture(&mut self) -> Option<EventGesture> {
+        Some(match self.u8()? {
+            0 => EventGesture::Click { button: self.button()?, position: self.position()?, count: self.u32()? },
+            1 => EventGesture::LongPress { button: self.button()?, position: self.position()? },
+            2 => EventGesture::DragStart { button: self.button()?, position: self.position()? },
+            3 => EventGesture::DragMove { button: self.button()?, position: self.position()? },
+            4 => EventGesture::DragEnd { button: self.button()?, position: self.position()? },
+            5 => EventGesture::PinchBegin { fingers: self.u32()?, position: self.point()? },
+            6 => EventGesture::PinchUpdate { scale: self.f64()?, rotation: self.f64()?, position: self.point()? },
+            7 => EventGesture::PinchEnd { scale: self.f64()?, rotation: self.f64()?, cancelled: self.bool()? },
+            8 => EventGesture::SwipeBegin { fingers: self.u32()?, position: self.point()? },
+            9 => EventGesture::SwipeUpdate { fingers: self.u32()?, delta: self.point()? },
+            10 => EventGesture::SwipeEnd { fingers: self.u32()?, cancelled: self.bool()? },
+            _ => return None,
+        })
+    }
+}
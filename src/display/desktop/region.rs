@@ -0,0 +1,78 @@
+//! Damaged region of a window accumulated from exposures.
+//!
+//! Exposures of a series are accumulated into a [DamageRegion] and delivered once as
+//! [EventWindow::Exposed](super::event::window::EventWindow::Exposed) with the bounding box of the region. The
+//! rectangles of the region are read with [Window::get_damage_region](super::Window::get_damage_region).
+
+/// Rectangle as position (x, y) and size (width, height).
+pub type Rect = ((i32, i32), (u32, u32));
+
+/// Union of damaged rectangles of a window.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DamageRegion {
+    /// Rectangles of region. No rectangle is contained in another.
+    rects : Vec<Rect>,
+}
+
+impl DamageRegion {
+    /// Create a new empty [DamageRegion].
+    pub fn new() -> DamageRegion {
+        DamageRegion { rects: Vec::new() }
+    }
+
+    /// Add a rectangle to region. Empty rectangles and rectangles already covered are ignored.
+    pub fn add(&mut self, rect : Rect) {
+        if rect.1.0 == 0 || rect.1.1 == 0 || self.rects.iter().any(|other| Self::contains(other, &rect)) {
+            return;
+        }
+
+        self.rects.retain(|other| !Self::contains(&rect, other));
+        self.rects.push(rect);
+    }
+
+    /// Returns true if region has no rectangle.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Get the rectangles of region.
+    pub fn get_rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Get the bounding box of region.
+    /// 
+    /// Returns Some([Rect]) or None if region is empty.
+    pub fn get_bounds(&self) -> Option<Rect> {
+        let mut rects = self.rects.iter();
+        let first = *rects.next()?;
+
+        let (mut left, mut top) = first.0;
+        let (mut right, mut bottom) = Self::get_end(&first);
+        for rect in rects {
+            let end = Self::get_end(rect);
+            left = left.min(rect.0.0);
+            top = top.min(rect.0.1);
+            right = right.max(end.0);
+            bottom = bottom.max(end.1);
+        }
+
+        Some(((left, top), ((right - left) as u32, (bottom - top) as u32)))
+    }
+
+    /// Remove all rectangles of region.
+    pub fn clear(&mut self) {
+        self.rects.clear();
+    }
+
+    /// Get the bottom right corner of a rectangle.
+    fn get_end(rect : &Rect) -> (i32, i32) {
+        (rect.0.0 + rect.1.0 as i32, rect.0.1 + rect.1.1 as i32)
+    }
+
+    /// Returns true if outer fully contains inner.
+    fn contains(outer : &Rect, inner : &Rect) -> bool {
+        let (outer_end, inner_end) = (Self::get_end(outer), Self::get_end(inner));
+        outer.0.0 <= inner.0.0 && outer.0.1 <= inner.0.1 && outer_end.0 >= inner_end.0 && outer_end.1 >= inner_end.1
+    }
+}
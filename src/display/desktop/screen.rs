@@ -38,7 +38,7 @@ impl ScreenList {
     }
 
     /// Create a screen list from combined resolution and vector of screen.
-    pub(crate) fn create(size : (u32,u32), screen_list : Vec<Screen>) -> ScreenList{
+    pub fn create(size : (u32,u32), screen_list : Vec<Screen>) -> ScreenList{
         ScreenList{ width: size.0, height: size.1, screen_list }
     }
 
@@ -64,6 +64,13 @@ impl ScreenList {
         None
     }
 
+    /// Get a screen reference from its identifier.
+    /// 
+    /// Returns Some([Screen]) or None if no screen with that identifier.
+    pub fn get_screen(&self, identifier : &str) -> Option<&Screen> {
+        self.screen_list.iter().find(|screen| screen.get_identifier() == identifier)
+    }
+
     /// Get a reference to the list of screens.
     pub fn get_screen_list(&self) -> &Vec<Screen> {
         &self.screen_list
@@ -77,6 +84,7 @@ impl ScreenList {
 /// # Note(s)
 /// Refresh rate is stored as unsigned integer. A 60hz refresh rate is 6000 and a 144hz is 14400. 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Screen {
     /// Identifier of that screen
     identifier : String,
@@ -142,6 +150,7 @@ impl Screen {
 /// # Note(s)
 /// Refresh rate is stored as unsigned integer. A 60hz refresh rate is 6000 and a 144hz is 14400. 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScreenResolution {
     width : u32,
     height : u32,
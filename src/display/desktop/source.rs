@@ -0,0 +1,162 @@
+//! External file descriptors watched in the window event wait.
+//!
+//! Sockets, pipes or inotify handles registered with [Window::get_fd_sources_mut](super::Window::get_fd_sources_mut)
+//! are waited on with the display connection and produce [Event::FdReady](super::event::Event::FdReady) when ready.
+//!
+//! Readiness is level-triggered : a file descriptor is reported again until it is read or written. With
+//! [WindowEventWaitMode::NeverWait](super::property::WindowEventWaitMode::NeverWait), each file descriptor is
+//! reported at most once until poll_event returns [Event::None](super::event::Event::None).
+
+use std::collections::VecDeque;
+use std::ffi::c_ulong;
+use std::os::fd::RawFd;
+
+use crate::display::DisplayError;
+use crate::error::StudioError;
+
+use super::manager::linux::cbind::{PollFd, poll, POLLIN, POLLOUT, POLLERR, POLLHUP, POLLNVAL};
+
+/// Readiness a file descriptor is watched for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FdInterest {
+    /// File descriptor can be read without blocking.
+    Readable,
+
+    /// File descriptor can be written without blocking.
+    Writable,
+
+    /// File descriptor can be read or written without blocking.
+    ReadWrite,
+}
+
+/// Readiness of a file descriptor given by [Event::FdReady](super::event::Event::FdReady).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FdReadiness {
+    /// File descriptor can be read without blocking.
+    pub readable : bool,
+
+    /// File descriptor can be written without blocking.
+    pub writable : bool,
+
+    /// An error occurred or the file descriptor is invalid.
+    pub error : bool,
+
+    /// Peer closed its end of the file descriptor.
+    pub hangup : bool,
+}
+
+/// File descriptor registered in a [FdSourceList].
+struct FdSource {
+    /// Raw file descriptor
+    fd : RawFd,
+
+    /// Token given with readiness.
+    token : u64,
+
+    /// Readiness watched.
+    interest : FdInterest,
+}
+
+/// List of external file descriptors watched by a window.
+pub struct FdSourceList {
+    /// Registered file descriptors.
+    sources : Vec<FdSource>,
+
+    /// Readiness polled and not sent yet, with their file descriptor.
+    ready : VecDeque<(RawFd, u64, FdReadiness)>,
+}
+
+impl FdSourceList {
+    /// Create a new empty [FdSourceList].
+    pub fn new() -> FdSourceList {
+        FdSourceList { sources: Vec::new(), ready: VecDeque::new() }
+    }
+
+    /// Watch fd for interest. Readiness is sent as [Event::FdReady](super::event::Event::FdReady)(token, readiness).
+    /// The file descriptor is not owned and must be deregistered before being closed.
+    ///
+    /// Returns Ok(()) on success, Err([StudioError]) if fd is already registered.
+    pub fn register(&mut self, fd : RawFd, token : u64, interest : FdInterest) -> Result<(), StudioError> {
+        if self.sources.iter().any(|source| source.fd == fd) {
+            return Err(StudioError::Display(DisplayError::FdAlreadyRegistered));
+        }
+
+        self.sources.push(FdSource { fd, token, interest });
+        Ok(())
+    }
+
+    /// Change the readiness watched for a registered fd.
+    ///
+    /// Returns true if changed, false if fd is not registered.
+    pub fn set_interest(&mut self, fd : RawFd, interest : FdInterest) -> bool {
+        match self.sources.iter_mut().find(|source| source.fd == fd) {
+            Some(source) => {
+                source.interest = interest;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Stop watching fd. Readiness of fd not sent yet is discarded.
+    ///
+    /// Returns true if fd was registered.
+    pub fn deregister(&mut self, fd : RawFd) -> bool {
+        match self.sources.iter().position(|source| source.fd == fd) {
+            Some(index) => {
+                self.sources.remove(index);
+                self.ready.retain(|(ready, _, _)| *ready != fd);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Returns true if no file descriptor is registered.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Get the registered file descriptors to poll with their interest.
+    pub(crate) fn get_poll_fds(&self) -> Vec<PollFd> {
+        self.sources.iter().map(|source| PollFd::with_events(source.fd, match source.interest {
+            FdInterest::Readable => POLLIN,
+            FdInterest::Writable => POLLOUT,
+            FdInterest::ReadWrite => POLLIN | POLLOUT,
+        })).collect()
+    }
+
+    /// Poll registered file descriptors without waiting and queue their readiness, unless readiness is still queued.
+    pub(crate) fn update(&mut self) {
+        if self.sources.is_empty() || !self.ready.is_empty() {
+            return;
+        }
+
+        let mut fds = self.get_poll_fds();
+        if unsafe { poll(fds.as_mut_ptr(), fds.len() as c_ulong, 0) } <= 0 {
+            return;
+        }
+
+        for (source, fd) in self.sources.iter().zip(fds) {
+            if fd.revents != 0 {
+                self.ready.push_back((source.fd, source.token, FdReadiness { readable: fd.revents & POLLIN != 0, writable: fd.revents & POLLOUT != 0,
+                    error: fd.revents & (POLLERR | POLLNVAL) != 0, hangup: fd.revents & POLLHUP != 0 }));
+            }
+        }
+    }
+
+    /// Pop the next readiness queued.
+    ///
+    /// Returns Some((token, [FdReadiness])) or None if no readiness queued.
+    pub(crate) fn pop_ready(&mut self) -> Option<(u64, FdReadiness)> {
+        self.ready.pop_front().map(|(_, token, readiness)| (token, readiness))
+    }
+}
+
+impl Default for FdSourceList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
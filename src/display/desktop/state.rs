@@ -0,0 +1,101 @@
+//! Window geometry and state persistence.
+//!
+//! A [WindowState] is a snapshot of a window position, size, screen and maximized or fullscreen state taken
+//! with [Window::get_state](super::Window::get_state). It can be saved (with the `serde` feature) and restored on
+//! next launch with [WindowState::get_properties], which clamps the geometry to the screens currently available.
+
+use super::property::{FullScreenMode, WindowPositionOption, WindowPropertySet, WINDOW_MAX_HEIGHT, WINDOW_MAX_WIDTH, WINDOW_MIN_HEIGHT, WINDOW_MIN_WIDTH};
+use super::screen::{Screen, ScreenList};
+use super::window::Window;
+
+/// Snapshot of a window geometry and state.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowState {
+    /// Identifier of the screen window was on. None if no screen was found.
+    pub screen : Option<String>,
+
+    /// Position of window relative to its screen, or to desktop if no screen. Normal position if window was maximized or fullscreen.
+    pub position : (i32, i32),
+
+    /// Size of window as (width, height). Normal size if window was maximized or fullscreen.
+    pub size : (u32, u32),
+
+    /// Window was maximized.
+    pub maximized : bool,
+
+    /// Window was fullscreen.
+    pub fullscreen : bool,
+}
+
+impl WindowState {
+    /// Create a snapshot of a window. The screen of window is the screen containing its center.
+    ///
+    /// The normal geometry of a maximized or fullscreen window is saved so that it can be restored once unmaximized.
+    pub fn new(window : &Window, screens : &ScreenList) -> WindowState {
+        let properties = window.get_properties();
+        let (position, size) = if properties.is_normal() {
+            (properties.position, properties.size)
+        } else {
+            (properties.normal_position, properties.normal_size)
+        };
+        let center = (position.0 + size.0 as i32 / 2, position.1 + size.1 as i32 / 2);
+
+        let screen = screens.get_screen_list().iter().find(|screen| Self::contains(screen, center));
+        let position = match screen {
+            Some(screen) => (position.0 - screen.get_extended_position().0, position.1 - screen.get_extended_position().1),
+            None => position,
+        };
+
+        WindowState { screen: screen.map(|screen| screen.get_identifier().clone()), position, size,
+            maximized: properties.maximized, fullscreen: properties.fullscreen.is_some() }
+    }
+
+    /// Get the properties restoring this state, to set with [Window::set_properties](super::Window::set_properties)
+    /// before showing the window.
+    ///
+    /// If the saved screen is gone, the primary screen is used. Size and position are clamped so that the window
+    /// fits in its screen, or in desktop if no screen is available.
+    pub fn get_properties(&self, screens : &ScreenList) -> Vec<WindowPropertySet<'static>> {
+        let screen = self.screen.as_ref().and_then(|identifier| screens.get_screen(identifier))
+            .or(screens.get_primary_screen()).or(screens.get_screen_list().first());
+
+        let bounds = match screen {
+            Some(screen) => screen.get_current_resolution(),
+            None => (screens.get_desktop_width(), screens.get_desktop_height()),
+        };
+
+        let size = (self.size.0.clamp(WINDOW_MIN_WIDTH, bounds.0.clamp(WINDOW_MIN_WIDTH, WINDOW_MAX_WIDTH)),
+            self.size.1.clamp(WINDOW_MIN_HEIGHT, bounds.1.clamp(WINDOW_MIN_HEIGHT, WINDOW_MAX_HEIGHT)));
+        let position = (self.position.0.clamp(0, bounds.0.saturating_sub(size.0) as i32),
+            self.position.1.clamp(0, bounds.1.saturating_sub(size.1) as i32));
+
+        let mut properties = vec![WindowPropertySet::Size(size)];
+        match screen {
+            Some(screen) => {
+                properties.push(WindowPropertySet::Position(WindowPositionOption::Screen(screen.clone(), position)));
+                if self.fullscreen {
+                    properties.push(WindowPropertySet::Fullscreen(FullScreenMode::Screen(screen.clone())));
+                }
+            },
+            None => {
+                properties.push(WindowPropertySet::Position(WindowPositionOption::Desktop(position)));
+                if self.fullscreen {
+                    properties.push(WindowPropertySet::Fullscreen(FullScreenMode::Current));
+                }
+            },
+        }
+
+        if self.maximized && !self.fullscreen {
+            properties.push(WindowPropertySet::Maximized);
+        }
+
+        properties
+    }
+
+    /// Returns true if point is within screen.
+    fn contains(screen : &Screen, point : (i32, i32)) -> bool {
+        let (position, resolution) = (screen.get_extended_position(), screen.get_current_resolution());
+        point.0 >= position.0 && point.1 >= position.1 && point.0 < position.0 + resolution.0 as i32 && point.1 < position.1 + resolution.1 as i32
+    }
+}
@@ -0,0 +1,138 @@
+//! Async stream of window events.
+//!
+//! Enabled by the `async` feature. An [EventStream] is created with [Window::event_stream](super::Window::event_stream)
+//! and never blocks the executor. When no event is available, the task is pending until one of
+//! [EventStream::get_event_fds] is readable or [EventStream::get_timeout] expires : a reactor registers them to wake
+//! the task. Executors without reactor can opt in [EventStream::with_fd_waiter], waiting on them with a helper thread.
+//!
+//! Events already read from the display connection don't make its file descriptor readable, so the task is woken
+//! immediately when some are queued.
+//!
+//! Streams only receive the events of windows created with [Window::new](super::Window::new). Windows of an
+//! [Application](super::Application) receive their events through the application.
+
+use std::future::poll_fn;
+use std::os::fd::RawFd;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+
+use super::event::Event;
+use super::event::window::EventWindow;
+use super::manager::{FdWaiterType, WindowManager};
+use super::manager::linux::get_poll_timeout;
+use super::manager::linux::cbind::PollFd;
+use super::property::WindowEventWaitMode;
+use super::window::Window;
+
+/// [Stream] of the events of a [Window]. The stream ends after [EventWindow::Closed].
+///
+/// The window event wait mode is set to [WindowEventWaitMode::NeverWait] while the stream exists and restored when dropped.
+pub struct EventStream<'stream, 'window> {
+    /// Window polled.
+    window : &'stream mut Window<'window>,
+
+    /// Wait mode of window before stream creation.
+    wait_mode : WindowEventWaitMode,
+
+    /// Optional thread waking the task when an event is available.
+    waiter : Option<FdWaiterType>,
+
+    /// Window was closed, ending the stream.
+    closed : bool,
+}
+
+impl<'stream, 'window> EventStream<'stream, 'window> {
+    /// Create a new [EventStream] of window.
+    pub(crate) fn new(window : &'stream mut Window<'window>) -> EventStream<'stream, 'window> {
+        let wait_mode = window.get_properties().wait_mode;
+        window.manager.set_event_wait_mode(WindowEventWaitMode::NeverWait);
+
+        EventStream { window, wait_mode, waiter: None, closed: false }
+    }
+
+    /// Wake the task with a helper thread waiting on [EventStream::get_event_fds] and [EventStream::get_timeout], for
+    /// executors without a reactor.
+    pub fn with_fd_waiter(mut self) -> EventStream<'stream, 'window> {
+        self.waiter = Some(FdWaiterType::new());
+        self
+    }
+
+    /// Get the file descriptors readable when an event is available : display connection, gamepads, user events and
+    /// the external file descriptors of [Window::get_fd_sources](super::Window::get_fd_sources).
+    ///
+    /// The set changes when gamepads are connected or sources registered, so it should be read again after each event.
+    pub fn get_event_fds(&self) -> Vec<RawFd> {
+        self.get_poll_fds().into_iter().map(|fd| fd.fd).collect()
+    }
+
+    /// Returns the duration until the next timer of window expires, zero if already expired. None if no timer.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.window.get_timers().get_timeout()
+    }
+
+    /// Get the next event.
+    ///
+    /// Returns Some([Event]) or None if the window was closed.
+    pub async fn next_event(&mut self) -> Option<Event> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Wait for a close request of the window, discarding other events.
+    ///
+    /// Returns true when a close request is received, false if the window was closed.
+    pub async fn next_close_request(&mut self) -> bool {
+        while let Some(event) = self.next_event().await {
+            if event == Event::Window(EventWindow::CloseRequest) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl EventStream<'_, '_> {
+    /// Get the file descriptors of window and external sources to poll.
+    fn get_poll_fds(&self) -> Vec<PollFd> {
+        let manager = &self.window.manager;
+        let mut fds : Vec<PollFd> = manager.get_event_fds().into_iter().map(PollFd::new).collect();
+        fds.extend(manager.get_fd_sources().get_poll_fds());
+        fds
+    }
+}
+
+impl Stream for EventStream<'_, '_> {
+    type Item = Event;
+
+    fn poll_next(self : Pin<&mut Self>, cx : &mut Context<'_>) -> Poll<Option<Event>> {
+        let stream = self.get_mut();
+
+        if stream.closed {
+            return Poll::Ready(None);
+        }
+
+        match *stream.window.poll_event() {
+            Event::None => {
+                if stream.window.manager.has_queued_events() {
+                    cx.waker().wake_by_ref();
+                } else if let Some(waiter) = &stream.waiter {
+                    // Events arriving after poll_event make a file descriptor readable and wake the task immediately.
+                    waiter.wait(stream.get_poll_fds(), get_poll_timeout(stream.get_timeout()), cx.waker());
+                }
+                Poll::Pending
+            },
+            event => {
+                stream.closed = event == Event::Window(EventWindow::Closed);
+                Poll::Ready(Some(event))
+            },
+        }
+    }
+}
+
+impl Drop for EventStream<'_, '_> {
+    fn drop(&mut self) {
+        self.window.manager.set_event_wait_mode(self.wait_mode);
+    }
+}
@@ -0,0 +1,158 @@
+//! One-shot and repeating timers producing [Event::Timer](super::event::Event::Timer).
+//!
+//! Timers are registered on a window with [Window::get_timers_mut](super::Window::get_timers_mut). Waiting for
+//! events with [WindowEventWaitMode::AlwaysWait](super::property::WindowEventWaitMode::AlwaysWait) wakes up when
+//! the next timer expires.
+//!
+//! Time is read from a [Clock] that can be replaced to test timers without waiting.
+
+use std::time::{Duration, Instant};
+
+/// Shortest interval of a repeating timer. Shorter intervals are clamped.
+pub const TIMER_MIN_INTERVAL : Duration = Duration::from_millis(1);
+
+/// Source of the current time of timers.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}
+
+/// [Clock] reading the system monotonic clock.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Identifier of a timer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerId(pub(crate) u32);
+
+/// Timer registered in a [TimerList].
+struct Timer {
+    /// Id of timer
+    id : TimerId,
+
+    /// Time at which the timer expires.
+    deadline : Instant,
+
+    /// Interval of repeating timer. None for one-shot timer.
+    interval : Option<Duration>,
+}
+
+/// List of timers of a window.
+pub struct TimerList {
+    /// Clock giving current time.
+    clock : Box<dyn Clock>,
+
+    /// Registered timers.
+    timers : Vec<Timer>,
+
+    /// Next timer id.
+    next_id : u32,
+}
+
+impl TimerList {
+    /// Create a new empty [TimerList] using the [SystemClock].
+    pub fn new() -> TimerList {
+        TimerList::with_clock(Box::new(SystemClock))
+    }
+
+    /// Create a new empty [TimerList] using clock.
+    pub fn with_clock(clock : Box<dyn Clock>) -> TimerList {
+        TimerList { clock, timers: Vec::new(), next_id: 0 }
+    }
+
+    /// Replace the clock of timers. Deadlines of registered timers are kept.
+    pub fn set_clock(&mut self, clock : Box<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the current time of the timers clock.
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Add a timer expiring once after delay.
+    pub fn add_timer(&mut self, delay : Duration) -> TimerId {
+        let deadline = self.clock.now() + delay;
+        self.insert(deadline, None)
+    }
+
+    /// Add a timer expiring every interval. Interval is clamped to [TIMER_MIN_INTERVAL].
+    pub fn add_repeating_timer(&mut self, interval : Duration) -> TimerId {
+        let interval = interval.max(TIMER_MIN_INTERVAL);
+        let deadline = self.clock.now() + interval;
+        self.insert(deadline, Some(interval))
+    }
+
+    /// Remove a timer before it expires.
+    ///
+    /// Returns true if the timer was removed, false if no timer with that id.
+    pub fn remove_timer(&mut self, id : TimerId) -> bool {
+        let count = self.timers.len();
+        self.timers.retain(|timer| timer.id != id);
+        self.timers.len() != count
+    }
+
+    /// Returns true if no timer is registered.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Returns the time at which the next timer expires. None if no timer.
+    pub fn get_next_deadline(&self) -> Option<Instant> {
+        self.timers.iter().map(|timer| timer.deadline).min()
+    }
+
+    /// Returns the duration until the next timer expires, zero if already expired. None if no timer.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        let now = self.clock.now();
+        self.get_next_deadline().map(|deadline| deadline.saturating_duration_since(now))
+    }
+
+    /// Pop the earliest expired timer. One-shot timers are removed, repeating timers are rescheduled.
+    /// Expirations missed by more than one interval are skipped.
+    ///
+    /// Returns Some([TimerId]) or None if no timer expired.
+    pub fn poll_timer(&mut self) -> Option<TimerId> {
+        let now = self.clock.now();
+        let index = self.timers.iter().enumerate().filter(|(_, timer)| timer.deadline <= now)
+            .min_by_key(|(_, timer)| timer.deadline).map(|(index, _)| index)?;
+
+        let timer = &mut self.timers[index];
+        let id = timer.id;
+
+        match timer.interval {
+            Some(interval) => {
+                timer.deadline += interval;
+                if timer.deadline <= now {
+                    timer.deadline = now + interval;
+                }
+            },
+            None => {
+                self.timers.remove(index);
+            },
+        }
+
+        Some(id)
+    }
+
+    /// Insert a new timer.
+    fn insert(&mut self, deadline : Instant, interval : Option<Duration>) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        self.timers.push(Timer { id, deadline, interval });
+        id
+    }
+}
+
+impl Default for TimerList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
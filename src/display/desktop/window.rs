@@ -1,9 +1,16 @@
 //! Window abstraction and properties
 
+use std::any::{Any, TypeId};
+
 use crate::display::DisplayError;
 use crate::error::StudioError;
 
 use super::event::Event;
+use super::event::EventCategoryMask;
+use super::gamepad::GamepadList;
+use super::gesture::GestureRecognizer;
+use super::gesture::GestureSettings;
+use super::icon::WindowIcon;
 use super::manager::WindowManager;
 use super::manager::WindowManagerType;
 use super::manager::WindowProvider;
@@ -16,6 +23,15 @@ use super::property::WindowEventWaitMode;
 use super::property::WindowPositionOption;
 use super::property::WindowProperty;
 use super::property::WindowPropertySet;
+use super::property::WindowType;
+use super::proxy::EventProxy;
+use super::region::DamageRegion;
+use super::screen::ScreenList;
+use super::state::WindowState;
+use super::source::FdSourceList;
+use super::timer::{TimerId, TimerList};
+#[cfg(feature = "async")]
+use super::stream::EventStream;
 
 /// Window wrapping a WindowManager.
 /// 
@@ -27,6 +43,15 @@ pub struct Window<'window> {
 
     /// [WindowManager] of this window
     pub(crate) manager : WindowManagerType<'window>,
+
+    /// Optional [GestureRecognizer] fed with polled events.
+    gesture : Option<GestureRecognizer>,
+
+    /// Timer waking up the window when a long press is due.
+    gesture_timer : Option<TimerId>,
+
+    /// Event returned when gestures are enabled.
+    event : Event,
 }
 
 impl<'window> Window<'window> {
@@ -40,12 +65,17 @@ impl<'window> Window<'window> {
             
             Ok(wm) => {
                Ok(Window { 
-                    manager: wm })
+                    manager: wm, gesture: None, gesture_timer: None, event: Event::None })
             },
             Err(err) => Err(err),
         }        
     }
 
+    /// Create a [Window] from a window manager, used by [Application](super::application::Application).
+    pub(crate) fn from_manager(manager : WindowManagerType<'window>) -> Window<'window> {
+        Window { manager, gesture: None, gesture_timer: None, event: Event::None }
+    }
+
     /// Get the window provider id
     pub fn get_window_provider(&self) -> WindowProvider{
         self.manager.get_window_provider()
@@ -73,8 +103,131 @@ impl<'window> Window<'window> {
     }
 
     /// Pop a window event from the queue.
+    /// 
+    /// Windows created by an [Application](super::application::Application) only receive events routed by 
+    /// [Application::poll_event](super::application::Application::poll_event).
+    /// 
+    /// If gestures are enabled, [Event::Gesture] are sent after the pointer events that triggered them. A pending long
+    /// press registers a timer in [Window::get_timers] so waiting for events wakes up when it is due.
     pub fn poll_event(&mut self) -> &Event{
-        self.manager.poll_event() 
+        let recognizer = match &mut self.gesture {
+            Some(recognizer) => recognizer,
+            None => return self.manager.poll_event(),
+        };
+
+        // Gestures are discarded if masked.
+        let accepted = self.manager.get_properties().event_mask.contains(EventCategoryMask::GESTURE);
+
+        match recognizer.poll_gesture().filter(|_| accepted) {
+            Some(gesture) => self.event = Event::Gesture(gesture),
+            None => {
+                self.event = *self.manager.poll_event();
+
+                // Long press timer is internal and never returned.
+                if matches!(self.event, Event::Timer(id) if self.gesture_timer == Some(id)) {
+                    self.gesture_timer = None;
+                    self.event = Event::None;
+                }
+
+                match self.event {
+                    // Long press can happen without event.
+                    Event::None => {
+                        recognizer.update_elapsed();
+                        self.event = recognizer.poll_gesture().filter(|_| accepted).map_or(Event::None, Event::Gesture);
+                    },
+                    event => recognizer.handle_event(&event),
+                }
+
+                // Keep a timer until the pending long press is due.
+                match (self.gesture_timer, recognizer.get_timeout()) {
+                    (None, Some(timeout)) => self.gesture_timer = Some(self.manager.get_timers_mut().add_timer(timeout)),
+                    (Some(id), None) => {
+                        self.manager.get_timers_mut().remove_timer(id);
+                        self.gesture_timer = None;
+                    },
+                    _ => {},
+                }
+            },
+        }
+        &self.event
+    }
+
+    /// Get the [GestureSettings] used if gestures are enabled.
+    pub fn get_gesture_settings(&self) -> Option<&GestureSettings> {
+        self.gesture.as_ref().map(|recognizer| recognizer.get_settings())
+    }
+
+    /// Create a cloneable [EventProxy] that other threads can use to post [Event::User] to this window.
+    pub fn create_proxy(&self) -> EventProxy {
+        self.manager.create_proxy()
+    }
+
+    /// Take the payload posted with [EventProxy::post_payload] received as [Event::User] with key.
+    ///
+    /// Returns Some(payload) or None if no payload of type T with key, or if already taken.
+    pub fn take_user_payload<T : Any>(&self, key : u64) -> Option<T> {
+        self.manager.take_user_payload(key, TypeId::of::<T>()).and_then(|payload| (payload as Box<dyn Any>).downcast::<T>().ok()).map(|payload| *payload)
+    }
+
+    /// Create an async [EventStream] of the window events. Requires the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn event_stream(&mut self) -> EventStream<'_, 'window> {
+        EventStream::new(self)
+    }
+
+    /// Get the timers of window. Expired timers are polled as [Event::Timer].
+    pub fn get_timers(&self) -> &TimerList {
+        self.manager.get_timers()
+    }
+
+    /// Get the timers of window as mutable to add or remove timers.
+    pub fn get_timers_mut(&mut self) -> &mut TimerList {
+        self.manager.get_timers_mut()
+    }
+
+    /// Get the external file descriptors watched by window. Ready file descriptors are polled as [Event::FdReady].
+    pub fn get_fd_sources(&self) -> &FdSourceList {
+        self.manager.get_fd_sources()
+    }
+
+    /// Get the external file descriptors watched by window as mutable to register or deregister file descriptors.
+    pub fn get_fd_sources_mut(&mut self) -> &mut FdSourceList {
+        self.manager.get_fd_sources_mut()
+    }
+
+    /// Get a snapshot of window geometry and state that can be restored with [WindowState::get_properties].
+    pub fn get_state(&self, screens : &ScreenList) -> WindowState {
+        WindowState::new(self, screens)
+    }
+
+    /// Push an event polled before the events of display server, such as an event replayed by an
+    /// [EventReplayer](super::record::EventReplayer).
+    pub fn push_event(&self, event : Event) {
+        self.manager.push_event(event)
+    }
+
+    /// Get the rectangles damaged since the previous [EventWindow::Exposed](super::event::window::EventWindow::Exposed).
+    pub fn get_damage_region(&self) -> &DamageRegion {
+        self.manager.get_damage_region()
+    }
+
+    /// Request a single [EventWindow::RedrawRequested](super::event::window::EventWindow::RedrawRequested), polled
+    /// once no other event is pending. Requests made before it is polled are merged.
+    pub fn request_redraw(&mut self) {
+        self.manager.request_redraw()
+    }
+
+    /// Get the connected gamepads.
+    /// 
+    /// Gamepads are opened on the first call to [Window::get_gamepads] or [Window::get_gamepads_mut]. Gamepad events
+    /// are polled once opened.
+    pub fn get_gamepads(&self) -> &GamepadList {
+        self.manager.get_gamepads()
+    }
+
+    /// Get the connected gamepads as mutable.
+    pub fn get_gamepads_mut(&mut self) -> &mut GamepadList {
+        self.manager.get_gamepads_mut()
     }
 
     /// Get window properties in a read only struct.
@@ -141,10 +294,24 @@ impl<'window> Window<'window> {
          match property {
             WindowPropertySet::SetParent(parent, option) => self.set_parent(parent, *option),
             WindowPropertySet::SetEventWaitMode(mode) => self.set_event_wait_mode(*mode),
+            WindowPropertySet::SetEventMask(mask) => self.set_event_mask(*mask),
+            WindowPropertySet::SetEventCoalescing(mask) => self.set_event_coalescing(*mask),
             WindowPropertySet::RemoveParent => todo!(),
             WindowPropertySet::Title(title) => self.set_title(title),
             WindowPropertySet::Position(option) => self.set_position(option),
             WindowPropertySet::Size(size) => self.set_size(size),
+            WindowPropertySet::MinSize(size) => self.set_min_size(*size),
+            WindowPropertySet::MaxSize(size) => self.set_max_size(*size),
+            WindowPropertySet::AspectRatio(ratio) => self.set_aspect_ratio(*ratio),
+            WindowPropertySet::ResizeIncrement(increment) => self.set_resize_increment(*increment),
+            WindowPropertySet::Resizable(resizable) => self.set_resizable(*resizable),
+            WindowPropertySet::Icon(icon) => self.set_icon(icon),
+            WindowPropertySet::Type(window_type) => self.set_window_type(*window_type),
+            WindowPropertySet::Above(above) => self.set_above(*above),
+            WindowPropertySet::Below(below) => self.set_below(*below),
+            WindowPropertySet::Sticky(sticky) => self.set_sticky(*sticky),
+            WindowPropertySet::SkipTaskbar(skip_taskbar) => self.set_skip_taskbar(*skip_taskbar),
+            WindowPropertySet::SkipPager(skip_pager) => self.set_skip_pager(*skip_pager),
             WindowPropertySet::ShowDecoration => self.show_decoration(),
             WindowPropertySet::HideDecoration => self.hide_decoration(),
             WindowPropertySet::Minimize => self.minimize(),
@@ -153,6 +320,7 @@ impl<'window> Window<'window> {
             WindowPropertySet::Restore => self.restore(),
             WindowPropertySet::Keyboard(kb_property) => self.set_keyboard_property(kb_property),
             WindowPropertySet::Pointer(p_property) => self.set_pointer_property(p_property),
+            WindowPropertySet::Gesture(settings) => self.set_gesture(settings),
          }
     }
 
@@ -230,6 +398,16 @@ impl<'window> Window<'window> {
         Ok(self.manager.set_event_wait_mode(mode))
     }
 
+    #[inline(always)]
+    fn set_event_mask(&mut self, mask : EventCategoryMask) -> Result<bool, StudioError>{
+        Ok(self.manager.set_event_mask(mask))
+    }
+
+    #[inline(always)]
+    fn set_event_coalescing(&mut self, mask : EventCategoryMask) -> Result<bool, StudioError>{
+        Ok(self.manager.set_event_coalescing(mask))
+    }
+
 
     #[inline(always)]
     fn set_title(&mut self, title : &String) -> Result<bool, StudioError>{
@@ -244,12 +422,111 @@ impl<'window> Window<'window> {
     #[inline(always)]
     fn set_size(&mut self, size : &(u32,u32))  -> Result<bool, StudioError>{
         if WindowProperty::is_size_within_boundaries(size){
-            Ok(self.manager.set_size(size))
+            Ok(self.manager.set_size(&self.get_properties().get_constrained_size(*size)))
         } else {    // Size incorrect.
             return Err(StudioError::Display(DisplayError::SizeError))
         }
     }
 
+    #[inline(always)]
+    fn set_min_size(&mut self, size : Option<(u32,u32)>) -> Result<bool, StudioError>{
+        match (size, self.get_properties().max_size) {
+            (Some(size), _) if !WindowProperty::is_size_within_boundaries(&size) => Err(StudioError::Display(DisplayError::SizeError)),
+            (Some(min), Some(max)) if min.0 > max.0 || min.1 > max.1 => Err(StudioError::Display(DisplayError::SizeError)),
+            _ => {
+                let recreate = self.manager.set_min_size(size);
+                Ok(self.apply_size_constraints() || recreate)
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn set_max_size(&mut self, size : Option<(u32,u32)>) -> Result<bool, StudioError>{
+        match (self.get_properties().min_size, size) {
+            (_, Some(size)) if !WindowProperty::is_size_within_boundaries(&size) => Err(StudioError::Display(DisplayError::SizeError)),
+            (Some(min), Some(max)) if min.0 > max.0 || min.1 > max.1 => Err(StudioError::Display(DisplayError::SizeError)),
+            _ => {
+                let recreate = self.manager.set_max_size(size);
+                Ok(self.apply_size_constraints() || recreate)
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn set_aspect_ratio(&mut self, ratio : Option<(u32,u32)>) -> Result<bool, StudioError>{
+        match ratio {
+            Some((width, height)) if width == 0 || height == 0 => Err(StudioError::Display(DisplayError::SizeError)),
+            _ => {
+                let recreate = self.manager.set_aspect_ratio(ratio);
+                Ok(self.apply_size_constraints() || recreate)
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn set_resize_increment(&mut self, increment : Option<(u32,u32)>) -> Result<bool, StudioError>{
+        match increment {
+            Some((width, height)) if width == 0 || height == 0 => Err(StudioError::Display(DisplayError::SizeError)),
+            _ => {
+                let recreate = self.manager.set_resize_increment(increment);
+                Ok(self.apply_size_constraints() || recreate)
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn set_resizable(&mut self, resizable : bool) -> Result<bool, StudioError>{
+        Ok(self.manager.set_resizable(resizable))
+    }
+
+    #[inline(always)]
+    fn set_icon(&mut self, icon : &[WindowIcon]) -> Result<bool, StudioError>{
+        Ok(self.manager.set_icon(icon))
+    }
+
+    #[inline(always)]
+    fn set_window_type(&mut self, window_type : WindowType) -> Result<bool, StudioError>{
+        Ok(self.manager.set_window_type(window_type))
+    }
+
+    #[inline(always)]
+    fn set_above(&mut self, above : bool) -> Result<bool, StudioError>{
+        Ok(self.manager.set_above(above))
+    }
+
+    #[inline(always)]
+    fn set_below(&mut self, below : bool) -> Result<bool, StudioError>{
+        Ok(self.manager.set_below(below))
+    }
+
+    #[inline(always)]
+    fn set_sticky(&mut self, sticky : bool) -> Result<bool, StudioError>{
+        Ok(self.manager.set_sticky(sticky))
+    }
+
+    #[inline(always)]
+    fn set_skip_taskbar(&mut self, skip_taskbar : bool) -> Result<bool, StudioError>{
+        Ok(self.manager.set_skip_taskbar(skip_taskbar))
+    }
+
+    #[inline(always)]
+    fn set_skip_pager(&mut self, skip_pager : bool) -> Result<bool, StudioError>{
+        Ok(self.manager.set_skip_pager(skip_pager))
+    }
+
+    /// Resize window if its current size doesn't respect its size constraints.
+    #[inline(always)]
+    fn apply_size_constraints(&mut self) -> bool {
+        let size = self.get_properties().size;
+        let constrained = self.get_properties().get_constrained_size(size);
+
+        if constrained != size {
+            self.manager.set_size(&constrained)
+        } else {
+            false
+        }
+    }
+
     #[inline(always)]
     fn show_decoration(&mut self) -> Result<bool, StudioError>{
         Ok(self.manager.show_decoration())
@@ -281,6 +558,15 @@ impl<'window> Window<'window> {
         Ok(true)
     }
 
+    #[inline(always)]
+    fn set_gesture(&mut self, settings : &Option<GestureSettings>) -> Result<bool, StudioError>{
+        if let Some(id) = self.gesture_timer.take() {
+            self.manager.get_timers_mut().remove_timer(id);
+        }
+        self.gesture = settings.map(GestureRecognizer::new);
+        Ok(false)
+    }
+
     #[inline(always)]
     fn set_keyboard_property(&mut self, property : &KeyboardPropertySet) -> Result<bool, StudioError>{
         match property {
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use studio::display::desktop::{application::{Application, ApplicationHandler, WindowId}, event::Event, property::WindowEventWaitMode};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test events of multiple windows sharing one application
+pub fn test_application(){
+
+    let mut app = Application::new().unwrap();
+
+    let first = app.create_window();
+    let second = app.create_window();
+
+    for id in [first, second] {
+        app.get_window_mut(id).unwrap().show();
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting application event tests ...", RESET_CONSOLE);
+    println!("{}Move pointer, type and focus both windows, then press SPACE...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+
+    let mut space = SpaceReceiver::new();
+
+    while !space.is_test_finished() {
+        while let Some((id, event)) = app.poll_event() {
+            match event {
+                Event::Pointer(..) => {},   // Too many to print.
+                _ => println!("{:?} {:?}", id, event),
+            }
+            space.receive(&event);
+        }
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "... application event tests ended ...", RESET_CONSOLE);
+
+    for id in app.get_window_ids() {
+        app.get_window_mut(id).unwrap().close();
+    }
+
+}
+
+/// Test the application run loop until all windows are closed
+pub fn test_application_run(){
+
+    let mut app = Application::new().unwrap();
+
+    for _ in 0..2 {
+        let id = app.create_window();
+        app.get_window_mut(id).unwrap().show();
+    }
+
+    app.set_event_wait_mode(WindowEventWaitMode::NeverWait);
+    app.set_frame_rate(Some(30));
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting application run loop tests ...", RESET_CONSOLE);
+    println!("{}Close both windows to end the run loop...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+
+    let mut handler = RunHandler { frames: 0, elapsed: Duration::ZERO };
+    app.run(&mut handler);
+
+    println!("{}... application run loop tests ended after {} frames ...{}", BLUE_CONSOLE, handler.frames, RESET_CONSOLE);
+
+}
+
+/// Handler that log events and print frame rate every second.
+struct RunHandler {
+    frames : u64,
+    elapsed : Duration,
+}
+
+impl<'app> ApplicationHandler<'app> for RunHandler {
+    fn on_event(&mut self, _app : &mut Application<'app>, id : WindowId, event : &Event) {
+        match event {
+            Event::Pointer(..) => {},   // Too many to print.
+            _ => println!("{:?} {:?}", id, event),
+        }
+    }
+
+    fn on_close_requested(&mut self, _app : &mut Application<'app>, id : WindowId) -> bool {
+        println!("Closing {:?}", id);
+        true
+    }
+
+    fn on_frame(&mut self, _app : &mut Application<'app>, elapsed : Duration) {
+        self.frames += 1;
+        self.elapsed += elapsed;
+
+        if self.elapsed >= Duration::from_secs(1) {
+            println!("{} frames after {:?}", self.frames, self.elapsed);
+            self.elapsed = Duration::ZERO;
+        }
+    }
+}
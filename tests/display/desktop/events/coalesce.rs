@@ -0,0 +1,50 @@
+use studio::display::desktop::{window::Window, event::{Event, EventCategoryMask, window::EventWindow}, property::WindowPropertySet};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver, main_loop}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test event coalescing and event category masks
+pub fn test_coalesce(){
+
+    let properties = [WindowPropertySet::SetEventCoalescing(EventCategoryMask::POINTER_MOTION | EventCategoryMask::CONFIGURE | EventCategoryMask::EXPOSE),
+        WindowPropertySet::SetEventMask(EventCategoryMask::ALL.without(EventCategoryMask::CURSOR_CROSSING | EventCategoryMask::GAMEPAD))];
+    let mut window = Window::new().unwrap();
+
+    window.set_properties(&properties).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting event coalescing tests ...", RESET_CONSOLE);
+
+    main_loop(&mut window, &mut CoalesceReceiver::new());
+
+    println!("{}{}{}", BLUE_CONSOLE, "... event coalescing tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
+
+/// Receiver that log coalesced events until space bar is pressed.
+pub struct CoalesceReceiver {
+    space : SpaceReceiver,
+}
+
+impl CoalesceReceiver {
+    pub fn new() -> CoalesceReceiver {
+        println!("{}Resize window and move pointer, cursor enter and leave are never received, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        CoalesceReceiver { space: SpaceReceiver::new() }
+    }
+}
+
+impl EventReceiver for CoalesceReceiver {
+    fn receive(&mut self, event: &Event) {
+        match event {
+            Event::Window(EventWindow::CursorEnter | EventWindow::CursorLeave) | Event::Gamepad(_) => panic!("Masked event received : {:?}", event),
+            Event::Pointer(..) | Event::Window(_) => println!("{:?}", event),
+            _ => self.space.receive(event),
+        }
+    }
+
+    fn is_test_finished(&self) -> bool {
+        self.space.is_test_finished()
+    }
+}
@@ -0,0 +1,39 @@
+use studio::display::desktop::{window::Window, event::{Event, pointer::EventPointer, window::EventWindow}, property::{WindowPropertySet, WindowEventWaitMode}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test minimum, maximum size, aspect ratio, resize increment constraints and non-resizable window
+pub fn test_constraint(){
+
+    let properties = [WindowPropertySet::SetEventWaitMode(WindowEventWaitMode::AlwaysWait),
+        WindowPropertySet::MinSize(Some((320, 180))), WindowPropertySet::MaxSize(Some((1280, 720))),
+        WindowPropertySet::AspectRatio(Some((16, 9))), WindowPropertySet::ResizeIncrement(Some((16, 9)))];
+    let locked = [WindowPropertySet::Resizable(false)];
+    let unlocked = [WindowPropertySet::Resizable(true)];
+    let mut window = Window::new().unwrap();
+
+    window.set_properties(&properties).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting size constraints tests ...", RESET_CONSOLE);
+    println!("{}Resize window, size should stay 16:9 between 320x180 and 1280x720, click to lock or unlock size, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+
+    let mut space = SpaceReceiver::new();
+    while !space.is_test_finished() {
+        let event = *window.poll_event();
+        match event {
+            Event::Window(EventWindow::Resized(size)) | Event::Window(EventWindow::MovedResized(_, size)) => println!("Resized {:?}", size),
+            Event::Pointer(EventPointer::ButtonDown(..), _) => {
+                window.set_properties(if window.get_properties().resizable { &locked } else { &unlocked }).unwrap();
+                println!("Resizable={}", window.get_properties().resizable);
+            },
+            _ => space.receive(&event),
+        }
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "... size constraints tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
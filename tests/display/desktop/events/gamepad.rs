@@ -1,23 +1,64 @@
+use std::time::Duration;
 
+use studio::display::desktop::{window::Window, event::Event, gamepad::effect::GamepadEffect};
 
-/*
- /// Happens when a controller device has been connected. Provides controller id.
-    Connected(u8),
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver, main_loop}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
 
-    /// Happens when a controller device has been disconnected. Provides controller id.
-    Disconnected(u8),
+/// Test gamepad events
+pub fn test_gamepad(){
 
-    /// Happens when a controller button is pressed. Provides controller id and button id.
-    ButtonDown(u8, u8),
+    let mut window = Window::new().unwrap();
 
-    /// Happens when a controller button is released. Provides controller id and button id.
-    ButtonUp(u8, u8),
+    window.show();
 
-    /// Happens when a controller axis is used. Provides controller id, axis id and axis value range from (range: -32768 to 32767).
-    /// 
-    /// # Reference(s)
-    /// Based on SDL_ControllerAxisEvent : <https://wiki.libsdl.org/SDL2/SDL_ControllerAxisEvent>
-    Axis(u8, u8, i16)
+    println!("{}{}{}", BLUE_CONSOLE, "Starting gamepad event tests ...", RESET_CONSOLE);
 
+    // Rumble connected gamepads
+    let rumble = GamepadEffect::rumble(0.5, 0.5, Duration::from_millis(300));
+    for id in 0..=u8::MAX {
+        if let Some(gamepad) = window.get_gamepads_mut().get_gamepad_mut(id) {
+            println!("Gamepad {} rumble : {:?}", id, gamepad.play_effect(&rumble));
+        }
+    }
 
- */
\ No newline at end of file
+    for gamepad in window.get_gamepads().get_gamepad_list() {
+        println!("Gamepad {} : {} {:04x?}", gamepad.get_id(), gamepad.get_name(), gamepad.get_identifiers());
+        match gamepad.get_mapping() {
+            Some(mapping) => println!("    Mapping {} : {}", gamepad.get_guid(), mapping.get_name()),
+            None => println!("    No mapping for {}. Set SDL_GAMECONTROLLERCONFIG to name buttons and axes.", gamepad.get_guid()),
+        }
+    }
+
+    // Gamepad events test
+    main_loop(&mut window, &mut GamepadReceiver::new());
+
+    println!("{}{}{}", BLUE_CONSOLE, "... gamepad event tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
+
+/// Receiver that log gamepad events until space bar is pressed.
+pub struct GamepadReceiver {
+    space : SpaceReceiver,
+}
+
+impl GamepadReceiver {
+    pub fn new() -> GamepadReceiver {
+        println!("{}Plug, unplug and use gamepads, then press SPACE...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        GamepadReceiver { space: SpaceReceiver::new() }
+    }
+}
+
+impl EventReceiver for GamepadReceiver {
+    fn receive(&mut self, event: &Event) {
+        match event {
+            Event::Gamepad(gamepad_event) => println!("{:?}", gamepad_event),
+            _ => self.space.receive(event),
+        }
+    }
+
+    fn is_test_finished(&self) -> bool {
+        self.space.is_test_finished()
+    }
+}
@@ -72,7 +72,7 @@ mod hold_space {
         pub(super) fn hold_space_step(&mut self, event: &Event){
             
             // Only validate keyboard events
-            if let Event::Keyboard(kb_event) = event {
+            if let Event::Keyboard(kb_event, _) = event {
                 match kb_event {
                     EventKeyboard::KeyDown(key) => {
                         if *key == SPACE_KEY_VALUE {
@@ -106,7 +106,7 @@ mod hold_space {
                 self.step_msg = true; 
             }
 
-            if let Event::Keyboard(kb_event) = event {
+            if let Event::Keyboard(kb_event, _) = event {
                 // Verify if space key was released.
                 if let EventKeyboard::KeyUp(key) = kb_event {
                     if *key == SPACE_KEY_VALUE {
@@ -176,7 +176,7 @@ mod different_keys{
                 self.almost_done_msg = true;
             }
 
-            if let Event::Keyboard(kb_event) = event {
+            if let Event::Keyboard(kb_event, _) = event {
                 match kb_event {
                     EventKeyboard::KeyDown(keycode) => {
                         if !self.keymap.contains_key(keycode) {
@@ -295,7 +295,7 @@ mod hold_different_keys{
             }
             
 
-            if let Event::Keyboard(kb_event) = event {
+            if let Event::Keyboard(kb_event, _) = event {
                 self.print_msg = true;
                 match kb_event {
                     EventKeyboard::KeyDown(keycode) => {
@@ -352,7 +352,7 @@ mod auto_repeat_space {
 
     fn receive(&mut self, event: &Event) {
 
-        if let Event::Keyboard(kb_event) = event {
+        if let Event::Keyboard(kb_event, _) = event {
             // Verify if space key was released.
             if let EventKeyboard::KeyPress(key) = kb_event {
                 if key.keycode == SPACE_KEY_VALUE {
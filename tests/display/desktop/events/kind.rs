@@ -0,0 +1,51 @@
+use studio::display::desktop::{window::Window, event::{Event, pointer::EventPointer}, property::{WindowPropertySet, WindowEventWaitMode, WindowPositionOption, WindowType}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test window types of dialogs, notifications and unmanaged popup menus
+pub fn test_window_type(){
+
+    let properties = [WindowPropertySet::SetEventWaitMode(WindowEventWaitMode::AlwaysWait), WindowPropertySet::Title(String::from("Normal"))];
+    let dialog_properties = [WindowPropertySet::Type(WindowType::Dialog), WindowPropertySet::Title(String::from("Dialog")), WindowPropertySet::Size((320, 200))];
+    let notification_properties = [WindowPropertySet::Type(WindowType::Notification), WindowPropertySet::Size((300, 80))];
+    let popup_properties = [WindowPropertySet::Type(WindowType::PopupMenu), WindowPropertySet::Size((160, 240)),
+        WindowPropertySet::Position(WindowPositionOption::Desktop((100, 100)))];
+
+    let mut window = Window::new().unwrap();
+    let mut dialog = Window::new().unwrap();
+    let mut notification = Window::new().unwrap();
+    let mut popup = Window::new().unwrap();
+
+    window.set_properties(&properties).unwrap();
+    dialog.set_properties(&dialog_properties).unwrap();
+    notification.set_properties(&notification_properties).unwrap();
+    popup.set_properties(&popup_properties).unwrap();
+
+    window.show();
+    dialog.show();
+    notification.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting window type tests ...", RESET_CONSOLE);
+    println!("{}Check dialog and notification placement, click to show or hide an undecorated popup menu at (100, 100), press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+
+    let mut popup_shown = false;
+    let mut space = SpaceReceiver::new();
+    while !space.is_test_finished() {
+        let event = *window.poll_event();
+        match event {
+            Event::Pointer(EventPointer::ButtonDown(..), _) => {
+                if popup_shown { popup.hide() } else { popup.show() }
+                popup_shown = !popup_shown;
+            },
+            _ => space.receive(&event),
+        }
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "... window type tests ended ...", RESET_CONSOLE);
+
+    popup.close();
+    notification.close();
+    dialog.close();
+    window.close();
+
+}
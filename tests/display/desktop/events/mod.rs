@@ -1,6 +1,6 @@
 
 use crate::{tools::{BLUE_CONSOLE, RESET_CONSOLE, MAGENTA_CONSOLE}, display::desktop::rsrcs::get_user_selection};
-use self::{keyboard::test_keyboard, pointer::test_pointer};
+use self::{keyboard::test_keyboard, pointer::test_pointer, pen::test_pen, gamepad::test_gamepad, application::{test_application, test_application_run}, user::test_user, wait::test_wait, source::test_source, coalesce::test_coalesce, redraw::test_redraw, constraint::test_constraint, kind::test_window_type, state::test_window_state};
 
 /**
  * This module test all events.
@@ -9,13 +9,26 @@ use self::{keyboard::test_keyboard, pointer::test_pointer};
 pub fn window_events_tests() {
 
     loop {
-        println!("{}{}{}", BLUE_CONSOLE, "EVENTS : (1) Keyboard    (2) Pointer    (3) Window    (4) Gamepad", RESET_CONSOLE);
+        println!("{}{}{}", BLUE_CONSOLE, "EVENTS : (1) Keyboard    (2) Pointer    (3) Window    (4) Gamepad    (5) Pen    (6) Application    (7) Run loop    (8) User    (9) Wait    (10) Async    (11) File descriptor    (12) Coalescing    (13) Redraw    (14) Size constraints    (15) Window types    (16) Window states", RESET_CONSOLE);
 
         match get_user_selection().as_str() {
             "1" => test_keyboard(),
             "2" => test_pointer(),
             "3" => todo!(),
-            "4" => todo!(),
+            "4" => test_gamepad(),
+            "5" => test_pen(),
+            "6" => test_application(),
+            "7" => test_application_run(),
+            "8" => test_user(),
+            "9" => test_wait(),
+            #[cfg(feature = "async")]
+            "10" => stream::test_stream(),
+            "11" => test_source(),
+            "12" => test_coalesce(),
+            "13" => test_redraw(),
+            "14" => test_constraint(),
+            "15" => test_window_type(),
+            "16" => test_window_state(),
             "q" => break,
             _ => println!("{}{}{}", MAGENTA_CONSOLE, "Invalid selection!" , RESET_CONSOLE),
         }
@@ -35,9 +48,43 @@ mod gamepad;
 // Window event tests
 mod window;
 
+// Pen event tests
+mod pen;
 
 
 
 
 
 
+
+
+// Application multiple windows event tests
+mod application;
+
+// User events posted from other threads tests
+mod user;
+
+// Wait timeout tests
+mod wait;
+
+// Async stream tests
+#[cfg(feature = "async")]
+mod stream;
+
+// External file descriptor tests
+mod source;
+
+// Event coalescing and masks tests
+mod coalesce;
+
+// Exposed regions and redraw requests tests
+mod redraw;
+
+// Size constraints tests
+mod constraint;
+
+// Window types tests
+mod kind;
+
+// Window states tests
+mod state;
@@ -0,0 +1,49 @@
+use studio::display::desktop::{window::Window, event::{Event, pen::EventPen}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver, main_loop}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test pen and graphics tablet events
+pub fn test_pen(){
+
+    let mut window = Window::new().unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting pen event tests ...", RESET_CONSOLE);
+
+    // Pen events test
+    main_loop(&mut window, &mut PenReceiver::new());
+
+    println!("{}{}{}", BLUE_CONSOLE, "... pen event tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
+
+/// Receiver that log pen events until space bar is pressed.
+pub struct PenReceiver {
+    space : SpaceReceiver,
+}
+
+impl PenReceiver {
+    pub fn new() -> PenReceiver {
+        println!("{}Draw with the pen, use the eraser and barrel buttons, then press SPACE...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        PenReceiver { space: SpaceReceiver::new() }
+    }
+}
+
+impl EventReceiver for PenReceiver {
+    fn receive(&mut self, event: &Event) {
+        match event {
+            Event::Pen(pen_event) => match pen_event {
+                EventPen::Moved(_) => {},   // Too many to print.
+                _ => println!("{:?}", pen_event),
+            },
+            _ => self.space.receive(event),
+        }
+    }
+
+    fn is_test_finished(&self) -> bool {
+        self.space.is_test_finished()
+    }
+}
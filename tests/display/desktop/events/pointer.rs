@@ -1,9 +1,14 @@
-use studio::display::desktop::window::Window;
+use studio::display::desktop::{window::Window, device::InputDeviceList};
 use crate::display::desktop::rsrcs::{SpaceReceiver, main_loop};
 
 /// Test pointer events
 pub fn test_pointer(){
 
+   // Print input devices so events device id can be matched.
+   for device in InputDeviceList::new().unwrap().get_device_list() {
+      println!("Device {} : {} {:?}", device.get_id(), device.get_name(), device.get_device_type());
+   }
+
    let mut window = Window::new().unwrap();
 
    window.show();
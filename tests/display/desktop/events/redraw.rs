@@ -0,0 +1,34 @@
+use studio::display::desktop::{window::Window, event::{Event, EventCategoryMask, pointer::EventPointer, window::EventWindow}, property::{WindowPropertySet, WindowEventWaitMode}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test accumulated exposures and redraw requests
+pub fn test_redraw(){
+
+    let properties = [WindowPropertySet::SetEventWaitMode(WindowEventWaitMode::AlwaysWait),
+        WindowPropertySet::SetEventCoalescing(EventCategoryMask::NONE)];
+    let mut window = Window::new().unwrap();
+
+    window.set_properties(&properties).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting redraw tests ...", RESET_CONSOLE);
+    println!("{}Cover and uncover window to expose it, click to request 3 redraws merged into one, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+
+    let mut space = SpaceReceiver::new();
+    while !space.is_test_finished() {
+        let event = *window.poll_event();
+        match event {
+            Event::Window(EventWindow::Exposed(position, size)) => println!("Exposed {:?} {:?}, region={:?}", position, size, window.get_damage_region().get_rects()),
+            Event::Window(EventWindow::RedrawRequested) => println!("RedrawRequested"),
+            Event::Pointer(EventPointer::ButtonDown(..), _) => (0..3).for_each(|_| window.request_redraw()),
+            _ => space.receive(&event),
+        }
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "... redraw tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
@@ -0,0 +1,69 @@
+use std::{io::{Read, Write}, os::{fd::AsRawFd, unix::net::UnixStream}, thread, time::Duration};
+
+use studio::display::desktop::{window::Window, event::Event, source::FdInterest};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver, main_loop}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Token of the socket registered.
+const SOCKET_TOKEN : u64 = 42;
+
+/// Test external file descriptors ready events
+pub fn test_source(){
+
+    let (mut receiver, mut sender) = UnixStream::pair().unwrap();
+    let mut window = Window::new().unwrap();
+
+    window.get_fd_sources_mut().register(receiver.as_raw_fd(), SOCKET_TOKEN, FdInterest::Readable).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting file descriptor event tests ...", RESET_CONSOLE);
+
+    // Thread ends when the receiving socket is closed.
+    let writer = thread::spawn(move || {
+        while sender.write_all(b"ping").is_ok() {
+            thread::sleep(Duration::from_millis(500));
+        }
+    });
+
+    main_loop(&mut window, &mut SourceReceiver::new(&mut receiver));
+
+    println!("{}{}{}", BLUE_CONSOLE, "... file descriptor event tests ended ...", RESET_CONSOLE);
+
+    window.get_fd_sources_mut().deregister(receiver.as_raw_fd());
+    window.close();
+    drop(receiver);
+
+    writer.join().unwrap();
+
+}
+
+/// Receiver that read the socket when ready until space bar is pressed.
+pub struct SourceReceiver<'socket> {
+    space : SpaceReceiver,
+    socket : &'socket mut UnixStream,
+}
+
+impl<'socket> SourceReceiver<'socket> {
+    pub fn new(socket : &'socket mut UnixStream) -> SourceReceiver<'socket> {
+        println!("{}Socket is written every 500ms, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        SourceReceiver { space: SpaceReceiver::new(), socket }
+    }
+}
+
+impl EventReceiver for SourceReceiver<'_> {
+    fn receive(&mut self, event: &Event) {
+        match event {
+            Event::FdReady(token, readiness) => {
+                let mut buffer = [0u8; 64];
+                let count = self.socket.read(&mut buffer).unwrap();
+                println!("FdReady({}, {:?}) read {:?}", token, readiness, String::from_utf8_lossy(&buffer[..count]));
+            },
+            _ => self.space.receive(event),
+        }
+    }
+
+    fn is_test_finished(&self) -> bool {
+        self.space.is_test_finished()
+    }
+}
@@ -0,0 +1,47 @@
+use studio::display::desktop::{window::Window, event::{Event, pointer::{EventPointer, PointerButton}, window::EventWindow}, property::{WindowPropertySet, WindowEventWaitMode}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test above, below, sticky, skip taskbar and skip pager window states
+pub fn test_window_state(){
+
+    let properties = [WindowPropertySet::SetEventWaitMode(WindowEventWaitMode::AlwaysWait), WindowPropertySet::SkipPager(true)];
+    let above = [WindowPropertySet::Above(true)];
+    let below = [WindowPropertySet::Below(true)];
+    let normal = [WindowPropertySet::Above(false), WindowPropertySet::Below(false)];
+    let sticky = [WindowPropertySet::Sticky(true), WindowPropertySet::SkipTaskbar(true)];
+    let unsticky = [WindowPropertySet::Sticky(false), WindowPropertySet::SkipTaskbar(false)];
+    let mut window = Window::new().unwrap();
+
+    window.set_properties(&properties).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting window state tests ...", RESET_CONSOLE);
+    println!("{}Left click to cycle above, below and normal, right click to toggle sticky and skip taskbar, change states from window menu, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+
+    let mut space = SpaceReceiver::new();
+    while !space.is_test_finished() {
+        let event = *window.poll_event();
+        match event {
+            Event::Pointer(EventPointer::ButtonDown(PointerButton::LeftButton, _), _) => {
+                let properties = window.get_properties();
+                window.set_properties(if properties.above { &below } else if properties.below { &normal } else { &above }).unwrap();
+            },
+            Event::Pointer(EventPointer::ButtonDown(PointerButton::RightButton, _), _) => {
+                window.set_properties(if window.get_properties().sticky { &unsticky } else { &sticky }).unwrap();
+            },
+            Event::Window(EventWindow::StateChanged) => {
+                let properties = window.get_properties();
+                println!("StateChanged above={}, below={}, sticky={}, skip_taskbar={}, skip_pager={}", properties.above, properties.below,
+                    properties.sticky, properties.skip_taskbar, properties.skip_pager);
+            },
+            _ => space.receive(&event),
+        }
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "... window state tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
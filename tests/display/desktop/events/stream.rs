@@ -0,0 +1,58 @@
+use std::{future::Future, pin::pin, sync::Arc, task::{Context, Poll, Wake}, thread::{self, Thread}};
+
+use studio::display::desktop::{window::Window, event::Event};
+
+use crate::tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE};
+
+/// Test async stream of window events
+pub fn test_stream(){
+
+    let mut window = Window::new().unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting async stream tests ...", RESET_CONSOLE);
+
+    block_on(async {
+        let mut stream = window.event_stream().with_fd_waiter();
+
+        println!("{}Window events are logged until the pointer moves over window...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        while let Some(event) = stream.next_event().await {
+            match event {
+                Event::Window(_) => println!("{:?}", event),
+                _ => break,
+            }
+        }
+
+        println!("{}Close window with the title bar button...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        assert!(stream.next_close_request().await);
+    });
+
+    println!("{}{}{}", BLUE_CONSOLE, "... async stream tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
+
+/// Waker unparking the thread running [block_on].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Minimal executor running a future on the current thread, parked while pending.
+fn block_on<F : Future>(future : F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
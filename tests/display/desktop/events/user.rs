@@ -0,0 +1,74 @@
+use std::{thread, time::Duration};
+
+use studio::display::desktop::{window::Window, event::Event, property::{WindowPropertySet, WindowEventWaitMode}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test user events posted from another thread and timers
+pub fn test_user(){
+
+    let mut window = Window::new().unwrap();
+
+    // Window is blocked waiting and must be woken by the posting thread.
+    window.set_properties(&[WindowPropertySet::SetEventWaitMode(WindowEventWaitMode::AlwaysWait)]).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting user event tests ...", RESET_CONSOLE);
+
+    // Timer wakes the window every second.
+    window.get_timers_mut().add_repeating_timer(Duration::from_secs(1));
+
+    let proxy = window.create_proxy();
+    let sender = thread::spawn(move || {
+        let mut value = 0;
+        while proxy.post_payload(format!("Payload {}", value)).is_ok() {
+            value += 1;
+            thread::sleep(Duration::from_millis(500));
+        }
+    });
+
+    // Payloads are taken from window with the key of user events.
+    let mut receiver = UserReceiver::new();
+    while !receiver.is_test_finished() {
+        match *window.poll_event() {
+            Event::User(key) => println!("User({}) = {:?}", key, window.take_user_payload::<String>(key)),
+            event => receiver.receive(&event),
+        }
+    }
+
+    println!("{}{}{}", BLUE_CONSOLE, "... user event tests ended ...", RESET_CONSOLE);
+
+    window.close();
+    drop(window);
+
+    // Thread ends when posting to the dropped window fails.
+    sender.join().unwrap();
+
+}
+
+/// Receiver that log user events until space bar is pressed.
+pub struct UserReceiver {
+    space : SpaceReceiver,
+}
+
+impl UserReceiver {
+    pub fn new() -> UserReceiver {
+        println!("{}User events with a payload are posted every 500ms and timer expires every second without moving the pointer, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        UserReceiver { space: SpaceReceiver::new() }
+    }
+}
+
+impl EventReceiver for UserReceiver {
+    fn receive(&mut self, event: &Event) {
+        match event {
+            Event::User(value) => println!("User({})", value),
+            Event::Timer(id) => println!("Timer({:?})", id),
+            _ => self.space.receive(event),
+        }
+    }
+
+    fn is_test_finished(&self) -> bool {
+        self.space.is_test_finished()
+    }
+}
@@ -0,0 +1,51 @@
+use std::time::{Duration, Instant};
+
+use studio::display::desktop::{window::Window, event::Event, property::{WindowPropertySet, WindowEventWaitMode}};
+
+use crate::{display::desktop::rsrcs::{EventReceiver, SpaceReceiver, main_loop}, tools::{RESET_CONSOLE, BLUE_CONSOLE, YELLOW_CONSOLE}};
+
+/// Test wait with timeout and wait until event wait modes
+pub fn test_wait(){
+
+    let properties = [WindowPropertySet::SetEventWaitMode(WindowEventWaitMode::WaitTimeout(Duration::from_secs(1)))];
+    let mut window = Window::new().unwrap();
+
+    window.set_properties(&properties).unwrap();
+
+    window.show();
+
+    println!("{}{}{}", BLUE_CONSOLE, "Starting wait timeout tests ...", RESET_CONSOLE);
+
+    main_loop(&mut window, &mut TimeoutReceiver::new());
+
+    println!("{}{}{}", BLUE_CONSOLE, "... wait timeout tests ended ...", RESET_CONSOLE);
+
+    window.close();
+
+}
+
+/// Receiver that log timeouts until space bar is pressed.
+pub struct TimeoutReceiver {
+    space : SpaceReceiver,
+    start : Instant,
+}
+
+impl TimeoutReceiver {
+    pub fn new() -> TimeoutReceiver {
+        println!("{}Poll times out every second without moving the pointer, press SPACE to end...{}", YELLOW_CONSOLE, RESET_CONSOLE);
+        TimeoutReceiver { space: SpaceReceiver::new(), start: Instant::now() }
+    }
+}
+
+impl EventReceiver for TimeoutReceiver {
+    fn receive(&mut self, event: &Event) {
+        match event {
+            Event::None => println!("Timeout after {:?}", self.start.elapsed()),
+            _ => self.space.receive(event),
+        }
+    }
+
+    fn is_test_finished(&self) -> bool {
+        self.space.is_test_finished()
+    }
+}
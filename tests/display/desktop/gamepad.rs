@@ -0,0 +1,239 @@
+use studio::display::desktop::event::gamepad::EventGamepad;
+use studio::display::desktop::gamepad::queue_disconnected;
+use studio::display::desktop::gamepad::evdev::{EvdevAxis, EvdevDecoder, EvdevInputEvent, EvdevLayout, EV_ABS, EV_KEY, EV_SYN,
+    SYN_REPORT, SYN_DROPPED, ABS_X, ABS_Y, ABS_HAT0X, BTN_GAMEPAD, INPUT_EVENT_SIZE};
+use std::time::Duration;
+use std::collections::VecDeque;
+
+use studio::display::desktop::gamepad::effect::{GamepadEffect, GamepadWaveform, EFFECT_MAX_DURATION};
+use studio::display::desktop::gamepad::state::{GamepadCalibration, GamepadDeadZone, GamepadFilter, GamepadSettings, GamepadState};
+use studio::display::desktop::gamepad::mapping::{GamepadAxis, GamepadButton, GamepadGuid, GamepadMapper, GamepadMapping, 
+    GamepadMappingDB, GamepadMappingInput, GamepadMappingOutput, GamepadAxisRange};
+
+/// Mapping of the pad of [layout] with a trigger on the full range of axis 1.
+const MAPPING : &str = "030000005e0400008e02000014010000,Test Pad,a:b0,b:b1,leftx:a0,lefttrigger:a1,dpleft:h0.8,dpright:h0.2,-lefty:+a0,platform:Linux,";
+
+/// Layout of a pad with 2 buttons, a stick and a hat.
+fn layout() -> EvdevLayout {
+    EvdevLayout::new(vec![BTN_GAMEPAD + 1, BTN_GAMEPAD], 
+        vec![EvdevAxis::new(ABS_HAT0X, -1, 1), EvdevAxis::new(ABS_Y, 0, 255), EvdevAxis::new(ABS_X, 0, 255)])
+}
+
+/// Record events as an evdev device byte stream.
+fn record(events : &[(u16, u16, i32)]) -> Vec<u8> {
+    events.iter().flat_map(|(kind, code, value)| EvdevInputEvent::new(*kind, *code, *value).to_bytes()).collect()
+}
+
+/// Decode a byte stream.
+fn decode(decoder : &mut EvdevDecoder, bytes : &[u8]) -> Vec<EventGamepad> {
+    EvdevInputEvent::from_bytes(bytes).iter().filter_map(|event| decoder.decode(3, event)).collect()
+}
+
+#[test]
+fn gamepad_evdev_layout() {
+    let layout = layout();
+
+    // Buttons and axes are ordered like SDL, hats last.
+    assert_eq!(layout.get_button_id(BTN_GAMEPAD), Some(0));
+    assert_eq!(layout.get_button_id(BTN_GAMEPAD + 1), Some(1));
+    assert_eq!(layout.get_axis_id(ABS_X), Some(0));
+    assert_eq!(layout.get_axis_id(ABS_Y), Some(1));
+    assert_eq!(layout.get_axis_id(ABS_HAT0X), Some(2));
+}
+
+#[test]
+fn gamepad_evdev_decode() {
+    let mut decoder = EvdevDecoder::new(layout());
+
+    let bytes = record(&[(EV_KEY, BTN_GAMEPAD, 1), (EV_ABS, ABS_X, 255), (EV_ABS, ABS_Y, 0), (EV_SYN, SYN_REPORT, 0),
+        (EV_ABS, ABS_HAT0X, -1), (EV_ABS, ABS_X, 255), (EV_KEY, BTN_GAMEPAD, 2), (EV_KEY, BTN_GAMEPAD, 0), (EV_SYN, SYN_REPORT, 0)]);
+
+    assert_eq!(decode(&mut decoder, &bytes), vec![
+        EventGamepad::ButtonDown(3, 0),
+        EventGamepad::Axis(3, 0, 32767),
+        EventGamepad::Axis(3, 1, -32768),
+        EventGamepad::Axis(3, 2, -32768),
+        EventGamepad::ButtonUp(3, 0),
+    ]);
+
+    // Incomplete trailing event is ignored.
+    assert_eq!(decode(&mut decoder, &bytes[0..INPUT_EVENT_SIZE * 2 - 1]), vec![EventGamepad::ButtonDown(3, 0)]);
+}
+
+#[test]
+fn gamepad_evdev_dropped() {
+    let mut decoder = EvdevDecoder::new(layout());
+
+    let bytes = record(&[(EV_SYN, SYN_DROPPED, 0), (EV_KEY, BTN_GAMEPAD, 1), (EV_SYN, SYN_REPORT, 0), (EV_KEY, BTN_GAMEPAD + 1, 1)]);
+
+    assert_eq!(decode(&mut decoder, &bytes), vec![EventGamepad::ButtonDown(3, 1)]);
+    assert!(decoder.needs_resync());
+
+    // State read after the drop is compared with the last known state.
+    assert_eq!(decoder.resync(3, &[BTN_GAMEPAD], &[(ABS_X, 255), (ABS_Y, 0), (ABS_HAT0X, 0)]), vec![
+        EventGamepad::ButtonDown(3, 0),
+        EventGamepad::ButtonUp(3, 1),
+        EventGamepad::Axis(3, 0, 32767),
+        EventGamepad::Axis(3, 1, -32768),
+    ]);
+    assert!(!decoder.needs_resync());
+
+    // Events matching the resynced state are not repeated.
+    let bytes = record(&[(EV_KEY, BTN_GAMEPAD, 1), (EV_ABS, ABS_X, 255), (EV_KEY, BTN_GAMEPAD, 0), (EV_SYN, SYN_REPORT, 0)]);
+
+    assert_eq!(decode(&mut decoder, &bytes), vec![EventGamepad::ButtonUp(3, 0)]);
+    assert!(!decoder.needs_resync());
+}
+
+#[test]
+fn gamepad_mapping_parse() {
+    let mapping = GamepadMapping::parse(MAPPING).unwrap();
+
+    assert_eq!(mapping.get_guid(), GamepadGuid::new(0x03, 0x045e, 0x028e, 0x0114));
+    assert_eq!(mapping.get_guid().to_string(), "030000005e0400008e02000014010000");
+    assert_eq!(mapping.get_name(), "Test Pad");
+    assert_eq!(mapping.get_bindings()[0], (GamepadMappingOutput::Button(GamepadButton::A), GamepadMappingInput::Button(0)));
+    assert_eq!(mapping.get_bindings()[4], (GamepadMappingOutput::Button(GamepadButton::DPadLeft), GamepadMappingInput::Hat(0, 8)));
+    assert_eq!(mapping.get_bindings()[6], (GamepadMappingOutput::Axis(GamepadAxis::LeftY, GamepadAxisRange::Negative), 
+        GamepadMappingInput::Axis(0, GamepadAxisRange::Positive, false)));
+
+    // Other platforms and invalid lines are rejected.
+    assert!(GamepadMapping::parse(&MAPPING.replace("Linux", "Windows")).is_err());
+    assert!(GamepadMapping::parse("0300,Test Pad,a:b0,").is_err());
+}
+
+#[test]
+fn gamepad_mapping_lookup() {
+    let mut mappings = GamepadMappingDB::new();
+    assert_eq!(mappings.load(&format!("# Comment\n{}\ninvalid\n", MAPPING)), 1);
+
+    // Exact guid then guid ignoring version.
+    assert!(mappings.get_mapping(&GamepadGuid::new(0x03, 0x045e, 0x028e, 0x0114)).is_some());
+    assert!(mappings.get_mapping(&GamepadGuid::new(0x03, 0x045e, 0x028e, 0x0110)).is_some());
+    assert!(mappings.get_mapping(&GamepadGuid::new(0x03, 0x045e, 0x028f, 0x0114)).is_none());
+
+    // Same guid replaces mapping.
+    assert_eq!(mappings.load(&MAPPING.replace("Test Pad", "Other Pad")), 1);
+    assert_eq!(mappings.get_mapping_list().len(), 1);
+    assert_eq!(mappings.get_mapping_list()[0].get_name(), "Other Pad");
+}
+
+#[test]
+fn gamepad_mapping_events() {
+    let mut mapper = GamepadMapper::new(GamepadMapping::parse(MAPPING).unwrap(), vec![(2, 3)]);
+    let mut events : Vec<EventGamepad> = Vec::new();
+
+    for event in [EventGamepad::ButtonDown(3, 0), EventGamepad::ButtonUp(3, 0), EventGamepad::Axis(3, 0, 32767),
+        EventGamepad::Axis(3, 1, -32768), EventGamepad::Axis(3, 2, -32768), EventGamepad::Axis(3, 2, 0)] {
+        mapper.map_event(&event, &mut events);
+    }
+
+    assert_eq!(events, vec![
+        EventGamepad::MappedButtonDown(3, GamepadButton::A),
+        EventGamepad::MappedButtonUp(3, GamepadButton::A),
+        EventGamepad::MappedAxis(3, GamepadAxis::LeftX, 32767),
+        EventGamepad::MappedAxis(3, GamepadAxis::LeftY, -32767),
+        EventGamepad::MappedAxis(3, GamepadAxis::LeftTrigger, 0),
+        EventGamepad::MappedButtonDown(3, GamepadButton::DPadLeft),
+        EventGamepad::MappedButtonUp(3, GamepadButton::DPadLeft),
+    ]);
+}
+
+#[test]
+fn gamepad_effect() {
+    // Magnitudes are clamped.
+    assert_eq!(GamepadEffect::rumble(1.5, 0.5, Duration::from_millis(200)), 
+        GamepadEffect::Rumble { strong: u16::MAX, weak: 32768, duration: Duration::from_millis(200) });
+    assert_eq!(GamepadEffect::periodic(GamepadWaveform::Sine, Duration::from_millis(50), -1.0, Duration::from_secs(1)),
+        GamepadEffect::Periodic { waveform: GamepadWaveform::Sine, period: Duration::from_millis(50), magnitude: 0, offset: 0, 
+            duration: Duration::from_secs(1) });
+
+    // Duration is clamped.
+    assert_eq!(GamepadEffect::rumble(1.0, 1.0, Duration::from_secs(60)).get_duration(), EFFECT_MAX_DURATION);
+
+    // Effects shorter than a millisecond are empty and never played.
+    assert!(GamepadEffect::rumble(1.0, 1.0, Duration::ZERO).is_empty());
+    assert!(GamepadEffect::periodic(GamepadWaveform::Square, Duration::from_millis(50), 1.0, Duration::from_micros(500)).is_empty());
+    assert!(!GamepadEffect::rumble(1.0, 1.0, Duration::from_millis(1)).is_empty());
+}
+
+/// Filter raw events.
+fn filter(filter : &mut GamepadFilter, raw : &[EventGamepad]) -> Vec<EventGamepad> {
+    let mut events : Vec<EventGamepad> = Vec::new();
+    for event in raw {
+        filter.filter_event(event, &mut events);
+    }
+    events
+}
+
+#[test]
+fn gamepad_filter_dead_zone() {
+    // Stick on axes 0 and 1 with a radial dead zone.
+    let mut settings = GamepadSettings::new(vec![(0, 1)], vec![]);
+    settings.dead_zone = GamepadDeadZone::Radial(8000);
+    let mut radial = GamepadFilter::new(settings.clone());
+
+    // Jitter inside dead zone is ignored, diagonal keeps its direction.
+    assert_eq!(filter(&mut radial, &[EventGamepad::Axis(3, 0, 5000), EventGamepad::Axis(3, 1, -5000), EventGamepad::ButtonDown(3, 0)]),
+        vec![EventGamepad::ButtonDown(3, 0)]);
+    assert_eq!(filter(&mut radial, &[EventGamepad::Axis(3, 0, 32767)]), vec![EventGamepad::Axis(3, 0, 32392), EventGamepad::Axis(3, 1, -4943)]);
+    assert_eq!(filter(&mut radial, &[EventGamepad::Axis(3, 0, 100), EventGamepad::Axis(3, 1, 0)]), 
+        vec![EventGamepad::Axis(3, 0, 0), EventGamepad::Axis(3, 1, 0)]);
+
+    // Axial dead zone is applied on each axis.
+    settings.dead_zone = GamepadDeadZone::Axial(8000);
+    let mut axial = GamepadFilter::new(settings);
+    assert_eq!(filter(&mut axial, &[EventGamepad::Axis(3, 0, 32767), EventGamepad::Axis(3, 1, -5000), EventGamepad::Axis(3, 1, -32768)]),
+        vec![EventGamepad::Axis(3, 0, 32767), EventGamepad::Axis(3, 1, -32768)]);
+}
+
+#[test]
+fn gamepad_filter_trigger_calibration() {
+    let mut settings = GamepadSettings::new(vec![], vec![2]);
+    settings.set_calibration(0, GamepadCalibration::new(-16384, 1000, 16384));
+    let mut calibrated = GamepadFilter::new(settings);
+
+    // Calibrated axis reach full range.
+    assert_eq!(GamepadCalibration::new(-16384, 1000, 16384).apply(1000), 0);
+    assert_eq!(filter(&mut calibrated, &[EventGamepad::Axis(3, 0, -20000), EventGamepad::Axis(3, 0, 16384)]),
+        vec![EventGamepad::Axis(3, 0, -32768), EventGamepad::Axis(3, 0, 32767)]);
+
+    // Trigger pressed over half travel.
+    assert_eq!(filter(&mut calibrated, &[EventGamepad::Axis(3, 2, -32768), EventGamepad::Axis(3, 2, 100), EventGamepad::Axis(3, 2, -100)]),
+        vec![EventGamepad::Axis(3, 2, -32768), EventGamepad::Axis(3, 2, 100), EventGamepad::TriggerDown(3, 2), 
+            EventGamepad::Axis(3, 2, -100), EventGamepad::TriggerUp(3, 2)]);
+}
+
+#[test]
+fn gamepad_state() {
+    let mut state = GamepadState::new(2, 3);
+
+    for event in [EventGamepad::ButtonDown(3, 1), EventGamepad::Axis(3, 0, -200), EventGamepad::TriggerDown(3, 2),
+        EventGamepad::MappedButtonDown(3, GamepadButton::B), EventGamepad::MappedAxis(3, GamepadAxis::RightTrigger, 500)] {
+        state.apply(&event);
+    }
+
+    assert!(state.is_button_pressed(1) && !state.is_button_pressed(0) && !state.is_button_pressed(10));
+    assert_eq!(state.get_axis(0), -200);
+    assert!(state.is_trigger_pressed(2));
+    assert!(state.is_mapped_button_pressed(GamepadButton::B) && !state.is_mapped_button_pressed(GamepadButton::A));
+    assert_eq!(state.get_mapped_axis(GamepadAxis::RightTrigger), 500);
+}
+
+#[test]
+fn gamepad_disconnect_queue() {
+    // Connection not polled yet, gamepad never seen.
+    let mut events = VecDeque::from([EventGamepad::Connected(0), EventGamepad::ButtonDown(0, 1), EventGamepad::Connected(1)]);
+    queue_disconnected(&mut events, 0);
+    assert_eq!(events, VecDeque::from([EventGamepad::Connected(1)]));
+
+    // Connection polled, pending releases are kept before disconnection.
+    let mut events = VecDeque::from([EventGamepad::ButtonUp(0, 1), EventGamepad::Axis(0, 2, 0)]);
+    queue_disconnected(&mut events, 0);
+    assert_eq!(events, VecDeque::from([EventGamepad::ButtonUp(0, 1), EventGamepad::Axis(0, 2, 0), EventGamepad::Disconnected(0)]));
+
+    // Id reused, disconnection of previous gamepad is kept.
+    let mut events = VecDeque::from([EventGamepad::Disconnected(0), EventGamepad::Connected(0), EventGamepad::ButtonDown(0, 1)]);
+    queue_disconnected(&mut events, 0);
+    assert_eq!(events, VecDeque::from([EventGamepad::Disconnected(0)]));
+}
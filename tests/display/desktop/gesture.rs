@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use studio::display::desktop::event::{Event, EventSource, gesture::EventGesture, pointer::{EventPointer, PointerButton}};
+use studio::display::desktop::gesture::{DEFAULT_LONG_PRESS_TIME, GestureRecognizer, GestureSettings, TouchpadGestureDecoder, TouchpadGestureInput, TouchpadGesturePhase};
+
+/// Create a pointer event at timestamp.
+fn pointer(event : EventPointer, timestamp : u64) -> Event {
+    Event::Pointer(event, EventSource::new(0, timestamp))
+}
+
+/// Poll all gestures of recognizer.
+fn poll_all(recognizer : &mut GestureRecognizer) -> Vec<EventGesture> {
+    let mut gestures = Vec::new();
+    while let Some(gesture) = recognizer.poll_gesture() {
+        gestures.push(gesture);
+    }
+    gestures
+}
+
+/// Create a touchpad gesture input.
+fn touchpad(phase : TouchpadGesturePhase, fingers : u32, delta : (f64, f64), scale : f64, delta_angle : f64, cancelled : bool) -> TouchpadGestureInput {
+    TouchpadGestureInput { phase, fingers, position: (10.0, 20.0), delta, scale, delta_angle, cancelled }
+}
+
+#[test]
+fn gesture_double_click() {
+    let mut recognizer = GestureRecognizer::new(GestureSettings::new());
+    let button = PointerButton::LeftButton;
+
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (10, 10)), 1000));
+    recognizer.handle_event(&pointer(EventPointer::ButtonUp(button, (10, 10)), 1050));
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (11, 10)), 1200));
+    recognizer.handle_event(&pointer(EventPointer::ButtonUp(button, (11, 10)), 1250));
+
+    // Too late to be a triple click.
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (11, 10)), 2000));
+    recognizer.handle_event(&pointer(EventPointer::ButtonUp(button, (11, 10)), 2050));
+
+    assert_eq!(poll_all(&mut recognizer), vec![
+        EventGesture::Click { button, position: (10, 10), count: 1 },
+        EventGesture::Click { button, position: (11, 10), count: 2 },
+        EventGesture::Click { button, position: (11, 10), count: 1 },
+    ]);
+}
+
+#[test]
+fn gesture_long_press() {
+    let mut recognizer = GestureRecognizer::new(GestureSettings::new());
+    let button = PointerButton::RightButton;
+
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (5, 5)), 0));
+    recognizer.update(200);
+    assert_eq!(recognizer.poll_gesture(), None);
+
+    recognizer.update(600);
+    recognizer.handle_event(&pointer(EventPointer::ButtonUp(button, (5, 5)), 700));
+
+    // No click after a long press.
+    assert_eq!(poll_all(&mut recognizer), vec![EventGesture::LongPress { button, position: (5, 5) }]);
+}
+
+#[test]
+fn gesture_long_press_deadline() {
+    let mut recognizer = GestureRecognizer::new(GestureSettings::new());
+    let button = PointerButton::LeftButton;
+    assert_eq!(recognizer.get_timeout(), None);
+
+    // Deadline is given to the wait timeout while the button is held.
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (5, 5)), 100));
+    assert_eq!(recognizer.get_long_press_deadline(), Some(100 + DEFAULT_LONG_PRESS_TIME));
+    assert!(recognizer.get_timeout().is_some_and(|timeout| timeout <= Duration::from_millis(DEFAULT_LONG_PRESS_TIME)));
+
+    recognizer.update(100 + DEFAULT_LONG_PRESS_TIME);
+    assert_eq!(recognizer.get_long_press_deadline(), None);
+    assert_eq!(recognizer.get_timeout(), None);
+    recognizer.handle_event(&pointer(EventPointer::ButtonUp(button, (5, 5)), 700));
+
+    // A drag cancels the pending long press.
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (5, 5)), 1000));
+    recognizer.handle_event(&pointer(EventPointer::Moved((50, 5)), 1010));
+    assert_eq!(recognizer.get_long_press_deadline(), None);
+    assert_eq!(recognizer.get_timeout(), None);
+}
+
+#[test]
+fn gesture_drag() {
+    let mut settings = GestureSettings::new();
+    settings.drag_threshold = 4;
+    let mut recognizer = GestureRecognizer::new(settings);
+    let button = PointerButton::LeftButton;
+
+    recognizer.handle_event(&pointer(EventPointer::ButtonDown(button, (0, 0)), 0));
+    recognizer.handle_event(&pointer(EventPointer::Moved((3, 3)), 10));
+    recognizer.handle_event(&pointer(EventPointer::Moved((10, 3)), 20));
+    recognizer.handle_event(&pointer(EventPointer::Moved((20, 3)), 30));
+    recognizer.handle_event(&pointer(EventPointer::ButtonUp(button, (20, 3)), 2000));
+
+    assert_eq!(poll_all(&mut recognizer), vec![
+        EventGesture::DragStart { button, position: (0, 0) },
+        EventGesture::DragMove { button, position: (10, 3) },
+        EventGesture::DragMove { button, position: (20, 3) },
+        EventGesture::DragEnd { button, position: (20, 3) },
+    ]);
+}
+
+#[test]
+fn gesture_touchpad_pinch() {
+    let mut decoder = TouchpadGestureDecoder::new();
+
+    assert_eq!(decoder.decode_pinch(&touchpad(TouchpadGesturePhase::Begin, 2, (0.0, 0.0), 1.0, 0.0, false)), 
+        EventGesture::PinchBegin { fingers: 2, position: (10.0, 20.0) });
+
+    // Rotation is accumulated from each update.
+    assert_eq!(decoder.decode_pinch(&touchpad(TouchpadGesturePhase::Update, 2, (1.0, 1.0), 1.5, 10.0, false)), 
+        EventGesture::PinchUpdate { scale: 1.5, rotation: 10.0, position: (10.0, 20.0) });
+    assert_eq!(decoder.decode_pinch(&touchpad(TouchpadGesturePhase::Update, 2, (1.0, 1.0), 2.0, -4.0, false)), 
+        EventGesture::PinchUpdate { scale: 2.0, rotation: 6.0, position: (10.0, 20.0) });
+    assert_eq!(decoder.decode_pinch(&touchpad(TouchpadGesturePhase::End, 2, (0.0, 0.0), 2.0, 0.0, false)), 
+        EventGesture::PinchEnd { scale: 2.0, rotation: 6.0, cancelled: false });
+
+    // Rotation is reset when a new pinch begins.
+    assert_eq!(decoder.decode_pinch(&touchpad(TouchpadGesturePhase::Begin, 3, (0.0, 0.0), 1.0, 0.0, false)), 
+        EventGesture::PinchBegin { fingers: 3, position: (10.0, 20.0) });
+    assert_eq!(decoder.decode_pinch(&touchpad(TouchpadGesturePhase::End, 3, (0.0, 0.0), 0.5, 0.0, true)), 
+        EventGesture::PinchEnd { scale: 0.5, rotation: 0.0, cancelled: true });
+}
+
+#[test]
+fn gesture_touchpad_swipe() {
+    let decoder = TouchpadGestureDecoder::new();
+
+    assert_eq!(decoder.decode_swipe(&touchpad(TouchpadGesturePhase::Begin, 3, (0.0, 0.0), 1.0, 0.0, false)), 
+        EventGesture::SwipeBegin { fingers: 3, position: (10.0, 20.0) });
+    assert_eq!(decoder.decode_swipe(&touchpad(TouchpadGesturePhase::Update, 3, (4.0, -2.5), 1.0, 0.0, false)), 
+        EventGesture::SwipeUpdate { fingers: 3, delta: (4.0, -2.5) });
+    assert_eq!(decoder.decode_swipe(&touchpad(TouchpadGesturePhase::End, 3, (0.0, 0.0), 1.0, 0.0, false)), 
+        EventGesture::SwipeEnd { fingers: 3, cancelled: false });
+    assert_eq!(decoder.decode_swipe(&touchpad(TouchpadGesturePhase::End, 4, (0.0, 0.0), 1.0, 0.0, true)), 
+        EventGesture::SwipeEnd { fingers: 4, cancelled: true });
+}
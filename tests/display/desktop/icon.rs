@@ -0,0 +1,13 @@
+use studio::{display::{desktop::icon::WindowIcon, DisplayError}, error::StudioError};
+
+#[test]
+fn window_icon() {
+    // Pixels must match size.
+    assert!(matches!(WindowIcon::new(2, 2, vec![0; 15]), Err(StudioError::Display(DisplayError::IconInvalid))));
+    assert!(matches!(WindowIcon::new(0, 2, Vec::new()), Err(StudioError::Display(DisplayError::IconInvalid))));
+
+    // RGBA pixels converted to ARGB.
+    let icon = WindowIcon::new(2, 1, vec![0x11, 0x22, 0x33, 0xFF, 0xAA, 0xBB, 0xCC, 0x80]).unwrap();
+    assert_eq!(icon.get_size(), (2, 1));
+    assert_eq!(icon.get_argb().collect::<Vec<u32>>(), vec![0xFF112233, 0x80AABBCC]);
+}
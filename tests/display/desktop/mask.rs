@@ -0,0 +1,19 @@
+use studio::display::desktop::event::{Event, EventCategoryMask, window::EventWindow, pointer::EventPointer, EventSource};
+
+#[test]
+fn event_category_mask() {
+    let mask = EventCategoryMask::ALL.without(EventCategoryMask::CURSOR_CROSSING | EventCategoryMask::POINTER_MOTION);
+    assert!(mask.contains(EventCategoryMask::KEYBOARD | EventCategoryMask::FOCUS));
+    assert!(!mask.contains(EventCategoryMask::CURSOR_CROSSING));
+    assert!(EventCategoryMask::ALL.contains(mask));
+    assert!(EventCategoryMask::NONE.without(EventCategoryMask::ALL) == EventCategoryMask::NONE);
+
+    assert!(!mask.accepts(&Event::Window(EventWindow::CursorEnter)));
+    assert!(!mask.accepts(&Event::Pointer(EventPointer::Moved((0, 0)), EventSource::new(0, 0))));
+    assert!(mask.accepts(&Event::Window(EventWindow::Resized((1, 1)))));
+
+    // Events without category are never masked.
+    assert!(EventCategoryMask::NONE.accepts(&Event::Window(EventWindow::CloseRequest)));
+    assert!(EventCategoryMask::NONE.accepts(&Event::User(0)));
+    assert!(EventCategoryMask::NONE.accepts(&Event::None));
+}
@@ -8,6 +8,44 @@ mod events;
 
 mod log;
 
+// Gamepad decoding tests
+mod gamepad;
+
+// Pointer gestures tests
+mod gesture;
+
+target_cfg! {
+    linux => {
+        // XSETTINGS parsing tests
+        mod xsettings;
+    }
+}
+
+// Timers tests
+mod timer;
+
+// External file descriptors tests
+mod source;
+
+// Event category masks tests
+mod mask;
+
+// Damage regions tests
+mod region;
+
+// Event recording and replay tests
+mod record;
+
+// Window state persistence tests
+mod state;
+
+// Window icons tests
+mod icon;
+
+// Serialization tests
+#[cfg(feature = "serde")]
+mod serde;
+
 
 /*********
 * CONSTS *
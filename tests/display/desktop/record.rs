@@ -0,0 +1,101 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use studio::{display::{DisplayError, desktop::{record::{EventRecorder, EventReplayer, EventSink, RECORDING_MAGIC}, event::{Event, EventSource, 
+    keyboard::{EventKeyboard, Key}, pointer::{EventPointer, PointerButton}, window::EventWindow, gamepad::EventGamepad, gesture::EventGesture, 
+    pen::{EventPen, PenState, PenTool}}, gamepad::mapping::GamepadAxis}}, error::StudioError};
+
+/// Events of recording tests.
+const EVENTS : [Event; 9] = [
+    Event::Window(EventWindow::Created),
+    Event::Window(EventWindow::MovedResized((-10, 20), (640, 480))),
+    Event::Keyboard(EventKeyboard::KeyDown(38), EventSource { device: 3, timestamp: 1000 }),
+    Event::Pointer(EventPointer::ButtonDown(PointerButton::Other(12), (5, 6)), EventSource { device: 2, timestamp: 1001 }),
+    Event::Gamepad(EventGamepad::MappedAxis(1, GamepadAxis::RightTrigger, -32768)),
+    Event::Gesture(EventGesture::PinchUpdate { scale: 1.5, rotation: -12.0, position: (10.5, 20.25) }),
+    Event::User(u64::MAX),
+    Event::Window(EventWindow::RedrawRequested),
+    Event::Window(EventWindow::CloseRequest),
+];
+
+#[test]
+fn record_replay() {
+    let mut recorder = EventRecorder::new(Vec::new()).unwrap();
+    for (index, event) in EVENTS.iter().enumerate() {
+        recorder.record_at(Duration::from_millis(index as u64), event).unwrap();
+    }
+    recorder.record(&Event::None).unwrap();     // Not recorded
+
+    let key = Event::Keyboard(EventKeyboard::KeyPress(Key::new(1, 38, Some('é'))), EventSource::new(3, 1002));
+    recorder.record_at(Duration::from_millis(20), &key).unwrap();
+    let mut pen = PenState::new(4, PenTool::Eraser);
+    pen.pressure = 0.5;
+    recorder.record_at(Duration::from_millis(21), &Event::Pen(EventPen::ButtonDown(2, pen))).unwrap();
+
+    let bytes = recorder.finish().unwrap();
+    assert_eq!(&bytes[0..4], &RECORDING_MAGIC);
+
+    let mut replayer = EventReplayer::new(bytes.as_slice()).unwrap();
+    assert_eq!(replayer.get_events().len(), EVENTS.len() + 2);
+    assert_eq!(replayer.get_events()[1].elapsed, Duration::from_millis(1));
+
+    // Replay without delays.
+    replayer.set_speed(f64::INFINITY);
+    for event in EVENTS.iter() {
+        assert_eq!(replayer.poll_event(), event);
+    }
+    assert_eq!(replayer.wait_event(), Some(key));
+    assert_eq!(replayer.wait_event(), Some(Event::Pen(EventPen::ButtonDown(2, pen))));
+    assert!(replayer.is_finished());
+    assert_eq!(replayer.poll_event(), &Event::None);
+    assert_eq!(replayer.wait_event(), None);
+}
+
+#[test]
+fn replay_push_order() {
+    let mut recorder = EventRecorder::new(Vec::new()).unwrap();
+    for (index, event) in EVENTS.iter().enumerate() {
+        recorder.record_at(Duration::from_millis(index as u64), event).unwrap();
+    }
+    recorder.record_at(Duration::from_secs(3600), &Event::User(1)).unwrap();    // Not due
+
+    let mut replayer = EventReplayer::new(recorder.finish().unwrap().as_slice()).unwrap();
+    replayer.set_speed(1000.0);
+
+    // Events pushed before the replay starts are polled first.
+    let queue : RefCell<VecDeque<Event>> = RefCell::new(VecDeque::new());
+    queue.push_event(Event::Window(EventWindow::Shown));
+
+    // Due events are pushed and polled back in recording order.
+    let mut count = 0;
+    while count < EVENTS.len() {
+        count += replayer.push_events(&queue);
+    }
+    assert_eq!(count, EVENTS.len());
+    assert_eq!(queue.borrow_mut().pop_front(), Some(Event::Window(EventWindow::Shown)));
+    for event in EVENTS.iter() {
+        assert_eq!(queue.borrow_mut().pop_front().as_ref(), Some(event));
+    }
+    assert_eq!(replayer.get_events().len(), 1);
+}
+
+#[test]
+fn replay_invalid() {
+    assert!(matches!(EventReplayer::new(&b"NOPE\x01\x00"[..]), Err(StudioError::Display(DisplayError::RecordingInvalid))));
+    assert!(matches!(EventReplayer::new(&b"STEV\x63\x00"[..]), Err(StudioError::Display(DisplayError::RecordingVersionNotSupported))));
+
+    // Truncated record.
+    let mut recorder = EventRecorder::new(Vec::new()).unwrap();
+    recorder.record_at(Duration::ZERO, &Event::User(7)).unwrap();
+    let mut bytes = recorder.finish().unwrap();
+    bytes.pop();
+    assert!(matches!(EventReplayer::new(bytes.as_slice()), Err(StudioError::Display(DisplayError::RecordingInvalid))));
+
+    // Events are due at their time.
+    let mut recorder = EventRecorder::new(Vec::new()).unwrap();
+    recorder.record_at(Duration::from_secs(60), &Event::User(7)).unwrap();
+    let mut replayer = EventReplayer::new(recorder.finish().unwrap().as_slice()).unwrap();
+    assert_eq!(replayer.poll_event(), &Event::None);
+    assert!(!replayer.is_finished());
+}
@@ -0,0 +1,24 @@
+use studio::display::desktop::region::DamageRegion;
+
+#[test]
+fn damage_region_union() {
+    let mut region = DamageRegion::new();
+    assert!(region.is_empty());
+    assert_eq!(region.get_bounds(), None);
+
+    region.add(((10, 10), (20, 20)));
+    region.add(((15, 15), (5, 5)));     // Covered, ignored.
+    region.add(((0, 0), (0, 10)));      // Empty, ignored.
+    assert_eq!(region.get_rects(), &[((10, 10), (20, 20))]);
+
+    region.add(((40, 5), (10, 10)));
+    assert_eq!(region.get_rects().len(), 2);
+    assert_eq!(region.get_bounds(), Some(((10, 5), (40, 25))));
+
+    // Covering rectangle replace covered ones.
+    region.add(((0, 0), (100, 100)));
+    assert_eq!(region.get_rects(), &[((0, 0), (100, 100))]);
+
+    region.clear();
+    assert!(region.is_empty());
+}
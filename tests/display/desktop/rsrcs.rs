@@ -55,7 +55,7 @@ impl SpaceReceiver {
 impl EventReceiver for SpaceReceiver{
     fn receive(&mut self, event: &Event) {
         match event {
-            Event::Keyboard(kb_event) => match kb_event {
+            Event::Keyboard(kb_event, _) => match kb_event {
                 EventKeyboard::KeyDown(key) 
                 | EventKeyboard::KeyUp(key) => self.finished = self.finished || ( *key == SPACE_KEY_VALUE ),
                 EventKeyboard::KeyPress(key) => println!("Key={:?}", key),
@@ -88,7 +88,7 @@ pub fn main_loop(window: &mut Window, receiver: &mut dyn EventReceiver){
             }
 
             match event {
-                Event::Keyboard(kb_event) => match kb_event {
+                Event::Keyboard(kb_event, _) => match kb_event {
                     EventKeyboard::KeyDown(key) => 
                     {      
                         if *key == ESC_KEY_VALUE {
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use studio::display::desktop::{event::{Event, EventSource, EventCategoryMask, keyboard::{EventKeyboard, Key, KeyModifier}, pointer::{EventPointer, PointerButton}, 
+    window::EventWindow}, property::{WindowEventWaitMode, PointerMode, FullScreenMode}, screen::{Screen, ScreenResolution}};
+
+#[test]
+fn serde_events() {
+    let events = [
+        Event::Window(EventWindow::MovedResized((-10, 20), (640, 480))),
+        Event::Pointer(EventPointer::ButtonDown(PointerButton::Other(12), (5, 6)), EventSource::new(2, 1001)),
+        Event::Keyboard(EventKeyboard::KeyPress(Key::from_modifiers(KeyModifier::SHIFT | KeyModifier::CTRL, 38, Some('A'))), EventSource::new(3, 1002)),
+        Event::User(7),
+    ];
+
+    for event in events {
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    }
+
+    // Key is serialized with modifier flags, not the display server state.
+    let key = Key::from_modifiers(KeyModifier::SHIFT | KeyModifier::NUMLOCK, 38, None);
+    assert_eq!(key.get_modifiers(), KeyModifier::SHIFT | KeyModifier::NUMLOCK);
+    assert_eq!(serde_json::to_value(key).unwrap(), serde_json::json!({ "modifiers": KeyModifier::SHIFT | KeyModifier::NUMLOCK, "keycode": 38, "character": null }));
+
+    let mask = EventCategoryMask::KEYBOARD | EventCategoryMask::FOCUS;
+    assert_eq!(serde_json::from_str::<EventCategoryMask>(&serde_json::to_string(&mask).unwrap()).unwrap(), mask);
+}
+
+#[test]
+fn serde_properties() {
+    let mode = WindowEventWaitMode::WaitTimeout(Duration::from_millis(250));
+    assert_eq!(serde_json::from_str::<WindowEventWaitMode>(&serde_json::to_string(&mode).unwrap()).unwrap(), mode);
+    assert!(serde_json::to_string(&WindowEventWaitMode::WaitUntil(std::time::Instant::now())).is_err());
+
+    assert_eq!(serde_json::from_str::<PointerMode>("\"Acceleration\"").unwrap(), PointerMode::Acceleration);
+
+    let resolution = ScreenResolution::new(1920, 1080);
+    let screen = Screen::new(String::from("DP-1"), (1920, 0), (1920, 1080), 6000, true, vec![resolution]);
+    let json = serde_json::to_string(&FullScreenMode::Screen(screen)).unwrap();
+
+    match serde_json::from_str::<FullScreenMode>(&json).unwrap() {
+        FullScreenMode::Screen(screen) => {
+            assert_eq!(screen.get_identifier(), "DP-1");
+            assert_eq!(screen.get_extended_position(), (1920, 0));
+            assert!(screen.is_primary());
+            assert_eq!(screen.get_supported_resolutions()[0].get_width(), 1920);
+        },
+        _ => panic!("Wrong fullscreen mode deserialized"),
+    }
+}
@@ -0,0 +1,23 @@
+use std::os::{fd::AsRawFd, unix::net::UnixStream};
+
+use studio::display::desktop::source::{FdInterest, FdSourceList};
+
+#[test]
+fn fd_source_register() {
+    let (first, second) = UnixStream::pair().unwrap();
+    let mut sources = FdSourceList::new();
+    assert!(sources.is_empty());
+
+    sources.register(first.as_raw_fd(), 1, FdInterest::Readable).unwrap();
+    sources.register(second.as_raw_fd(), 2, FdInterest::Writable).unwrap();
+
+    // File descriptors are registered once.
+    assert!(sources.register(first.as_raw_fd(), 3, FdInterest::ReadWrite).is_err());
+    assert!(sources.set_interest(first.as_raw_fd(), FdInterest::ReadWrite));
+
+    assert!(sources.deregister(first.as_raw_fd()));
+    assert!(!sources.deregister(first.as_raw_fd()));
+    assert!(!sources.set_interest(first.as_raw_fd(), FdInterest::Readable));
+    assert!(sources.deregister(second.as_raw_fd()));
+    assert!(sources.is_empty());
+}
@@ -0,0 +1,40 @@
+use studio::display::desktop::{state::WindowState, screen::{Screen, ScreenList}, property::{WindowPropertySet, WindowPositionOption, FullScreenMode}};
+
+/// Create a screen list of a 1920x1080 primary screen and a 1280x1024 screen on its right.
+fn get_screens() -> ScreenList {
+    ScreenList::create((3200, 1080), vec![Screen::new(String::from("DP-1"), (0, 0), (1920, 1080), 6000, true, Vec::new()),
+        Screen::new(String::from("HDMI-1"), (1920, 0), (1280, 1024), 6000, false, Vec::new())])
+}
+
+#[test]
+fn window_state_restore() {
+    let screens = get_screens();
+
+    // Saved screen available.
+    let state = WindowState { screen: Some(String::from("HDMI-1")), position: (100, 50), size: (800, 600), maximized: true, fullscreen: false };
+    let properties = state.get_properties(&screens);
+    assert_eq!(properties.len(), 3);
+    assert!(matches!(properties[0], WindowPropertySet::Size((800, 600))));
+    assert!(matches!(&properties[1], WindowPropertySet::Position(WindowPositionOption::Screen(screen, (100, 50))) if screen.get_identifier() == "HDMI-1"));
+    assert!(matches!(properties[2], WindowPropertySet::Maximized));
+
+    // Saved screen gone and window larger than primary screen.
+    let state = WindowState { screen: Some(String::from("DP-2")), position: (3000, -20), size: (2560, 600), maximized: false, fullscreen: true };
+    let properties = state.get_properties(&screens);
+    assert_eq!(properties.len(), 3);
+    assert!(matches!(properties[0], WindowPropertySet::Size((1920, 600))));
+    assert!(matches!(&properties[1], WindowPropertySet::Position(WindowPositionOption::Screen(screen, (0, 0))) if screen.get_identifier() == "DP-1"));
+    assert!(matches!(&properties[2], WindowPropertySet::Fullscreen(FullScreenMode::Screen(screen)) if screen.is_primary()));
+
+    // Resolution of saved screen changed.
+    let state = WindowState { screen: Some(String::from("HDMI-1")), position: (1000, 900), size: (800, 600), maximized: false, fullscreen: false };
+    let properties = state.get_properties(&screens);
+    assert_eq!(properties.len(), 2);
+    assert!(matches!(&properties[1], WindowPropertySet::Position(WindowPositionOption::Screen(_, (480, 424)))));
+
+    // No screen, clamped to desktop.
+    let state = WindowState { screen: None, position: (-50, 50), size: (0, 600), maximized: false, fullscreen: false };
+    let properties = state.get_properties(&ScreenList::create((1024, 768), Vec::new()));
+    assert!(matches!(properties[0], WindowPropertySet::Size((1, 600))));
+    assert!(matches!(properties[1], WindowPropertySet::Position(WindowPositionOption::Desktop((0, 50)))));
+}
@@ -0,0 +1,72 @@
+use std::{cell::Cell, rc::Rc, time::{Duration, Instant}};
+
+use studio::display::desktop::timer::{Clock, TimerList, TIMER_MIN_INTERVAL};
+
+/// Clock advanced manually by tests.
+#[derive(Clone)]
+struct ManualClock {
+    now : Rc<Cell<Instant>>,
+}
+
+impl ManualClock {
+    fn new() -> ManualClock {
+        ManualClock { now: Rc::new(Cell::new(Instant::now())) }
+    }
+
+    fn advance(&self, duration : Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+#[test]
+fn timer_one_shot() {
+    let clock = ManualClock::new();
+    let mut timers = TimerList::with_clock(Box::new(clock.clone()));
+
+    let late = timers.add_timer(Duration::from_millis(200));
+    let early = timers.add_timer(Duration::from_millis(100));
+    assert_eq!(timers.get_timeout(), Some(Duration::from_millis(100)));
+    assert_eq!(timers.poll_timer(), None);
+
+    // Expired timers are polled by deadline, once.
+    clock.advance(Duration::from_millis(250));
+    assert_eq!(timers.get_timeout(), Some(Duration::ZERO));
+    assert_eq!(timers.poll_timer(), Some(early));
+    assert_eq!(timers.poll_timer(), Some(late));
+    assert_eq!(timers.poll_timer(), None);
+    assert!(timers.is_empty());
+    assert_eq!(timers.get_timeout(), None);
+}
+
+#[test]
+fn timer_repeating() {
+    let clock = ManualClock::new();
+    let mut timers = TimerList::with_clock(Box::new(clock.clone()));
+
+    let id = timers.add_repeating_timer(Duration::from_millis(100));
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(timers.poll_timer(), Some(id));
+    assert_eq!(timers.poll_timer(), None);
+    assert_eq!(timers.get_timeout(), Some(Duration::from_millis(100)));
+
+    // Missed expirations are skipped.
+    clock.advance(Duration::from_millis(350));
+    assert_eq!(timers.poll_timer(), Some(id));
+    assert_eq!(timers.poll_timer(), None);
+    assert_eq!(timers.get_timeout(), Some(Duration::from_millis(100)));
+
+    assert!(timers.remove_timer(id));
+    assert!(!timers.remove_timer(id));
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(timers.poll_timer(), None);
+
+    // Zero interval is clamped.
+    timers.add_repeating_timer(Duration::ZERO);
+    assert_eq!(timers.get_timeout(), Some(TIMER_MIN_INTERVAL));
+}
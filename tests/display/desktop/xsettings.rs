@@ -0,0 +1,100 @@
+use studio::display::desktop::manager::linux::x11::xsettings::parse_xsettings_int;
+
+/// XSETTINGS setting value used to build property data.
+enum Setting {
+    Integer(i32),
+    String(&'static str),
+    Color(u16, u16, u16, u16),
+}
+
+/// Build XSETTINGS property data with byte order.
+fn xsettings_data(msb : bool, settings : &[(&str, Setting)]) -> Vec<u8> {
+    let u16_bytes = |value : u16| if msb { value.to_be_bytes() } else { value.to_le_bytes() };
+    let u32_bytes = |value : u32| if msb { value.to_be_bytes() } else { value.to_le_bytes() };
+    let padded = |data : &mut Vec<u8>, bytes : &[u8]| {
+        data.extend_from_slice(bytes);
+        data.resize(data.len() + (4 - bytes.len() % 4) % 4, 0);
+    };
+
+    // Header : byte-order, unused, serial, count
+    let mut data = vec![msb as u8, 0, 0, 0];
+    data.extend_from_slice(&u32_bytes(7));
+    data.extend_from_slice(&u32_bytes(settings.len() as u32));
+
+    for (name, setting) in settings {
+        let setting_type = match setting {
+            Setting::Integer(_) => 0,
+            Setting::String(_) => 1,
+            Setting::Color(..) => 2,
+        };
+
+        // Setting header : type, unused, name-len, name, last-change-serial
+        data.extend_from_slice(&[setting_type, 0]);
+        data.extend_from_slice(&u16_bytes(name.len() as u16));
+        padded(&mut data, name.as_bytes());
+        data.extend_from_slice(&u32_bytes(3));
+
+        match setting {
+            Setting::Integer(value) => data.extend_from_slice(&u32_bytes(*value as u32)),
+            Setting::String(value) => {
+                data.extend_from_slice(&u32_bytes(value.len() as u32));
+                padded(&mut data, value.as_bytes());
+            },
+            Setting::Color(red, green, blue, alpha) => [red, green, blue, alpha].iter().for_each(|value| data.extend_from_slice(&u16_bytes(**value))),
+        }
+    }
+
+    data
+}
+
+/// Settings of each type, with the integer after a string and a color.
+fn settings() -> Vec<(&'static str, Setting)> {
+    vec![
+        ("Net/ThemeName", Setting::String("Adwaita")),
+        ("Gtk/CursorThemeColor", Setting::Color(0xFFFF, 0x8000, 0, 0xFFFF)),
+        ("Net/DoubleClickTime", Setting::Integer(250)),
+        ("Net/DndDragThreshold", Setting::Integer(-1)),
+    ]
+}
+
+#[test]
+fn xsettings_parse_int() {
+    for msb in [false, true] {
+        let data = xsettings_data(msb, &settings());
+
+        assert_eq!(parse_xsettings_int(&data, "Net/DoubleClickTime"), Some(250));
+        assert_eq!(parse_xsettings_int(&data, "Net/DndDragThreshold"), Some(-1));
+
+        // Strings, colors and unknown names aren't integers.
+        assert_eq!(parse_xsettings_int(&data, "Net/ThemeName"), None);
+        assert_eq!(parse_xsettings_int(&data, "Gtk/CursorThemeColor"), None);
+        assert_eq!(parse_xsettings_int(&data, "Net/CursorBlinkTime"), None);
+    }
+}
+
+#[test]
+fn xsettings_parse_byte_order() {
+    // LSB data read as MSB gives a wrong count and must not find the setting.
+    let mut data = xsettings_data(false, &[("Net/DoubleClickTime", Setting::Integer(250))]);
+    assert_eq!(parse_xsettings_int(&data, "Net/DoubleClickTime"), Some(250));
+    data[0] = 1;
+    assert_eq!(parse_xsettings_int(&data, "Net/DoubleClickTime"), None);
+
+    let data = xsettings_data(true, &[("Net/DoubleClickTime", Setting::Integer(250))]);
+    assert_eq!(parse_xsettings_int(&data, "Net/DoubleClickTime"), Some(250));
+}
+
+#[test]
+fn xsettings_parse_truncated() {
+    assert_eq!(parse_xsettings_int(&[], "Net/DoubleClickTime"), None);
+
+    for msb in [false, true] {
+        let data = xsettings_data(msb, &settings());
+
+        // Every truncation before the end of the last setting must fail without panicking.
+        for len in 0..data.len() {
+            assert_eq!(parse_xsettings_int(&data[..len], "Net/DndDragThreshold"), None, "truncated at {}", len);
+        }
+        assert_eq!(parse_xsettings_int(&data, "Net/DndDragThreshold"), Some(-1));
+    }
+}